@@ -0,0 +1,250 @@
+/// Directed acyclic word graph (DAWG) for the lexicon
+///
+/// A minimized letter trie: common prefixes share a path from the root and
+/// common suffixes share their tails, so the full word list fits in a few
+/// MB while still answering membership, prefix and completion queries by
+/// walking at most one edge per letter.
+
+use std::collections::HashMap;
+
+/// Index of a node inside a [`Dawg`]
+pub type NodeId = u32;
+
+/// Graph node: sorted outgoing edges plus an end-of-word flag
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+struct DawgNode {
+    edges: Vec<(char, NodeId)>,
+    is_terminal: bool,
+}
+
+/// Minimized word graph built from a sorted word list
+#[derive(Debug, Clone)]
+pub struct Dawg {
+    nodes: Vec<DawgNode>,
+}
+
+impl Dawg {
+    /// The root node (the empty prefix)
+    pub const ROOT: NodeId = 0;
+
+    /// Builds a DAWG from words in any order
+    ///
+    /// Uses incremental minimization on the sorted input (Daciuk et al.), so
+    /// the unminimized trie is never held in memory.
+    pub fn build<I, S>(words: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut sorted: Vec<String> = words.into_iter().map(|w| w.as_ref().to_string()).collect();
+        sorted.sort();
+        sorted.dedup();
+
+        let mut builder = DawgBuilder::new();
+        for word in &sorted {
+            builder.insert(word);
+        }
+        builder.finish()
+    }
+
+    /// Number of nodes in the graph
+    pub fn node_count(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Follows the edge labelled `letter` from `node`
+    pub fn child(&self, node: NodeId, letter: char) -> Option<NodeId> {
+        let edges = &self.nodes[node as usize].edges;
+        edges
+            .binary_search_by(|&(l, _)| l.cmp(&letter))
+            .ok()
+            .map(|i| edges[i].1)
+    }
+
+    /// Outgoing edges of `node` in alphabetical order
+    pub fn edges(&self, node: NodeId) -> impl Iterator<Item = (char, NodeId)> + '_ {
+        self.nodes[node as usize].edges.iter().copied()
+    }
+
+    /// Checks if the path to `node` spells a complete word
+    pub fn is_terminal(&self, node: NodeId) -> bool {
+        self.nodes[node as usize].is_terminal
+    }
+
+    /// Walks `path` from `node`, returning where it ends (if every edge exists)
+    pub fn walk(&self, node: NodeId, path: &str) -> Option<NodeId> {
+        path.chars().try_fold(node, |current, letter| self.child(current, letter))
+    }
+
+    /// Checks if `word` is in the graph
+    pub fn contains(&self, word: &str) -> bool {
+        self.walk(Self::ROOT, word).is_some_and(|node| self.is_terminal(node))
+    }
+
+    /// Checks if any word starts with `prefix`
+    pub fn has_prefix(&self, prefix: &str) -> bool {
+        self.walk(Self::ROOT, prefix).is_some()
+    }
+
+    /// Collects every word reachable from `node`, each prefixed with `prefix`
+    pub fn collect_words(&self, node: NodeId, prefix: &str, out: &mut Vec<String>) {
+        let mut current = prefix.to_string();
+        self.collect_from(node, &mut current, out);
+    }
+
+    fn collect_from(&self, node: NodeId, current: &mut String, out: &mut Vec<String>) {
+        if self.is_terminal(node) {
+            out.push(current.clone());
+        }
+        for (letter, child) in self.edges(node) {
+            current.push(letter);
+            self.collect_from(child, current, out);
+            current.pop();
+        }
+    }
+}
+
+impl Default for Dawg {
+    fn default() -> Self {
+        Self {
+            nodes: vec![DawgNode::default()],
+        }
+    }
+}
+
+/// Incremental DAWG construction state
+struct DawgBuilder {
+    nodes: Vec<DawgNode>,
+    /// Path of the previous word whose tail is not yet minimized: (parent, letter, child)
+    unchecked: Vec<(NodeId, char, NodeId)>,
+    /// Canonical node for each distinct (edges, terminal) signature
+    register: HashMap<DawgNode, NodeId>,
+    previous: String,
+}
+
+impl DawgBuilder {
+    fn new() -> Self {
+        Self {
+            nodes: vec![DawgNode::default()],
+            unchecked: Vec::new(),
+            register: HashMap::new(),
+            previous: String::new(),
+        }
+    }
+
+    /// Inserts the next word; words must arrive in sorted order
+    fn insert(&mut self, word: &str) {
+        let common = word
+            .chars()
+            .zip(self.previous.chars())
+            .take_while(|(a, b)| a == b)
+            .count();
+
+        self.minimize(common);
+
+        let mut node = self.unchecked.last().map_or(Dawg::ROOT, |&(_, _, child)| child);
+        for letter in word.chars().skip(common) {
+            let child = self.nodes.len() as NodeId;
+            self.nodes.push(DawgNode::default());
+            self.nodes[node as usize].edges.push((letter, child));
+            self.unchecked.push((node, letter, child));
+            node = child;
+        }
+
+        self.nodes[node as usize].is_terminal = true;
+        self.previous = word.to_string();
+    }
+
+    /// Merges unchecked nodes deeper than `down_to` with equivalent registered nodes
+    fn minimize(&mut self, down_to: usize) {
+        while self.unchecked.len() > down_to {
+            let (parent, _, child) = self.unchecked.pop().unwrap();
+            let signature = self.nodes[child as usize].clone();
+
+            if let Some(&existing) = self.register.get(&signature) {
+                if let Some(edge) = self.nodes[parent as usize].edges.last_mut() {
+                    edge.1 = existing;
+                }
+            } else {
+                self.register.insert(signature, child);
+            }
+        }
+    }
+
+    /// Finishes minimization and drops nodes that were merged away
+    fn finish(mut self) -> Dawg {
+        self.minimize(0);
+
+        // Renumber reachable nodes so the graph is compact
+        let mut remap: Vec<Option<NodeId>> = vec![None; self.nodes.len()];
+        let mut order = vec![Dawg::ROOT];
+        remap[Dawg::ROOT as usize] = Some(0);
+        let mut next = 0;
+
+        while next < order.len() {
+            let old = order[next];
+            next += 1;
+            for &(_, child) in &self.nodes[old as usize].edges {
+                if remap[child as usize].is_none() {
+                    remap[child as usize] = Some(order.len() as NodeId);
+                    order.push(child);
+                }
+            }
+        }
+
+        let nodes = order
+            .iter()
+            .map(|&old| {
+                let node = &self.nodes[old as usize];
+                DawgNode {
+                    edges: node
+                        .edges
+                        .iter()
+                        .map(|&(letter, child)| (letter, remap[child as usize].unwrap()))
+                        .collect(),
+                    is_terminal: node.is_terminal,
+                }
+            })
+            .collect();
+
+        Dawg { nodes }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_membership_and_prefixes() {
+        let dawg = Dawg::build(["CAT", "CATS", "CAR", "DOG"]);
+
+        assert!(dawg.contains("CAT"));
+        assert!(dawg.contains("CATS"));
+        assert!(!dawg.contains("CA"));
+        assert!(!dawg.contains("COW"));
+        assert!(dawg.has_prefix("CA"));
+        assert!(!dawg.has_prefix("DA"));
+    }
+
+    #[test]
+    fn test_suffixes_are_shared() {
+        // "TAKES"/"MAKES" and "TAKER"/"MAKER" share everything after the first letter
+        let dawg = Dawg::build(["MAKER", "MAKES", "TAKER", "TAKES"]);
+        let trie_nodes = 1 + 5 + 1 + 5 + 1;
+
+        assert!(dawg.node_count() < trie_nodes);
+        let root_children: Vec<NodeId> = dawg.edges(Dawg::ROOT).map(|(_, n)| n).collect();
+        assert_eq!(root_children[0], root_children[1]);
+    }
+
+    #[test]
+    fn test_collect_words() {
+        let dawg = Dawg::build(["BAT", "BATH", "BATS", "CAT"]);
+        let node = dawg.walk(Dawg::ROOT, "BAT").unwrap();
+
+        let mut words = Vec::new();
+        dawg.collect_words(node, "BAT", &mut words);
+        assert_eq!(words, vec!["BAT", "BATH", "BATS"]);
+    }
+}
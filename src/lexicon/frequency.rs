@@ -0,0 +1,194 @@
+/// Word commonness ranking
+///
+/// Ranks every word of a lexicon from most to least common, so AI opponents
+/// can be limited to the vocabulary a player of their level would know. The
+/// ranking starts from a shipped list of common words (most common first);
+/// inflections of listed words (-S, -ED, -ING, ...) come right after the
+/// listed words, and everything else is ordered by how plain it looks:
+/// common letters and everyday lengths first.
+
+use bevy::prelude::*;
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use super::Lexicon;
+
+/// Commonness list looked for at startup
+pub const COMMON_WORDS_PATH: &str = "assets/lexicons/COMMON.txt";
+
+/// Letters from most to least frequent in English text
+const LETTERS_BY_FREQUENCY: &str = "ETAOINSHRDLCUMWFGYPBVKJXQZ";
+
+/// Suffixes that turn a common word into an equally familiar one, with the
+/// ending to put back on the stem
+const INFLECTIONS: [(&str, &str); 12] = [
+    ("IES", "Y"),
+    ("IED", "Y"),
+    ("ING", "E"),
+    ("ING", ""),
+    ("ED", "E"),
+    ("ED", ""),
+    ("ERS", ""),
+    ("ER", ""),
+    ("ES", ""),
+    ("LY", ""),
+    ("S", ""),
+    ("D", ""),
+];
+
+/// How common a word is; smaller sorts first
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Commonness {
+    /// On the commonness list, by rank
+    Listed(usize),
+    /// Inflection of a listed word, by the stem's rank
+    Inflected(usize),
+    /// Not derived from the list, by plainness (lower is plainer)
+    Unlisted(usize),
+}
+
+/// Rank of each word on the commonness list
+#[derive(Resource, Debug, Clone, Default)]
+pub struct WordFrequency {
+    ranks: HashMap<String, usize>,
+}
+
+impl WordFrequency {
+    /// Loads the shipped commonness list, or an empty ranking if it's missing
+    pub fn load_default() -> Self {
+        match Self::load_from_file(COMMON_WORDS_PATH) {
+            Ok(frequency) => frequency,
+            Err(e) => {
+                warn!("No commonness list ({}); ranking words by plainness only", e);
+                Self::default()
+            }
+        }
+    }
+
+    /// Loads a commonness list: one word per line, most common first,
+    /// '#' lines are comments
+    pub fn load_from_file<P: AsRef<Path>>(path: P) -> Result<Self, String> {
+        let contents = fs::read_to_string(path.as_ref())
+            .map_err(|e| format!("Failed to read commonness list: {}", e))?;
+        Ok(Self::from_ranked_words(
+            contents.lines().filter(|line| !line.trim_start().starts_with('#')),
+        ))
+    }
+
+    /// Builds a ranking from words listed most common first
+    pub fn from_ranked_words<I, S>(words: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut ranks = HashMap::new();
+        for word in words {
+            let word = word.as_ref().trim().to_uppercase();
+            if !word.is_empty() {
+                let rank = ranks.len();
+                ranks.entry(word).or_insert(rank);
+            }
+        }
+        Self { ranks }
+    }
+
+    /// How common `word` is (expects uppercase)
+    pub fn commonness(&self, word: &str) -> Commonness {
+        if let Some(&rank) = self.ranks.get(word) {
+            return Commonness::Listed(rank);
+        }
+        if let Some(rank) = self.stem_rank(word) {
+            return Commonness::Inflected(rank);
+        }
+        Commonness::Unlisted(plainness(word))
+    }
+
+    /// Best rank among the listed words `word` could be an inflection of
+    fn stem_rank(&self, word: &str) -> Option<usize> {
+        let mut best: Option<usize> = None;
+        for (suffix, ending) in INFLECTIONS {
+            let Some(stem) = word.strip_suffix(suffix) else {
+                continue;
+            };
+            if stem.len() < 2 {
+                continue;
+            }
+
+            let mut candidates = vec![format!("{}{}", stem, ending)];
+            // Doubled final consonant: STOPPED, RUNNING
+            let bytes = stem.as_bytes();
+            if ending.is_empty() && bytes.len() >= 3 && bytes[bytes.len() - 1] == bytes[bytes.len() - 2] {
+                candidates.push(stem[..stem.len() - 1].to_string());
+            }
+
+            for candidate in candidates {
+                if let Some(&rank) = self.ranks.get(&candidate) {
+                    best = Some(best.map_or(rank, |b| b.min(rank)));
+                }
+            }
+        }
+        best
+    }
+
+    /// The most common `fraction` of `lexicon` as a lexicon of its own
+    pub fn vocabulary(&self, lexicon: &Lexicon, fraction: f32) -> Lexicon {
+        if fraction >= 1.0 {
+            return lexicon.clone();
+        }
+
+        let mut words = lexicon.words_with_prefix("");
+        let keep = ((words.len() as f32 * fraction.max(0.0)).ceil() as usize).min(words.len());
+        words.sort_by_cached_key(|word| (self.commonness(word), word.clone()));
+        words.truncate(keep);
+
+        Lexicon::from_words(
+            words,
+            &format!("{} ({}%)", lexicon.lexicon_name, (fraction * 100.0).round()),
+        )
+    }
+}
+
+/// Rarity of a word's letters plus distance from an everyday length
+fn plainness(word: &str) -> usize {
+    let letters: usize = word
+        .chars()
+        .map(|c| LETTERS_BY_FREQUENCY.find(c).unwrap_or(LETTERS_BY_FREQUENCY.len()))
+        .sum();
+    let average = letters / word.len().max(1);
+    average * 10 + word.len().abs_diff(5) * 15
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_commonness_tiers() {
+        let frequency = WordFrequency::from_ranked_words(["the", "stop", "make", "carry"]);
+
+        assert_eq!(frequency.commonness("STOP"), Commonness::Listed(1));
+        assert_eq!(frequency.commonness("MAKE"), Commonness::Listed(2));
+        assert_eq!(frequency.commonness("STOPPED"), Commonness::Inflected(1));
+        assert_eq!(frequency.commonness("MAKING"), Commonness::Inflected(2));
+        assert_eq!(frequency.commonness("CARRIES"), Commonness::Inflected(3));
+        assert!(matches!(frequency.commonness("QOPH"), Commonness::Unlisted(_)));
+
+        // Plain words come before ones full of rare letters
+        assert!(frequency.commonness("TREAT") < frequency.commonness("JUKED"));
+        assert!(frequency.commonness("STOPS") < frequency.commonness("TREAT"));
+    }
+
+    #[test]
+    fn test_vocabulary_keeps_common_words() {
+        let lexicon = Lexicon::from_words(["CAT", "CATS", "DOG", "QAT", "ZAX", "XYST"], "Test");
+        let frequency = WordFrequency::from_ranked_words(["dog", "cat"]);
+
+        let vocabulary = frequency.vocabulary(&lexicon, 0.5);
+        assert_eq!(vocabulary.word_count(), 3);
+        assert!(vocabulary.is_valid("DOG"));
+        assert!(vocabulary.is_valid("CATS"));
+        assert!(!vocabulary.is_valid("ZAX"));
+
+        assert_eq!(frequency.vocabulary(&lexicon, 1.0).word_count(), 6);
+    }
+}
@@ -0,0 +1,133 @@
+/// Reader for KWG word graphs (the binary format produced by wolges' buildlex)
+///
+/// A KWG file is a flat array of little-endian u32 nodes. Each node packs a
+/// tile (bits 24-31), an "accepts" flag (bit 23), an "is end of sibling list"
+/// flag (bit 22) and the index of its first child (bits 0-21). Node 0 points
+/// at the DAWG root and node 1 at the GADDAG root; only the DAWG half is
+/// needed to recover the word list.
+
+use std::fs;
+use std::path::Path;
+
+const ACCEPTS_FLAG: u32 = 0x0080_0000;
+const IS_END_FLAG: u32 = 0x0040_0000;
+const ARC_INDEX_MASK: u32 = 0x003f_ffff;
+
+/// Longest word the reader will follow before treating the file as corrupt
+const MAX_WORD_LENGTH: usize = 64;
+
+/// Reads a KWG file and returns every word in its DAWG (uppercase, sorted)
+pub fn read_kwg_file<P: AsRef<Path>>(path: P) -> Result<Vec<String>, String> {
+    let bytes = fs::read(path.as_ref())
+        .map_err(|e| format!("Failed to read KWG file: {}", e))?;
+    read_kwg_words(&bytes)
+}
+
+/// Decodes KWG bytes into the list of words stored in its DAWG
+pub fn read_kwg_words(bytes: &[u8]) -> Result<Vec<String>, String> {
+    if !bytes.len().is_multiple_of(4) {
+        return Err(format!("Invalid KWG file: size {} is not a multiple of 4", bytes.len()));
+    }
+
+    let nodes: Vec<u32> = bytes
+        .chunks_exact(4)
+        .map(|chunk| u32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]))
+        .collect();
+
+    if nodes.len() < 2 {
+        return Err("Invalid KWG file: missing DAWG and GADDAG root nodes".to_string());
+    }
+
+    let mut words = Vec::new();
+    let dawg_root = nodes[0] & ARC_INDEX_MASK;
+    if dawg_root != 0 {
+        let mut current = String::new();
+        collect_words(&nodes, dawg_root as usize, &mut current, &mut words)?;
+    }
+
+    words.sort();
+    Ok(words)
+}
+
+/// Walks the sibling list starting at `index`, collecting accepted words
+fn collect_words(
+    nodes: &[u32],
+    index: usize,
+    current: &mut String,
+    words: &mut Vec<String>,
+) -> Result<(), String> {
+    if current.len() >= MAX_WORD_LENGTH {
+        return Err("Invalid KWG file: word graph is too deep".to_string());
+    }
+
+    let mut i = index;
+    loop {
+        let node = *nodes
+            .get(i)
+            .ok_or_else(|| format!("Invalid KWG file: node index {} out of range", i))?;
+
+        current.push(tile_to_letter(node >> 24)?);
+
+        if node & ACCEPTS_FLAG != 0 {
+            words.push(current.clone());
+        }
+
+        let arc_index = (node & ARC_INDEX_MASK) as usize;
+        if arc_index != 0 {
+            collect_words(nodes, arc_index, current, words)?;
+        }
+
+        current.pop();
+
+        if node & IS_END_FLAG != 0 {
+            return Ok(());
+        }
+        i += 1;
+    }
+}
+
+/// Maps a KWG tile number (1 = A ... 26 = Z) to its letter
+fn tile_to_letter(tile: u32) -> Result<char, String> {
+    match tile {
+        1..=26 => Ok((b'A' + (tile as u8 - 1)) as char),
+        _ => Err(format!("Invalid KWG file: unsupported tile {} in DAWG", tile)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn node(tile: u32, accepts: bool, is_end: bool, arc_index: u32) -> u32 {
+        (tile << 24)
+            | if accepts { ACCEPTS_FLAG } else { 0 }
+            | if is_end { IS_END_FLAG } else { 0 }
+            | arc_index
+    }
+
+    fn to_bytes(nodes: &[u32]) -> Vec<u8> {
+        nodes.iter().flat_map(|n| n.to_le_bytes()).collect()
+    }
+
+    #[test]
+    fn test_reads_dawg_words() {
+        // Words: AB, ABS, AC
+        let nodes = [
+            node(0, false, true, 2), // DAWG root pointer
+            node(0, false, true, 0), // GADDAG root pointer (unused)
+            node(1, false, true, 3), // A
+            node(2, true, false, 5), // AB
+            node(3, true, true, 0),  // AC
+            node(19, true, true, 0), // ABS
+        ];
+
+        let words = read_kwg_words(&to_bytes(&nodes)).unwrap();
+        assert_eq!(words, vec!["AB", "ABS", "AC"]);
+    }
+
+    #[test]
+    fn test_rejects_truncated_file() {
+        assert!(read_kwg_words(&[0, 0, 0]).is_err());
+        assert!(read_kwg_words(&to_bytes(&[node(0, false, true, 9), 0])).is_err());
+    }
+}
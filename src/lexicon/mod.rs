@@ -1,18 +1,30 @@
 /// Lexicon module for word validation
 ///
-/// This module provides fast word validation using a HashSet-based approach
-/// optimized for various word list formats (CSW24, ENABLE, etc.).
+/// This module provides fast word validation backed by a DAWG (directed
+/// acyclic word graph), which also answers prefix, completion and hook
+/// queries for move generation and word drills, across various word list
+/// formats (CSW24, ENABLE, etc.).
 
 use bevy::prelude::*;
-use std::collections::HashSet;
+use std::collections::BTreeSet;
 use std::fs;
 use std::path::Path;
+use std::sync::Arc;
+
+pub mod dawg;
+pub mod frequency;
+pub mod kwg;
+
+pub use dawg::{Dawg, NodeId};
+pub use frequency::WordFrequency;
 
 /// Lexicon structure containing all valid words
 #[derive(Resource, Debug, Clone)]
 pub struct Lexicon {
-    /// All valid words stored in a HashSet for O(1) lookup
-    words: HashSet<String>,
+    /// All valid words as a forward DAWG (shared, so clones are cheap)
+    dawg: Arc<Dawg>,
+    /// Every word spelled backwards, for suffix and front-hook queries
+    reverse_dawg: Arc<Dawg>,
     /// Total word count
     word_count: usize,
     /// Name of the loaded lexicon (e.g., "CSW24", "ENABLE", "Custom")
@@ -23,43 +35,89 @@ impl Lexicon {
     /// Creates a new empty lexicon
     pub fn new() -> Self {
         Self {
-            words: HashSet::new(),
+            dawg: Arc::new(Dawg::default()),
+            reverse_dawg: Arc::new(Dawg::default()),
             word_count: 0,
             lexicon_name: "Empty".to_string(),
         }
     }
 
-    /// Loads lexicon from a text file (one word per line)
+    /// Loads lexicon from a file, picking the format by extension
+    ///
+    /// `.kwg` files are read as compiled KWG word graphs; anything else is
+    /// treated as a text file (one word per line).
     ///
     /// # Arguments
-    /// * `path` - Path to the lexicon file (e.g., "CSW24.txt")
+    /// * `path` - Path to the lexicon file (e.g., "CSW24.txt", "CSW24.kwg")
     ///
     /// # Returns
     /// * `Result<Lexicon, String>` - Loaded lexicon or error message
     pub fn load_from_file<P: AsRef<Path>>(path: P) -> Result<Self, String> {
         let path_ref = path.as_ref();
+
+        let is_kwg = path_ref
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("kwg"));
+        if is_kwg {
+            return Self::load_from_kwg(path_ref);
+        }
+
         let content = fs::read_to_string(path_ref)
             .map_err(|e| format!("Failed to read lexicon file: {}", e))?;
 
-        let mut words = HashSet::new();
+        Ok(Self::from_words(content.lines(), &Self::name_from_path(path_ref)))
+    }
 
-        for line in content.lines() {
-            let word = line.trim().trim_matches('\r').to_uppercase();
-            if !word.is_empty() {
-                words.insert(word);
-            }
+    /// Loads lexicon from a compiled KWG word graph (wolges buildlex format)
+    ///
+    /// # Arguments
+    /// * `path` - Path to the KWG file (e.g., "assets/lexicons/CSW24.kwg")
+    ///
+    /// # Returns
+    /// * `Result<Lexicon, String>` - Loaded lexicon or error message
+    pub fn load_from_kwg<P: AsRef<Path>>(path: P) -> Result<Self, String> {
+        let path_ref = path.as_ref();
+        let words = kwg::read_kwg_file(path_ref)?;
+
+        if words.is_empty() {
+            return Err(format!("KWG file {} contains no words", path_ref.display()));
         }
 
-        let word_count = words.len();
+        Ok(Self::from_words(words, &Self::name_from_path(path_ref)))
+    }
 
-        // Extract lexicon name from filename (e.g., "CSW24.txt" -> "CSW24")
-        let lexicon_name = path_ref
-            .file_stem()
+    /// Extracts lexicon name from filename (e.g., "CSW24.txt" -> "CSW24")
+    fn name_from_path(path: &Path) -> String {
+        path.file_stem()
             .and_then(|s| s.to_str())
             .unwrap_or("Unknown")
-            .to_string();
+            .to_string()
+    }
+
+    /// Builds a lexicon from an in-memory word list
+    ///
+    /// Words are trimmed and uppercased; blank entries are skipped.
+    pub fn from_words<I, S>(words: I, lexicon_name: &str) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let words: BTreeSet<String> = words
+            .into_iter()
+            .map(|line| line.as_ref().trim().trim_matches('\r').to_uppercase())
+            .filter(|word| !word.is_empty())
+            .collect();
 
-        Ok(Self { words, word_count, lexicon_name })
+        let word_count = words.len();
+        let reversed: Vec<String> = words.iter().map(|w| w.chars().rev().collect()).collect();
+
+        Self {
+            dawg: Arc::new(Dawg::build(&words)),
+            reverse_dawg: Arc::new(Dawg::build(&reversed)),
+            word_count,
+            lexicon_name: lexicon_name.to_string(),
+        }
     }
 
     /// Attempts to load a lexicon from multiple sources (in priority order)
@@ -71,16 +129,24 @@ impl Lexicon {
     /// 4. ENABLE.txt (original public domain, 173K words)
     /// 5. custom.txt (user-provided)
     ///
+    /// A compiled `.kwg` with the same name is preferred over its `.txt`.
+    ///
     /// # Returns
     /// * `Result<Lexicon, String>` - First successfully loaded lexicon or error if none found
     pub fn load_default() -> Result<Self, String> {
         let paths = vec![
+            "assets/lexicons/CSW24.kwg",
             "assets/lexicons/CSW24.txt",
+            "assets/lexicons/TML.kwg",
             "assets/lexicons/TML.txt",
+            "assets/lexicons/RE-ENABLE.kwg",
             "assets/lexicons/RE-ENABLE.txt",
+            "assets/lexicons/ENABLE.kwg",
             "assets/lexicons/ENABLE.txt",
+            "assets/lexicons/custom.kwg",
             "assets/lexicons/custom.txt",
-            "CSW24.txt",  // Try root directory as fallback
+            "CSW24.kwg",  // Try root directory as fallback
+            "CSW24.txt",
             "TML.txt",
             "RE-ENABLE.txt",
             "ENABLE.txt",
@@ -91,8 +157,8 @@ impl Lexicon {
         for path in paths {
             match Self::load_from_file(path) {
                 Ok(lexicon) => {
-                    println!("✓ Loaded {} lexicon with {} words from {}",
-                             lexicon.lexicon_name, lexicon.word_count, path);
+                    info!("✓ Loaded {} lexicon with {} words ({} graph nodes) from {}",
+                             lexicon.lexicon_name, lexicon.word_count, lexicon.dawg.node_count(), path);
                     return Ok(lexicon);
                 }
                 Err(e) => {
@@ -124,7 +190,96 @@ impl Lexicon {
     /// * `bool` - true if valid, false otherwise
     pub fn is_valid(&self, word: &str) -> bool {
         let normalized = word.trim().to_uppercase();
-        self.words.contains(&normalized)
+        self.dawg.contains(&normalized)
+    }
+
+    /// Checks if any word in the lexicon starts with `prefix` (case-insensitive)
+    ///
+    /// A complete word counts as its own prefix.
+    pub fn is_prefix(&self, prefix: &str) -> bool {
+        let normalized = prefix.trim().to_uppercase();
+        self.dawg.has_prefix(&normalized)
+    }
+
+    /// Checks if any word in the lexicon ends with `suffix` (case-insensitive)
+    pub fn is_suffix(&self, suffix: &str) -> bool {
+        let reversed: String = suffix.trim().to_uppercase().chars().rev().collect();
+        self.reverse_dawg.has_prefix(&reversed)
+    }
+
+    /// Gets all words starting with `prefix` (sorted)
+    pub fn words_with_prefix(&self, prefix: &str) -> Vec<String> {
+        let normalized = prefix.trim().to_uppercase();
+        let mut words = Vec::new();
+        if let Some(node) = self.dawg.walk(Dawg::ROOT, &normalized) {
+            self.dawg.collect_words(node, &normalized, &mut words);
+        }
+        words
+    }
+
+    /// Gets all words ending with `suffix` (sorted)
+    pub fn words_with_suffix(&self, suffix: &str) -> Vec<String> {
+        let reversed: String = suffix.trim().to_uppercase().chars().rev().collect();
+        let mut reversed_words = Vec::new();
+        if let Some(node) = self.reverse_dawg.walk(Dawg::ROOT, &reversed) {
+            self.reverse_dawg.collect_words(node, &reversed, &mut reversed_words);
+        }
+
+        let mut words: Vec<String> = reversed_words
+            .iter()
+            .map(|w| w.chars().rev().collect())
+            .collect();
+        words.sort();
+        words
+    }
+
+    /// Letters that can directly follow `prefix` on the way to some word
+    pub fn children_of(&self, prefix: &str) -> Vec<char> {
+        let normalized = prefix.trim().to_uppercase();
+        self.dawg
+            .walk(Dawg::ROOT, &normalized)
+            .map(|node| self.dawg.edges(node).map(|(letter, _)| letter).collect())
+            .unwrap_or_default()
+    }
+
+    /// Letters that can directly precede `suffix` on the way to some word
+    pub fn parents_of(&self, suffix: &str) -> Vec<char> {
+        let reversed: String = suffix.trim().to_uppercase().chars().rev().collect();
+        self.reverse_dawg
+            .walk(Dawg::ROOT, &reversed)
+            .map(|node| self.reverse_dawg.edges(node).map(|(letter, _)| letter).collect())
+            .unwrap_or_default()
+    }
+
+    /// Letters that can be added in front of `word` to make another word
+    pub fn front_hooks(&self, word: &str) -> Vec<char> {
+        let reversed: String = word.trim().to_uppercase().chars().rev().collect();
+        let Some(node) = self.reverse_dawg.walk(Dawg::ROOT, &reversed) else {
+            return Vec::new();
+        };
+        self.reverse_dawg
+            .edges(node)
+            .filter(|&(_, child)| self.reverse_dawg.is_terminal(child))
+            .map(|(letter, _)| letter)
+            .collect()
+    }
+
+    /// Letters that can be added to the end of `word` to make another word
+    pub fn back_hooks(&self, word: &str) -> Vec<char> {
+        let normalized = word.trim().to_uppercase();
+        let Some(node) = self.dawg.walk(Dawg::ROOT, &normalized) else {
+            return Vec::new();
+        };
+        self.dawg
+            .edges(node)
+            .filter(|&(_, child)| self.dawg.is_terminal(child))
+            .map(|(letter, _)| letter)
+            .collect()
+    }
+
+    /// The forward word graph, for callers that walk it node by node (e.g. move generation)
+    pub fn dawg(&self) -> &Dawg {
+        &self.dawg
     }
 
     /// Gets the total number of words in the lexicon
@@ -140,14 +295,12 @@ impl Lexicon {
     /// # Returns
     /// * `Vec<String>` - All words of that length (sorted)
     pub fn get_words_by_length(&self, length: usize) -> Vec<String> {
-        let mut filtered: Vec<String> = self.words
-            .iter()
-            .filter(|w| w.len() == length)
-            .cloned()
-            .collect();
+        let mut all = Vec::new();
+        self.dawg.collect_words(Dawg::ROOT, "", &mut all);
 
-        filtered.sort();
-        filtered
+        // DAWG traversal is already alphabetical
+        all.retain(|w| w.len() == length);
+        all
     }
 
     /// Gets all 2-letter words (optimized for Stage 1)
@@ -158,7 +311,7 @@ impl Lexicon {
     /// Checks if a word exists and returns it in uppercase if valid
     pub fn validate_and_normalize(&self, word: &str) -> Option<String> {
         let normalized = word.trim().to_uppercase();
-        if self.words.contains(&normalized) {
+        if self.dawg.contains(&normalized) {
             Some(normalized)
         } else {
             None
@@ -184,10 +337,7 @@ mod tests {
 
     #[test]
     fn test_word_validation() {
-        let mut lexicon = Lexicon::new();
-        lexicon.words.insert("AA".to_string());
-        lexicon.words.insert("AB".to_string());
-        lexicon.word_count = 2;
+        let lexicon = Lexicon::from_words(["AA", "AB"], "Test");
 
         assert!(lexicon.is_valid("AA"));
         assert!(lexicon.is_valid("aa")); // case-insensitive
@@ -195,13 +345,20 @@ mod tests {
         assert!(!lexicon.is_valid("ZZ"));
     }
 
+    #[test]
+    fn test_is_prefix() {
+        let lexicon = Lexicon::from_words(["CAT", "CATS"], "Test");
+
+        assert!(lexicon.is_prefix("C"));
+        assert!(lexicon.is_prefix("ca"));
+        assert!(lexicon.is_prefix("CAT")); // complete words are prefixes too
+        assert!(!lexicon.is_prefix("CATSS"));
+        assert!(!lexicon.is_prefix("D"));
+    }
+
     #[test]
     fn test_get_words_by_length() {
-        let mut lexicon = Lexicon::new();
-        lexicon.words.insert("AA".to_string());
-        lexicon.words.insert("AB".to_string());
-        lexicon.words.insert("ABC".to_string());
-        lexicon.word_count = 3;
+        let lexicon = Lexicon::from_words(["AA", "AB", "ABC"], "Test");
 
         let two_letter = lexicon.get_words_by_length(2);
         assert_eq!(two_letter.len(), 2);
@@ -212,4 +369,24 @@ mod tests {
         assert_eq!(three_letter.len(), 1);
         assert!(three_letter.contains(&"ABC".to_string()));
     }
+
+    #[test]
+    fn test_prefix_and_suffix_queries() {
+        let lexicon = Lexicon::from_words(["CARE", "CARES", "CART", "SCARE", "RACE"], "Test");
+
+        assert_eq!(lexicon.words_with_prefix("car"), vec!["CARE", "CARES", "CART"]);
+        assert_eq!(lexicon.children_of("CAR"), vec!['E', 'T']);
+        assert!(lexicon.is_suffix("ARE"));
+        assert_eq!(lexicon.words_with_suffix("ARE"), vec!["CARE", "SCARE"]);
+        assert_eq!(lexicon.parents_of("CARE"), vec!['S']);
+    }
+
+    #[test]
+    fn test_hooks() {
+        let lexicon = Lexicon::from_words(["CARE", "CARES", "CARED", "SCARE"], "Test");
+
+        assert_eq!(lexicon.front_hooks("CARE"), vec!['S']);
+        assert_eq!(lexicon.back_hooks("care"), vec!['D', 'S']);
+        assert!(lexicon.back_hooks("CARES").is_empty());
+    }
 }
@@ -10,7 +10,7 @@ mod stage3;
 mod stage4;
 mod stage5;
 
-use plugins::{CorePlugin, StatePlugin, AssetPlugin, InputPlugin, SettingsPlugin};
+use plugins::{CorePlugin, StatePlugin, AssetPlugin, InputPlugin, SettingsPlugin, RngPlugin, RatingPlugin};
 use ui::UiPlugin;
 use stage1::Stage1Plugin;
 use stage2::Stage2Plugin;
@@ -34,6 +34,8 @@ fn main() {
             AssetPlugin,
             InputPlugin,
             SettingsPlugin,
+            RngPlugin,
+            RatingPlugin,
             UiPlugin,
             Stage1Plugin,
             Stage2Plugin,
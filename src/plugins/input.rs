@@ -22,6 +22,7 @@ pub enum InputAction {
     PlaceTile,   // Left Click
     RotateTile,  // R key
     SwapTiles,   // S key (or could be Settings in menu)
+    PassTurn,    // X key
     Hint,        // H key
     Undo,        // Ctrl+Z or U
 
@@ -132,6 +133,7 @@ fn map_key_to_actions(key: KeyCode, actions: &mut Vec<InputAction>) {
         KeyCode::KeyH => actions.push(InputAction::Hint),
         KeyCode::KeyU => actions.push(InputAction::Undo),
         KeyCode::KeyP => actions.push(InputAction::Pause),
+        KeyCode::KeyX => actions.push(InputAction::PassTurn),
 
         // Context-dependent: S can be Settings in menu, SwapTiles in game
         KeyCode::KeyS => {
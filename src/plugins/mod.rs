@@ -3,9 +3,14 @@ pub mod state;
 pub mod assets;
 pub mod input;
 pub mod settings;
+pub mod rng;
+pub mod rating;
+pub mod rules;
 
 pub use core::CorePlugin;
 pub use state::StatePlugin;
 pub use assets::AssetPlugin;
 pub use input::InputPlugin;
 pub use settings::SettingsPlugin;
+pub use rng::RngPlugin;
+pub use rating::RatingPlugin;
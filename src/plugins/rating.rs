@@ -0,0 +1,183 @@
+/// Player rating
+///
+/// The player carries an Elo rating from game to game, saved to
+/// `rating.ron` in the user data dir along with every change. AI opponents
+/// have fixed published ratings: the Stage 3 AI by difficulty level, and
+/// each tournament persona its own. Practice games are rated one at a time;
+/// a tournament match is rated once, on its result, so its games aren't
+/// counted twice. New players are provisional (a bigger K) for their first
+/// games so they find their level quickly.
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+
+/// Where a new player starts
+pub const INITIAL_RATING: f64 = 1000.0;
+
+/// Rated games played while the rating is still provisional
+pub const PROVISIONAL_GAMES: u32 = 20;
+
+/// How far one result can move the rating: provisional, then established
+const PROVISIONAL_K: f64 = 40.0;
+const ESTABLISHED_K: f64 = 20.0;
+
+/// Published rating of the Stage 3 AI at a difficulty level (1-5)
+pub fn level_rating(level: u8) -> u32 {
+    700 + 200 * level.clamp(1, 5) as u32
+}
+
+/// Chance of beating an opponent, by the Elo formula
+pub fn expected_score(rating: f64, opponent_rating: f64) -> f64 {
+    1.0 / (1.0 + 10f64.powf((opponent_rating - rating) / 400.0))
+}
+
+/// One rated result
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RatingChange {
+    /// When it was rated (local time)
+    pub date: String,
+    pub opponent: String,
+    pub opponent_rating: u32,
+    /// 1 for a win, 0.5 for a tie, 0 for a loss
+    pub score: f64,
+    pub before: f64,
+    pub after: f64,
+    /// A whole tournament match rather than a single game
+    pub is_match: bool,
+}
+
+impl RatingChange {
+    /// Rating points gained (negative if lost), rounded
+    pub fn delta(&self) -> i32 {
+        self.after.round() as i32 - self.before.round() as i32
+    }
+}
+
+/// The player's rating and how it got there
+#[derive(Resource, Debug, Clone, Serialize, Deserialize)]
+pub struct PlayerRating {
+    pub rating: f64,
+    /// Rated games and matches so far
+    pub games: u32,
+    pub history: Vec<RatingChange>,
+    /// Change from the result just rated, for the results screen
+    #[serde(skip)]
+    pub last_change: Option<RatingChange>,
+}
+
+impl Default for PlayerRating {
+    fn default() -> Self {
+        Self {
+            rating: INITIAL_RATING,
+            games: 0,
+            history: Vec::new(),
+            last_change: None,
+        }
+    }
+}
+
+impl PlayerRating {
+    /// Rating as shown to the player
+    pub fn display(&self) -> u32 {
+        self.rating.round().max(0.0) as u32
+    }
+
+    pub fn is_provisional(&self) -> bool {
+        self.games < PROVISIONAL_GAMES
+    }
+
+    /// Rates a result against an opponent and adds it to the history
+    pub fn record(&mut self, opponent: &str, opponent_rating: u32, score: f64, is_match: bool) -> &RatingChange {
+        let k = if self.is_provisional() { PROVISIONAL_K } else { ESTABLISHED_K };
+        let before = self.rating;
+        self.rating += k * (score - expected_score(before, opponent_rating as f64));
+        self.games += 1;
+
+        let change = RatingChange {
+            date: chrono::Local::now().format("%Y-%m-%d %H:%M").to_string(),
+            opponent: opponent.to_string(),
+            opponent_rating,
+            score,
+            before,
+            after: self.rating,
+            is_match,
+        };
+        info!("📈 Rating {} → {} ({:+}) vs {}", before.round(), self.rating.round(), change.delta(), opponent);
+        self.history.push(change.clone());
+        self.last_change.insert(change)
+    }
+
+    /// Where the rating is saved
+    fn rating_path() -> Option<PathBuf> {
+        dirs::data_dir().map(|dir| dir.join("tilemania").join("rating.ron"))
+    }
+
+    /// Load the rating, or start a new one if there isn't one yet
+    pub fn load() -> Self {
+        let Some(path) = Self::rating_path() else {
+            return Self::default();
+        };
+
+        if let Ok(contents) = fs::read_to_string(&path) {
+            match ron::from_str(&contents) {
+                Ok(rating) => {
+                    info!("✅ Loaded rating from {:?}", path);
+                    return rating;
+                }
+                Err(e) => {
+                    warn!("⚠️ Failed to parse rating file: {}. Starting a new rating.", e);
+                }
+            }
+        }
+
+        Self::default()
+    }
+
+    /// Save the rating and its history
+    pub fn save(&self) -> Result<(), String> {
+        let path = Self::rating_path().ok_or("No data directory")?;
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|e| format!("Failed to create data directory: {}", e))?;
+        }
+
+        let contents = ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default())
+            .map_err(|e| format!("Failed to serialize rating: {}", e))?;
+        fs::write(&path, contents)
+            .map_err(|e| format!("Failed to write rating file: {}", e))
+    }
+}
+
+pub struct RatingPlugin;
+
+impl Plugin for RatingPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(PlayerRating::load());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rating_changes() {
+        assert!((expected_score(1500.0, 1500.0) - 0.5).abs() < 1e-9);
+        assert!(expected_score(1700.0, 1500.0) > 0.75);
+
+        let mut rating = PlayerRating::default();
+        let change = rating.record("AI", 1000, 1.0, false).clone();
+        assert_eq!(change.delta(), 20);
+        assert_eq!(rating.display(), 1020);
+
+        // Beating a much weaker opponent gains little; losing to them costs a lot
+        let mut rating = PlayerRating::default();
+        let win = rating.record("Rookie", 600, 1.0, false).delta();
+        let loss = rating.record("Rookie", 600, 0.0, false).delta();
+        assert!(win < 5 && loss < -30);
+        assert_eq!(rating.history.len(), 2);
+        assert!(rating.is_provisional());
+    }
+}
@@ -0,0 +1,124 @@
+use bevy::prelude::*;
+use rand::rngs::StdRng;
+use rand::{RngCore, SeedableRng};
+
+/// Environment variable that fixes the seed of the first game
+const SEED_VAR: &str = "TILEMANIA_SEED";
+
+/// The one random number generator every stage draws from
+///
+/// Each game starts from its own seed (shown in the HUD and on the results
+/// screen), so replaying a seed replays the same tile bag, falling letters
+/// and AI decisions. Set `TILEMANIA_SEED` to start the first game from a
+/// given seed.
+#[derive(Resource)]
+pub struct GameRng {
+    seed: u64,
+    rng: StdRng,
+    /// Seed the next game starts from instead of a fresh one
+    next_seed: Option<u64>,
+    /// Whether the current game started from a chosen seed
+    replaying: bool,
+}
+
+impl Default for GameRng {
+    fn default() -> Self {
+        let mut rng = Self::from_seed(rand::random());
+        rng.next_seed = std::env::var(SEED_VAR).ok().and_then(|s| s.trim().parse().ok());
+        rng
+    }
+}
+
+impl GameRng {
+    pub fn from_seed(seed: u64) -> Self {
+        Self {
+            seed,
+            rng: StdRng::seed_from_u64(seed),
+            next_seed: None,
+            replaying: false,
+        }
+    }
+
+    /// Seed the current game started from
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Whether the current game replays a chosen seed
+    pub fn is_replay(&self) -> bool {
+        self.replaying
+    }
+
+    /// Reseeds for a new game and returns its seed
+    pub fn start_game(&mut self) -> u64 {
+        self.replaying = self.next_seed.is_some();
+        let seed = self.next_seed.take().unwrap_or_else(rand::random);
+        self.seed = seed;
+        self.rng = StdRng::seed_from_u64(seed);
+        info!("🎲 Game seed: {}", seed);
+        seed
+    }
+
+    /// Makes the next game start from `seed`
+    pub fn replay(&mut self, seed: u64) {
+        self.next_seed = Some(seed);
+    }
+
+    /// Independent generator split off this one, for work on other threads
+    pub fn fork(&mut self) -> StdRng {
+        StdRng::seed_from_u64(self.rng.next_u64())
+    }
+}
+
+impl RngCore for GameRng {
+    fn next_u32(&mut self) -> u32 {
+        self.rng.next_u32()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.rng.next_u64()
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.rng.fill_bytes(dest)
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+        self.rng.try_fill_bytes(dest)
+    }
+}
+
+pub struct RngPlugin;
+
+impl Plugin for RngPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<GameRng>();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::Rng;
+
+    #[test]
+    fn test_same_seed_same_game() {
+        let mut first = GameRng::from_seed(1);
+        let mut second = GameRng::from_seed(2);
+        second.replay(42);
+        first.replay(42);
+        assert_eq!(first.start_game(), 42);
+        second.start_game();
+        assert!(first.is_replay());
+
+        let rolls = |rng: &mut GameRng| (0..8).map(|_| rng.gen_range(0..100)).collect::<Vec<u32>>();
+        assert_eq!(rolls(&mut first), rolls(&mut second));
+        assert_eq!(first.fork().next_u64(), second.fork().next_u64());
+
+        // Without a replay each game gets a fresh seed
+        assert_eq!(second.seed(), 42);
+        second.start_game();
+        assert_ne!(second.seed(), 42);
+        assert!(!second.is_replay());
+    }
+}
@@ -0,0 +1,72 @@
+/// Game rules shared by the settings and the stages that play under them
+///
+/// `ChallengeRule` lives here rather than in Stage 3 so Settings can offer
+/// it without depending on a stage.
+
+use serde::{Deserialize, Serialize};
+
+/// How invalid words are handled
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum ChallengeRule {
+    /// Invalid words are rejected before they reach the board
+    #[default]
+    Void,
+    /// Phonies may be played; an unsuccessful challenge costs nothing
+    Single,
+    /// Phonies may be played; an unsuccessful challenge loses the challenger's turn
+    Double,
+    /// Phonies may be played; an unsuccessful challenge gives the opponent 5 points
+    FivePoint,
+}
+
+impl ChallengeRule {
+    /// Checks if phonies may be placed on the board
+    pub fn allows_phonies(&self) -> bool {
+        *self != ChallengeRule::Void
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            ChallengeRule::Void => "Void",
+            ChallengeRule::Single => "Single",
+            ChallengeRule::Double => "Double",
+            ChallengeRule::FivePoint => "Five-Point",
+        }
+    }
+
+    /// Next rule in the Settings cycle
+    pub fn next(self) -> Self {
+        match self {
+            ChallengeRule::Void => ChallengeRule::Single,
+            ChallengeRule::Single => ChallengeRule::Double,
+            ChallengeRule::Double => ChallengeRule::FivePoint,
+            ChallengeRule::FivePoint => ChallengeRule::Void,
+        }
+    }
+
+    /// Previous rule in the Settings cycle
+    pub fn previous(self) -> Self {
+        match self {
+            ChallengeRule::Void => ChallengeRule::FivePoint,
+            ChallengeRule::Single => ChallengeRule::Void,
+            ChallengeRule::Double => ChallengeRule::Single,
+            ChallengeRule::FivePoint => ChallengeRule::Double,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rule_cycle() {
+        let mut rule = ChallengeRule::Void;
+        for _ in 0..4 {
+            assert_eq!(rule.next().previous(), rule);
+            rule = rule.next();
+        }
+        assert_eq!(rule, ChallengeRule::Void);
+        assert_eq!(ChallengeRule::Double.next(), ChallengeRule::FivePoint);
+    }
+}
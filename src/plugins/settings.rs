@@ -2,6 +2,7 @@ use bevy::prelude::*;
 use serde::{Deserialize, Serialize};
 use std::fs;
 use std::path::PathBuf;
+use crate::plugins::rules::ChallengeRule;
 
 /// Global game settings resource
 #[derive(Resource, Clone, Debug, Serialize, Deserialize)]
@@ -23,6 +24,9 @@ pub struct GameplaySettings {
     pub dictionary: String,
     pub default_time_limit: u32,  // seconds
     pub default_difficulty: u8,   // 1-5
+    /// Rule for practice games on the Classic Board (tournaments play double)
+    #[serde(default)]
+    pub challenge_rule: ChallengeRule,
 }
 
 impl Default for GameSettings {
@@ -38,6 +42,7 @@ impl Default for GameSettings {
                 dictionary: "TML".to_string(),
                 default_time_limit: 25 * 60, // 25 minutes in seconds
                 default_difficulty: 3, // Medium
+                challenge_rule: ChallengeRule::Void,
             },
         }
     }
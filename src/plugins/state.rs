@@ -26,6 +26,8 @@ pub enum GameState {
     Stage2Paused,
     Stage3Playing,
     Stage3Paused,
+    /// Stepping through a finished board game
+    Stage3Review,
     Stage4Playing,
     Stage4Paused,
     Stage5Playing,
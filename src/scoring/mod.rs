@@ -6,6 +6,7 @@ use bevy::prelude::*;
 use std::collections::HashMap;
 
 /// Tile point values (standard letter distribution)
+#[derive(Clone)]
 pub struct TileValues {
     values: HashMap<char, u32>,
 }
@@ -84,7 +85,7 @@ impl Default for TileValues {
 }
 
 /// Scoring calculator for gameplay
-#[derive(Resource)]
+#[derive(Resource, Clone)]
 pub struct ScoreCalculator {
     tile_values: TileValues,
 }
@@ -4,6 +4,7 @@ use bevy::prelude::*;
 use rand::Rng;
 use super::components::*;
 use super::{Stage1Config, Stage1State};
+use crate::plugins::rng::GameRng;
 
 /// Chance of power-up spawning (5% per tile)
 const POWERUP_SPAWN_CHANCE: f32 = 0.05;
@@ -34,6 +35,7 @@ pub fn spawn_powerup_pickups(
     config: Res<Stage1Config>,
     asset_server: Res<AssetServer>,
     pickup_query: Query<&PowerUpPickup>,
+    mut rng: ResMut<GameRng>,
 ) {
     *last_spawn_time += time.delta_secs();
 
@@ -46,7 +48,6 @@ pub fn spawn_powerup_pickups(
             return;
         }
 
-        let mut rng = rand::thread_rng();
         if rng.gen::<f32>() < 0.7 {
             // 70% chance to spawn
             let powerup_type = match rng.gen_range(0..4) {
@@ -161,6 +162,7 @@ pub fn despawn_offscreen_powerups(
 }
 
 /// Handles keyboard-based power-up collection (1-4 keys and spacebar)
+#[allow(clippy::too_many_arguments)]
 pub fn collect_powerups(
     mut commands: Commands,
     keyboard: Res<ButtonInput<KeyCode>>,
@@ -169,6 +171,7 @@ pub fn collect_powerups(
     mut state: ResMut<Stage1State>,
     mut config: ResMut<Stage1Config>,
     tile_query: Query<(Entity, &Transform), With<FallingTile>>,
+    mut rng: ResMut<GameRng>,
 ) {
     // Check which key was pressed
     let target_number = if keyboard.just_pressed(KeyCode::Digit1) {
@@ -202,6 +205,7 @@ pub fn collect_powerups(
                     &mut state,
                     &mut config,
                     &tile_query,
+                    &mut rng,
                 );
 
                 // Despawn the collected powerup
@@ -287,6 +291,7 @@ pub fn activate_powerups(
     mut state: ResMut<Stage1State>,
     mut config: ResMut<Stage1Config>,
     tile_query: Query<(Entity, &Transform), With<FallingTile>>,
+    mut rng: ResMut<GameRng>,
 ) {
     // Check if player pressed 1, 2, 3, or 4
     let powerup_index = if keyboard.just_pressed(KeyCode::Digit1) {
@@ -311,6 +316,7 @@ pub fn activate_powerups(
                 &mut state,
                 &mut config,
                 &tile_query,
+                &mut rng,
             );
         }
     }
@@ -324,6 +330,7 @@ fn apply_powerup_effect(
     state: &mut Stage1State,
     config: &mut Stage1Config,
     tile_query: &Query<(Entity, &Transform), With<FallingTile>>,
+    rng: &mut GameRng,
 ) {
     match powerup {
         PowerUp::SlowMotion => {
@@ -370,7 +377,6 @@ fn apply_powerup_effect(
                 .collect();
 
             if !positions.is_empty() {
-                let mut rng = rand::thread_rng();
                 let mut shuffled_positions = positions.clone();
 
                 // Fisher-Yates shuffle
@@ -6,6 +6,7 @@ use super::components::*;
 use super::{Stage1Config, Stage1State};
 use super::visuals::{spawn_score_popup, spawn_particle_burst, TileColors, ValidationFlash};
 use super::powerups::{ActivePowerUps, get_fall_speed_multiplier};
+use crate::plugins::rng::GameRng;
 use crate::plugins::state::GameState;
 use crate::lexicon::Lexicon;
 use crate::scoring::ScoreCalculator;
@@ -34,6 +35,7 @@ pub fn spawn_falling_tiles(
     mut spawn_timer: Local<Option<Timer>>,
     config: Res<Stage1Config>,
     asset_server: Res<AssetServer>,
+    mut rng: ResMut<GameRng>,
 ) {
     // Initialize timer on first run
     if spawn_timer.is_none() {
@@ -45,10 +47,10 @@ pub fn spawn_falling_tiles(
 
         if timer.just_finished() {
             // Randomly select a letter weighted by standard tile distribution
-            let letter = get_weighted_random_letter();
+            let letter = get_weighted_random_letter(&mut *rng);
 
             // Randomly select a column
-            let column = rand::thread_rng().gen_range(0..config.column_count);
+            let column = rng.gen_range(0..config.column_count);
 
             // Calculate spawn position
             let x_pos = -400.0 + (column as f32 * 120.0); // Spread across screen
@@ -454,8 +456,7 @@ pub fn check_game_over(
 }
 
 /// Returns a random letter weighted by standard tile distribution
-fn get_weighted_random_letter() -> char {
-    let mut rng = rand::thread_rng();
+fn get_weighted_random_letter(rng: &mut impl Rng) -> char {
     let roll: u32 = rng.gen_range(0..100);
 
     // Standard English letter frequency distribution (simplified)
@@ -4,12 +4,14 @@ use bevy::prelude::*;
 use super::components::*;
 use super::{Stage1Config, Stage1State};
 use super::difficulty::{get_difficulty, DIFFICULTY_LEVELS};
+use crate::plugins::rng::GameRng;
 use crate::plugins::state::GameState;
 
 /// Spawns the in-game HUD (score, timer, combo)
 pub fn spawn_stage1_hud(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
+    rng: Res<GameRng>,
 ) {
     let font = asset_server.load("fonts/FiraSans-Bold.ttf");
 
@@ -101,9 +103,9 @@ pub fn spawn_stage1_hud(
                         WordDisplay,
                     ));
 
-                    // Help hint (press F1)
+                    // Help hint (press F1) and the game's seed
                     bottom.spawn((
-                        Text::new("Press F1 for Help"),
+                        Text::new(format!("Press F1 for Help  |  Seed {}", rng.seed())),
                         TextFont {
                             font: font.clone(),
                             font_size: 18.0,
@@ -324,6 +326,7 @@ pub fn handle_difficulty_selection(
     mut state: ResMut<Stage1State>,
     mut next_state: ResMut<NextState<GameState>>,
     start_screen_query: Query<Entity, With<StartScreen>>,
+    mut rng: ResMut<GameRng>,
 ) {
     for (interaction, button, mut bg_color) in interaction_query.iter_mut() {
         match *interaction {
@@ -337,6 +340,7 @@ pub fn handle_difficulty_selection(
                 config.fall_speed = difficulty.fall_speed;
 
                 // Initialize game state
+                rng.start_game();
                 state.score = 0;
                 state.time_remaining_ms = config.total_time_ms;
                 state.combo_count = 0;
@@ -623,6 +627,7 @@ pub fn spawn_results_screen(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
     state: Res<Stage1State>,
+    rng: Res<GameRng>,
     last_stage: Res<crate::plugins::state::LastStageCompleted>,
 ) {
     // Only spawn if this stage just completed
@@ -734,6 +739,17 @@ pub fn spawn_results_screen(
                 ));
             }
 
+            // Seed, to replay the same letters
+            parent.spawn((
+                Text::new(format!("Seed: {}", rng.seed())),
+                TextFont {
+                    font: font.clone(),
+                    font_size: 20.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.5, 0.5, 0.6)),
+            ));
+
             // Play again button
             parent
                 .spawn((
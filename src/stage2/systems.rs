@@ -4,6 +4,7 @@ use bevy::prelude::*;
 use rand::Rng;
 use super::components::*;
 use super::{Stage2Config, Stage2State};
+use crate::plugins::rng::GameRng;
 use crate::plugins::state::GameState;
 use crate::lexicon::Lexicon;
 use crate::scoring::ScoreCalculator;
@@ -16,6 +17,7 @@ pub fn spawn_grid(
     mut commands: Commands,
     config: Res<Stage2Config>,
     asset_server: Res<AssetServer>,
+    mut rng: ResMut<GameRng>,
 ) {
     let grid_size = config.grid_size;
     let total_width = (grid_size as f32) * (TILE_SIZE + GRID_SPACING);
@@ -31,7 +33,7 @@ pub fn spawn_grid(
     // Spawn 8x8 grid of tiles with staggered animation
     for row in 0..grid_size {
         for col in 0..grid_size {
-            let letter = get_weighted_random_letter();
+            let letter = get_weighted_random_letter(&mut *rng);
             let x = start_x + (col as f32) * (TILE_SIZE + GRID_SPACING);
             let y = start_y + (row as f32) * (TILE_SIZE + GRID_SPACING);
 
@@ -435,6 +437,7 @@ pub fn spawn_new_tiles(
     tile_query: Query<&GridTile>,
     config: Res<Stage2Config>,
     asset_server: Res<AssetServer>,
+    mut rng: ResMut<GameRng>,
 ) {
     let grid_size = config.grid_size;
 
@@ -450,7 +453,7 @@ pub fn spawn_new_tiles(
     for row in 0..grid_size {
         for col in 0..grid_size {
             if !occupied[row][col] {
-                let letter = get_weighted_random_letter();
+                let letter = get_weighted_random_letter(&mut *rng);
                 let grid_pos = (row, col);
 
                 // Calculate world position
@@ -616,8 +619,7 @@ pub fn cleanup_stage2_gameplay(
 }
 
 /// Returns a weighted random letter (standard tile distribution)
-fn get_weighted_random_letter() -> char {
-    let mut rng = rand::thread_rng();
+fn get_weighted_random_letter(rng: &mut impl Rng) -> char {
     let roll: u32 = rng.gen_range(0..100);
 
     match roll {
@@ -4,6 +4,7 @@ use bevy::prelude::*;
 use super::{Stage2Config, Stage2State};
 use super::difficulty::*;
 use super::components::{ScoreDisplay, TimerDisplay, MovesDisplay};
+use crate::plugins::rng::GameRng;
 use crate::plugins::state::GameState;
 
 /// Marker for Stage 2 start screen
@@ -186,12 +187,14 @@ pub fn handle_difficulty_selection(
     >,
     mut next_state: ResMut<NextState<GameState>>,
     start_screen_query: Query<Entity, With<Stage2StartScreen>>,
+    mut rng: ResMut<GameRng>,
 ) {
     for (interaction, button, mut color) in interaction_query.iter_mut() {
         match *interaction {
             Interaction::Pressed => {
                 // Initialize game with selected difficulty
                 let difficulty = get_difficulty(button.level);
+                rng.start_game();
 
                 commands.insert_resource(Stage2Config {
                     difficulty: button.level,
@@ -236,6 +239,7 @@ pub fn spawn_stage2_hud(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
     config: Res<Stage2Config>,
+    rng: Res<GameRng>,
 ) {
     let font_medium: Handle<Font> = asset_server.load("fonts/FiraSans-Medium.ttf");
     let font_bold: Handle<Font> = asset_server.load("fonts/FiraSans-Bold.ttf");
@@ -281,6 +285,16 @@ pub fn spawn_stage2_hud(
                         TextColor(Color::WHITE),
                         ScoreDisplay,
                     ));
+
+                    section.spawn((
+                        Text::new(format!("Seed {}", rng.seed())),
+                        TextFont {
+                            font: font_medium.clone(),
+                            font_size: 14.0,
+                            ..default()
+                        },
+                        TextColor(Color::srgb(0.5, 0.5, 0.6)),
+                    ));
                 });
 
             // Center section: Timer
@@ -406,6 +420,7 @@ pub fn spawn_results_screen(
     asset_server: Res<AssetServer>,
     state: Res<Stage2State>,
     config: Res<Stage2Config>,
+    rng: Res<GameRng>,
     last_stage: Res<crate::plugins::state::LastStageCompleted>,
 ) {
     // Only spawn if this stage just completed
@@ -510,6 +525,16 @@ pub fn spawn_results_screen(
                         },
                         TextColor(Color::WHITE),
                     ));
+
+                    stats.spawn((
+                        Text::new(format!("Seed: {}", rng.seed())),
+                        TextFont {
+                            font: font_medium.clone(),
+                            font_size: 20.0,
+                            ..default()
+                        },
+                        TextColor(Color::srgb(0.5, 0.5, 0.6)),
+                    ));
                 });
 
             // Buttons container
@@ -1,89 +1,275 @@
 /// AI opponent system for Stage 3
 
 use bevy::prelude::*;
-use super::{Stage3State, Stage3Config, Turn, Direction, MoveEvent};
-use super::board::Board;
-use super::difficulty::get_ai_difficulty;
-use crate::lexicon::Lexicon;
+use bevy::tasks::{block_on, poll_once, AsyncComputeTaskPool, Task};
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+use super::{Stage3State, Stage3Config, Turn, Direction, MoveEvent, MoveKind};
+use super::board::{Board, TileBag, TilePlacement, BLANK};
+use super::difficulty::{get_ai_difficulty, AIDifficulty};
+use super::endgame::solve_endgame;
+use super::leave::LeaveTable;
+use super::movegen::generate_moves;
+use super::challenge::{resolve_challenge, PendingChallenge};
+use super::scoring::{score_move, RACK_SIZE};
+use super::personality::{apply_move_bias, openings_created, PersonalityStats};
+use super::simulation::simulate;
+use crate::lexicon::{Lexicon, WordFrequency};
+use crate::plugins::rng::GameRng;
 use crate::scoring::ScoreCalculator;
+use crate::stage5::ai_personality::{AIPersonality, MoveBias};
 
 /// AI move calculation state
 #[derive(Resource, Default)]
 pub struct AIState {
     pub is_thinking: bool,
-    pub think_timer_ms: u32,
     pub current_best_move: Option<AIMove>,
+    /// Move search running off the main thread; `cancel_ai_search` drops it on pause or exit
+    pub search: Option<Task<Option<AIMove>>>,
+}
+
+impl AIState {
+    /// True while a search is running and the AI has no move yet
+    pub fn is_searching(&self) -> bool {
+        self.search.is_some()
+    }
+}
+
+/// Takes the result of the task in `slot` if it has finished, emptying the slot
+///
+/// Resources holding a task pass the slot through `bypass_change_detection`,
+/// so polling alone doesn't count as a change and only a finished task
+/// redraws what shows its result.
+pub(super) fn poll_task<T>(slot: &mut Option<Task<T>>) -> Option<T> {
+    let result = block_on(poll_once(slot.as_mut()?))?;
+    *slot = None;
+    Some(result)
+}
+
+/// The part of the lexicon the current AI knows (see `vocabulary_size`)
+#[derive(Resource)]
+pub struct AIVocabulary {
+    /// Difficulty level it was built for
+    pub level: u8,
+    pub lexicon: Lexicon,
 }
 
 /// Represents a potential AI move
-#[derive(Clone)]
+#[derive(Debug, Clone)]
 pub struct AIMove {
     pub word: String,
     pub position: (usize, usize),
     pub direction: Direction,
     pub score: u32,
+    /// Score plus the value of the rack leave (equals score until a leave table is applied)
+    pub equity: f32,
     pub tiles_used: Vec<char>,
+    pub placements: Vec<TilePlacement>,
+}
+
+/// Build the AI's vocabulary once the lexicon is loaded (and again if the difficulty changes)
+pub fn prepare_ai_vocabulary(
+    mut commands: Commands,
+    config: Res<Stage3Config>,
+    lexicon: Option<Res<Lexicon>>,
+    frequency: Option<Res<WordFrequency>>,
+    vocabulary: Option<Res<AIVocabulary>>,
+) {
+    let (Some(lexicon), Some(frequency)) = (lexicon, frequency) else {
+        return;
+    };
+    if vocabulary.is_some_and(|v| v.level == config.difficulty) {
+        return;
+    }
+
+    let difficulty = get_ai_difficulty(config.difficulty);
+    let known = frequency.vocabulary(&lexicon, difficulty.vocabulary_size);
+    info!("{} knows {} of {} words", difficulty.name, known.word_count(), lexicon.word_count());
+    commands.insert_resource(AIVocabulary {
+        level: config.difficulty,
+        lexicon: known,
+    });
 }
 
 /// Calculate AI move (runs during AI's turn)
+///
+/// The search runs on the async compute pool; this system starts it and
+/// collects the result once it's done, so the game keeps drawing meanwhile.
+#[allow(clippy::too_many_arguments)]
 pub fn calculate_ai_move(
     mut ai_state: ResMut<AIState>,
     state: Res<Stage3State>,
     config: Res<Stage3Config>,
     board: Res<Board>,
+    tile_bag: Res<TileBag>,
     lexicon: Res<Lexicon>,
+    vocabulary: Option<Res<AIVocabulary>>,
     scorer: Res<ScoreCalculator>,
-    time: Res<Time>,
+    leaves: Res<LeaveTable>,
+    mut rng: ResMut<GameRng>,
 ) {
-    // Only calculate when it's AI's turn
-    if state.current_turn != Turn::AI || !state.is_active {
+    // Only calculate when it's AI's turn (and the player's play is settled)
+    if state.current_turn != Turn::AI || !state.is_active || state.pending_challenge.is_some() {
         return;
     }
 
     if !ai_state.is_thinking {
         // Start thinking
         ai_state.is_thinking = true;
-        ai_state.think_timer_ms = 0;
         ai_state.current_best_move = None;
 
-        // Calculate best move
+        // Moves come from the words this AI knows
+        let known = vocabulary
+            .as_ref()
+            .filter(|v| v.level == config.difficulty)
+            .map_or(&*lexicon, |v| &v.lexicon);
+
+        // A replayed seed limits the search by iterations alone, so it
+        // plays the same moves on any machine
         let difficulty = get_ai_difficulty(config.difficulty);
-        let best_move = find_best_move(
-            &board,
-            &state.ai_rack,
-            &lexicon,
-            &scorer,
+        let difficulty = if rng.is_replay() { difficulty.replayable() } else { difficulty.clone() };
+
+        // The search gets its own copy of everything it reads
+        let search = AISearch {
+            board: board.clone(),
+            rack: state.ai_rack.clone(),
+            opponent_rack: state.player_rack.clone(),
+            bag: tile_bag.tiles.clone(),
+            known: known.clone(),
+            lexicon: lexicon.clone(),
+            scorer: scorer.clone(),
+            leaves: leaves.clone(),
             difficulty,
-        );
+            personality: config.personality,
+            allows_phonies: config.challenge_rule.allows_phonies(),
+            rng: rng.fork(),
+        };
+        ai_state.search = Some(AsyncComputeTaskPool::get().spawn(async move { search.run() }));
+        return;
+    }
 
+    if let Some(best_move) = poll_task(&mut ai_state.search) {
         ai_state.current_best_move = best_move;
-    } else {
-        // Simulate thinking time
-        ai_state.think_timer_ms += (time.delta_secs() * 1000.0) as u32;
     }
 }
 
+/// Everything one AI search needs, owned so it can run on another thread
+pub(super) struct AISearch {
+    pub(super) board: Board,
+    pub(super) rack: Vec<char>,
+    pub(super) opponent_rack: Vec<char>,
+    pub(super) bag: Vec<char>,
+    /// Words this AI knows
+    pub(super) known: Lexicon,
+    /// The full lexicon, for bluffing
+    pub(super) lexicon: Lexicon,
+    pub(super) scorer: ScoreCalculator,
+    pub(super) leaves: LeaveTable,
+    pub(super) difficulty: AIDifficulty,
+    pub(super) personality: Option<AIPersonality>,
+    pub(super) allows_phonies: bool,
+    /// Split off the game's RNG so a replayed seed makes the same choices
+    pub(super) rng: StdRng,
+}
+
+impl AISearch {
+    pub(super) fn run(mut self) -> Option<AIMove> {
+        let difficulty = &self.difficulty;
+
+        // Calculate best move; with the bag empty both racks are known,
+        // so stronger AIs solve the endgame instead
+        let mut best_move = match difficulty.endgame_budget() {
+            Some(budget) if self.bag.is_empty() => {
+                let solution = solve_endgame(
+                    &self.board,
+                    &self.rack,
+                    &self.opponent_rack,
+                    &self.known,
+                    &self.scorer,
+                    budget,
+                );
+                info!(
+                    "AI endgame search: depth {}, {} nodes, value {:+}",
+                    solution.depth, solution.nodes, solution.value
+                );
+                solution.best_move().cloned()
+            }
+            _ => {
+                // The AI can't tell the bag from the player's rack, only their union
+                let unseen: Vec<char> = self.bag.iter().chain(&self.opponent_rack).copied().collect();
+                find_best_move(
+                    &self.board,
+                    &self.rack,
+                    &unseen,
+                    &self.known,
+                    &self.scorer,
+                    &self.leaves,
+                    difficulty,
+                    self.personality.map(|p| p.get_move_bias()).as_ref(),
+                    &mut self.rng,
+                )
+            }
+        };
+
+        // Weak AIs sometimes bluff when a challenge rule lets phonies stand
+        if self.allows_phonies && difficulty.should_play_phony(&mut self.rng) {
+            if let Some(phony) = best_move
+                .as_ref()
+                .and_then(|m| make_phony(&self.board, m, &self.lexicon, &self.scorer))
+            {
+                best_move = Some(phony);
+            }
+        }
+
+        best_move
+    }
+}
+
+/// Drop any search still running, e.g. when the game is paused or left
+pub fn cancel_ai_search(mut ai_state: ResMut<AIState>) {
+    if ai_state.search.is_some() {
+        info!("Cancelling AI search");
+    }
+    *ai_state = AIState::default();
+}
+
 /// Execute AI move when thinking is complete
+#[allow(clippy::too_many_arguments)]
 pub fn execute_ai_move(
     mut commands: Commands,
     mut ai_state: ResMut<AIState>,
     mut state: ResMut<Stage3State>,
     mut board: ResMut<Board>,
+    mut tile_bag: ResMut<TileBag>,
     config: Res<Stage3Config>,
+    lexicon: Res<Lexicon>,
+    leaves: Res<LeaveTable>,
+    mut personality_stats: ResMut<PersonalityStats>,
     mut move_events: EventWriter<MoveEvent>,
+    mut rng: ResMut<GameRng>,
 ) {
-    if !ai_state.is_thinking || state.current_turn != Turn::AI {
+    if !ai_state.is_thinking || ai_state.is_searching() || state.current_turn != Turn::AI {
         return;
     }
 
-    // Check if AI has finished thinking
     let difficulty = get_ai_difficulty(config.difficulty);
-    if ai_state.think_timer_ms < difficulty.think_time_ms {
-        return;
-    }
+    let rack_before = state.ai_rack.clone();
 
     // Execute the move
     if let Some(ai_move) = &ai_state.current_best_move {
+        // Note any phonies before the tiles go down
+        let phonies = board
+            .evaluate_move(&ai_move.placements, &lexicon)
+            .map(|evaluated| evaluated.phonies)
+            .unwrap_or_default();
+        let scoreless_before = state.scoreless_turns;
+
+        if let Some(personality) = config.personality {
+            let openings = openings_created(&board, &ai_move.placements);
+            personality_stats.record(personality, ai_move.score, openings);
+        }
+
         // Place tiles on board
         place_move_on_board(&mut board, ai_move);
 
@@ -97,6 +283,13 @@ pub fn execute_ai_move(
             }
         }
 
+        // Draw new tiles to refill rack to 7 (under challenge rules, once the play stands)
+        if !config.challenge_rule.allows_phonies() {
+            let tiles_to_draw = RACK_SIZE.saturating_sub(state.ai_rack.len());
+            let new_tiles = tile_bag.draw_tiles(tiles_to_draw);
+            state.ai_rack.extend(new_tiles);
+        }
+
         // Send move event
         move_events.send(MoveEvent {
             player: Turn::AI,
@@ -107,258 +300,283 @@ pub fn execute_ai_move(
         });
 
         // Record move
-        let turn_number = state.moves_history.len() as u32 + 1;
-        state.moves_history.push(super::MoveRecord {
-            turn_number,
-            player: Turn::AI,
-            word: ai_move.word.clone(),
-            score: ai_move.score,
-            position: ai_move.position,
-            direction: ai_move.direction,
-        });
+        let record = state.record_move(
+            Turn::AI,
+            MoveKind::Play,
+            ai_move.word.clone(),
+            ai_move.score,
+            ai_move.position,
+            ai_move.direction,
+        );
+        record.rack = rack_before;
+        record.placements = ai_move.placements.clone();
+
+        if config.challenge_rule.allows_phonies() {
+            state.pending_challenge = Some(PendingChallenge {
+                player: Turn::AI,
+                placements: ai_move.placements.clone(),
+                tiles_used: ai_move.tiles_used.clone(),
+                word: ai_move.word.clone(),
+                phonies,
+                score: ai_move.score,
+                record_index: state.moves_history.len() - 1,
+                scoreless_before,
+            });
+        }
+    } else {
+        // No playable word: swap tiles if the bag allows it, otherwise pass
+        let tiles = if difficulty.uses_strategic_play {
+            let keep = leaves.best_keep(&state.ai_rack);
+            LeaveTable::leave_after(&state.ai_rack, &keep)
+        } else {
+            choose_exchange_tiles(&state.ai_rack)
+        };
+        let kind = match tile_bag.exchange(tiles.clone(), &mut *rng) {
+            Ok(drawn) => {
+                for tile in &tiles {
+                    if let Some(pos) = state.ai_rack.iter().position(|t| t == tile) {
+                        state.ai_rack.remove(pos);
+                    }
+                }
+                state.ai_rack.extend(drawn);
+                MoveKind::Exchange(tiles)
+            }
+            Err(_) => MoveKind::Pass,
+        };
+
+        state.record_move(Turn::AI, kind, String::new(), 0, (7, 7), Direction::Horizontal).rack = rack_before;
     }
 
     // Reset AI state and switch turns
     ai_state.is_thinking = false;
-    ai_state.think_timer_ms = 0;
     state.current_turn = Turn::Player;
 }
 
+/// Sets each move's equity to its score plus the value of its leave
+pub fn apply_leave_equity(moves: &mut [AIMove], rack: &[char], leaves: &LeaveTable) {
+    for ai_move in moves.iter_mut() {
+        let leave = LeaveTable::leave_after(rack, &ai_move.tiles_used);
+        ai_move.equity = ai_move.score as f32 + leaves.evaluate(&leave);
+    }
+}
+
+/// Accept or challenge the player's last play (challenge rules only)
+pub fn respond_to_player_play(
+    mut state: ResMut<Stage3State>,
+    mut board: ResMut<Board>,
+    mut tile_bag: ResMut<TileBag>,
+    config: Res<Stage3Config>,
+    mut rng: ResMut<GameRng>,
+) {
+    if state.current_turn != Turn::AI || !state.is_active {
+        return;
+    }
+    let Some(pending) = &state.pending_challenge else {
+        return;
+    };
+    if pending.player != Turn::Player {
+        return;
+    }
+
+    let difficulty = get_ai_difficulty(config.difficulty);
+    let challenged = difficulty.should_challenge(!pending.phonies.is_empty(), &mut *rng);
+
+    if let Some(outcome) = resolve_challenge(&mut state, &mut board, &mut tile_bag, config.challenge_rule, challenged) {
+        if challenged {
+            info!("AI challenged the player's play: {:?}", outcome);
+            state.last_challenge = Some((Turn::AI, outcome));
+        }
+    }
+}
+
+/// Orders candidate moves the way `difficulty` and its personality see them
+///
+/// Strategic AIs rank by equity so they hold on to good tiles; the rest go
+/// by score alone. A personality reshapes either to its style, but never
+/// teaches a non-strategic AI about leaves.
+fn rank_moves(
+    moves: &mut [AIMove],
+    board: &Board,
+    rack: &[char],
+    leaves: &LeaveTable,
+    difficulty: &AIDifficulty,
+    bias: Option<&MoveBias>,
+) {
+    if difficulty.uses_strategic_play {
+        apply_leave_equity(moves, rack, leaves);
+    } else {
+        for ai_move in moves.iter_mut() {
+            ai_move.equity = ai_move.score as f32;
+        }
+    }
+    if let Some(bias) = bias {
+        apply_move_bias(moves, board, bias);
+    }
+    if difficulty.uses_strategic_play || bias.is_some() {
+        moves.sort_by(|a, b| b.equity.total_cmp(&a.equity));
+    }
+}
+
 /// Find the best move for AI
+#[allow(clippy::too_many_arguments)]
 fn find_best_move(
     board: &Board,
     rack: &[char],
+    unseen: &[char],
     lexicon: &Lexicon,
     scorer: &ScoreCalculator,
-    difficulty: &super::difficulty::AIDifficulty,
+    leaves: &LeaveTable,
+    difficulty: &AIDifficulty,
+    bias: Option<&MoveBias>,
+    rng: &mut impl Rng,
 ) -> Option<AIMove> {
-    let mut candidate_moves = Vec::new();
-
-    // If board is empty, play first move
-    if board.is_board_empty() {
-        candidate_moves = find_first_move_candidates(rack, lexicon, scorer, difficulty);
-    } else {
-        // Find all valid moves on current board
-        candidate_moves = find_all_valid_moves(board, rack, lexicon, scorer, difficulty);
+    // Every legal move, already sorted by score
+    let mut candidate_moves = generate_moves(board, rack, lexicon, scorer);
+    rank_moves(&mut candidate_moves, board, rack, leaves, difficulty, bias);
+
+    // Weaker AIs don't see long words
+    let max_length = difficulty.max_word_length();
+    candidate_moves.retain(|m| m.word.len() <= max_length);
+
+    // Prefer moves above the difficulty's score threshold, but never pass
+    // just because nothing reaches it
+    let min_score = bias.map_or(difficulty.min_move_score(), |b| {
+        difficulty.min_move_score().max(b.min_score_threshold)
+    });
+    if candidate_moves.iter().any(|m| m.score >= min_score) {
+        candidate_moves.retain(|m| m.score >= min_score);
     }
 
     if candidate_moves.is_empty() {
         return None;
     }
 
-    // Apply AI difficulty filter
+    // Aim at the difficulty's percentile of the ranked list, then apply
+    // the candidate limit from there
+    let target = difficulty.target_move_index(candidate_moves.len());
+    candidate_moves.drain(..target);
     let max_candidates = difficulty.candidate_move_count();
     candidate_moves.truncate(max_candidates);
 
     // Potentially make a suboptimal move based on error rate
-    if difficulty.should_make_error() && candidate_moves.len() > 1 {
+    if difficulty.should_make_error(rng) && candidate_moves.len() > 1 {
         // Choose a random move from top candidates instead of best
-        use rand::seq::SliceRandom;
-        let mut rng = rand::thread_rng();
-        candidate_moves.choose(&mut rng).cloned()
+        candidate_moves.choose(rng).cloned()
+    } else if let Some(settings) = difficulty.simulation_settings() {
+        // Top AIs look a few plies ahead before committing
+        candidate_moves.truncate(difficulty.sim_candidates);
+        let result = simulate(board, rack, unseen, &candidate_moves, lexicon, scorer, leaves, settings, rng)?;
+        let chosen = candidate_moves.into_iter().nth(result.best_index)?;
+        info!(
+            "AI simulated {} candidates over {} iterations: {} averages {:+.1} (static equity {:.1})",
+            result.average_spread.len(),
+            result.iterations,
+            chosen.word,
+            result.average_spread[result.best_index],
+            chosen.equity
+        );
+        Some(chosen)
     } else {
         // Choose best move
         candidate_moves.into_iter().next()
     }
 }
 
-/// Find candidate moves for first turn (center square)
-fn find_first_move_candidates(
-    rack: &[char],
-    lexicon: &Lexicon,
-    scorer: &ScoreCalculator,
-    difficulty: &super::difficulty::AIDifficulty,
-) -> Vec<AIMove> {
-    let mut moves = Vec::new();
-    let max_length = difficulty.max_word_length().min(rack.len());
-
-    // Generate words from rack tiles
-    for length in 2..=max_length {
-        let words = generate_words_from_tiles(rack, length, lexicon);
-
-        for word in words {
-            // First move must go through center (7, 7)
-            // Try horizontal placement
-            let score = scorer.calculate_score(&word, 0.0, 1.0);
-            moves.push(AIMove {
-                word: word.clone(),
-                position: (7, 7 - (word.len() / 2)),
-                direction: Direction::Horizontal,
-                score,
-                tiles_used: word.chars().collect(),
-            });
-
-            // Try vertical placement
-            moves.push(AIMove {
-                word: word.clone(),
-                position: (7 - (word.len() / 2), 7),
-                direction: Direction::Vertical,
-                score,
-                tiles_used: word.chars().collect(),
-            });
-        }
-    }
-
-    // Sort by score descending
-    moves.sort_by(|a, b| b.score.cmp(&a.score));
-
-    // Filter by minimum score
-    let min_score = difficulty.min_move_score();
-    moves.retain(|m| m.score >= min_score);
-
-    moves
-}
-
-/// Find all valid moves on current board
-fn find_all_valid_moves(
-    board: &Board,
-    rack: &[char],
-    lexicon: &Lexicon,
-    scorer: &ScoreCalculator,
-    difficulty: &super::difficulty::AIDifficulty,
-) -> Vec<AIMove> {
-    let mut moves = Vec::new();
-
-    // For each position on the board
-    for row in 0..15 {
-        for col in 0..15 {
-            // Skip occupied squares
-            if board.get(row, col).is_some() {
-                continue;
+/// Tiles the AI gives back when it exchanges
+///
+/// Keeps blanks and a single S, which almost always help the next rack.
+pub(super) fn choose_exchange_tiles(rack: &[char]) -> Vec<char> {
+    let mut kept_s = false;
+    rack.iter()
+        .copied()
+        .filter(|&tile| {
+            if tile == BLANK {
+                return false;
             }
-
-            // Try placing each letter from rack
-            for &letter in rack {
-                // Try horizontal word formation
-                if let Some(ai_move) = try_form_word_horizontal(
-                    board, rack, (row, col), letter, lexicon, scorer,
-                ) {
-                    if ai_move.score >= difficulty.min_move_score() {
-                        moves.push(ai_move);
-                    }
-                }
-
-                // Try vertical word formation
-                if let Some(ai_move) = try_form_word_vertical(
-                    board, rack, (row, col), letter, lexicon, scorer,
-                ) {
-                    if ai_move.score >= difficulty.min_move_score() {
-                        moves.push(ai_move);
-                    }
-                }
+            if tile == 'S' && !kept_s {
+                kept_s = true;
+                return false;
             }
-        }
-    }
-
-    // Sort by score descending
-    moves.sort_by(|a, b| b.score.cmp(&a.score));
-
-    moves
+            true
+        })
+        .collect()
 }
 
-/// Generate valid words from tiles (placeholder - simplified)
-fn generate_words_from_tiles(
-    tiles: &[char],
-    length: usize,
+/// Turns a real move into a phony by shuffling its placed letters
+///
+/// The squares (and so the placement rules) stay the same; only moves
+/// whose main word stops being valid are returned.
+fn make_phony(
+    board: &Board,
+    ai_move: &AIMove,
     lexicon: &Lexicon,
-) -> Vec<String> {
-    // Placeholder: In real implementation, would use GADDAG/wolges
-    // For now, generate simple permutations and check lexicon
-
-    let mut words = Vec::new();
-
-    // Simple brute force for demo (real implementation uses GADDAG)
-    if length > tiles.len() {
-        return words;
-    }
-
-    // Try all permutations of length N
-    generate_permutations(tiles, length, &mut |perm| {
-        let word: String = perm.iter().collect();
-        if lexicon.is_valid(&word.to_uppercase()) {
-            words.push(word.to_uppercase());
+    scorer: &ScoreCalculator,
+) -> Option<AIMove> {
+    let count = ai_move.placements.len();
+
+    for shift in 1..count {
+        let mut placements = ai_move.placements.clone();
+        for (i, placement) in placements.iter_mut().enumerate() {
+            let source = &ai_move.placements[(i + shift) % count];
+            placement.letter = source.letter;
+            placement.is_blank = source.is_blank;
         }
-    });
-
-    words
-}
 
-/// Generate permutations (helper)
-fn generate_permutations<F>(tiles: &[char], length: usize, callback: &mut F)
-where
-    F: FnMut(&[char]),
-{
-    fn permute<F>(
-        tiles: &[char],
-        length: usize,
-        current: &mut Vec<char>,
-        used: &mut Vec<bool>,
-        callback: &mut F,
-    )
-    where
-        F: FnMut(&[char]),
-    {
-        if current.len() == length {
-            callback(current);
-            return;
+        let Ok(evaluated) = board.evaluate_move(&placements, lexicon) else {
+            continue;
+        };
+        if lexicon.is_valid(&evaluated.word) {
+            continue;
         }
 
-        for i in 0..tiles.len() {
-            if used[i] {
-                continue;
-            }
-
-            used[i] = true;
-            current.push(tiles[i]);
-            permute(tiles, length, current, used, callback);
-            current.pop();
-            used[i] = false;
-        }
+        return Some(AIMove {
+            word: evaluated.word,
+            position: evaluated.position,
+            direction: evaluated.direction,
+            score: score_move(board, &placements, evaluated.direction, scorer),
+            equity: ai_move.equity,
+            tiles_used: ai_move.tiles_used.clone(),
+            placements,
+        });
     }
 
-    let mut current = Vec::new();
-    let mut used = vec![false; tiles.len()];
-    permute(tiles, length, &mut current, &mut used, callback);
-}
-
-/// Try to form a horizontal word at position
-fn try_form_word_horizontal(
-    board: &Board,
-    rack: &[char],
-    position: (usize, usize),
-    first_letter: char,
-    lexicon: &Lexicon,
-    scorer: &ScoreCalculator,
-) -> Option<AIMove> {
-    // Placeholder: Simplified word formation
-    // Real implementation would use anchor-based GADDAG search
     None
 }
 
-/// Try to form a vertical word at position
-fn try_form_word_vertical(
-    board: &Board,
-    rack: &[char],
-    position: (usize, usize),
-    first_letter: char,
-    lexicon: &Lexicon,
-    scorer: &ScoreCalculator,
-) -> Option<AIMove> {
-    // Placeholder: Simplified word formation
-    // Real implementation would use anchor-based GADDAG search
-    None
-}
-
-/// Place AI move on board
+/// Place AI move on board (only the newly placed tiles)
 fn place_move_on_board(board: &mut Board, ai_move: &AIMove) {
-    let (start_row, start_col) = ai_move.position;
+    for placement in &ai_move.placements {
+        let _ = board.place_placement(placement);
+    }
+}
 
-    for (i, letter) in ai_move.word.chars().enumerate() {
-        let (row, col) = match ai_move.direction {
-            Direction::Horizontal => (start_row, start_col + i),
-            Direction::Vertical => (start_row + i, start_col),
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_personality_keeps_non_strategic_ai_score_only() {
+        let lexicon = Lexicon::from_words(["CAT", "CATS", "TEEN", "TEENS", "AT", "ET"], "Test");
+        let scorer = ScoreCalculator::new();
+        let board = Board::with_word("CAT", 7, 6);
+        let rack = ['E', 'E', 'N', 'S'];
+        let no_leaves = LeaveTable::parse("").unwrap();
+        let keep_s = LeaveTable::parse("S 50").unwrap();
+        let bias = AIPersonality::Defensive.get_move_bias();
+
+        let ranked = |difficulty: &AIDifficulty, leaves: &LeaveTable| {
+            let mut moves = generate_moves(&board, &rack, &lexicon, &scorer);
+            rank_moves(&mut moves, &board, &rack, leaves, difficulty, Some(&bias));
+            moves.iter().map(|m| (m.word.clone(), m.equity)).collect::<Vec<_>>()
         };
 
-        let _ = board.place(row, col, letter);
+        // A novice with a personality still can't see what the S is worth
+        let novice = get_ai_difficulty(2);
+        assert!(!novice.uses_strategic_play);
+        assert_eq!(ranked(novice, &keep_s), ranked(novice, &no_leaves));
+
+        // A strategic AI with the same personality does
+        let expert = get_ai_difficulty(5);
+        assert_ne!(ranked(expert, &keep_s), ranked(expert, &no_leaves));
     }
 }
@@ -0,0 +1,134 @@
+/// AI-vs-AI games played out by the Stage 3 engine
+///
+/// Used for tournament matches the player isn't in. Both sides choose
+/// moves as the Stage 3 opponent does at their difficulty and personality,
+/// but with the cheaper background search (no simulation, a small endgame
+/// budget), and nothing is drawn: the game runs to the end in one call, so
+/// it belongs on a background thread. Both sides draw on the full lexicon
+/// and no phonies are played.
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use super::{Turn, MAX_SCORELESS_TURNS};
+use super::ai::{choose_exchange_tiles, AISearch};
+use super::board::{Board, TileBag};
+use super::difficulty::get_ai_difficulty;
+use super::leave::LeaveTable;
+use super::scoring::{end_of_game_adjustments, RACK_SIZE};
+use crate::lexicon::Lexicon;
+use crate::scoring::ScoreCalculator;
+use crate::stage5::ai_personality::AIPersonality;
+
+/// Longest a game is allowed to run, in turns
+const MAX_TURNS: usize = 200;
+
+/// One side of an AI-vs-AI game
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AutoPlayer {
+    /// Stage 3 difficulty level (1-5)
+    pub level: u8,
+    pub personality: Option<AIPersonality>,
+}
+
+/// Plays a whole game, `players[0]` moving first, and returns the final scores
+pub fn play_game(
+    players: [AutoPlayer; 2],
+    lexicon: &Lexicon,
+    scorer: &ScoreCalculator,
+    leaves: &LeaveTable,
+    rng: &mut impl Rng,
+) -> [u32; 2] {
+    let mut board = Board::default();
+    let mut bag = TileBag::default();
+    bag.reset(rng);
+    let mut racks = [bag.draw_tiles(RACK_SIZE), bag.draw_tiles(RACK_SIZE)];
+    let mut scores = [0u32; 2];
+    let mut scoreless_turns = 0;
+    let mut went_out = None;
+
+    for turn in 0..MAX_TURNS {
+        let mover = turn % 2;
+        let search = AISearch {
+            board: board.clone(),
+            rack: racks[mover].clone(),
+            opponent_rack: racks[1 - mover].clone(),
+            bag: bag.tiles.clone(),
+            known: lexicon.clone(),
+            lexicon: lexicon.clone(),
+            scorer: scorer.clone(),
+            leaves: leaves.clone(),
+            difficulty: get_ai_difficulty(players[mover].level).background(),
+            personality: players[mover].personality,
+            allows_phonies: false,
+            rng: StdRng::seed_from_u64(rng.gen()),
+        };
+
+        match search.run() {
+            Some(ai_move) => {
+                for placement in &ai_move.placements {
+                    let _ = board.place_placement(placement);
+                }
+                for tile in &ai_move.tiles_used {
+                    if let Some(pos) = racks[mover].iter().position(|t| t == tile) {
+                        racks[mover].remove(pos);
+                    }
+                }
+                let drawn = bag.draw_tiles(RACK_SIZE.saturating_sub(racks[mover].len()));
+                racks[mover].extend(drawn);
+                scores[mover] += ai_move.score;
+                scoreless_turns = if ai_move.score == 0 { scoreless_turns + 1 } else { 0 };
+            }
+            None => {
+                // Swap if the bag allows it, otherwise pass
+                let tiles = choose_exchange_tiles(&racks[mover]);
+                if let Ok(drawn) = bag.exchange(tiles.clone(), rng) {
+                    for tile in &tiles {
+                        if let Some(pos) = racks[mover].iter().position(|t| t == tile) {
+                            racks[mover].remove(pos);
+                        }
+                    }
+                    racks[mover].extend(drawn);
+                }
+                scoreless_turns += 1;
+            }
+        }
+
+        if racks[mover].is_empty() && bag.is_empty() {
+            went_out = Some(if mover == 0 { Turn::Player } else { Turn::AI });
+            break;
+        }
+        if scoreless_turns >= MAX_SCORELESS_TURNS {
+            break;
+        }
+    }
+
+    let (first_adjust, second_adjust) = end_of_game_adjustments(&racks[0], &racks[1], went_out, scorer);
+    [
+        scores[0].saturating_add_signed(first_adjust),
+        scores[1].saturating_add_signed(second_adjust),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_same_seed_same_game() {
+        let lexicon = Lexicon::from_words(
+            ["AT", "TA", "AN", "NA", "IN", "IT", "TI", "ON", "NO", "TO", "OE", "RE", "ER", "EN", "NE", "ES", "AS", "IS"],
+            "Test",
+        );
+        let scorer = ScoreCalculator::new();
+        let leaves = LeaveTable::default();
+        // Level 5 searches the endgame, on a node limit alone
+        for level in [1, 5] {
+            let player = AutoPlayer { level, personality: None };
+
+            let first = play_game([player, player], &lexicon, &scorer, &leaves, &mut StdRng::seed_from_u64(3));
+            let again = play_game([player, player], &lexicon, &scorer, &leaves, &mut StdRng::seed_from_u64(3));
+
+            assert_eq!(first, again);
+        }
+    }
+}
@@ -1,14 +1,34 @@
 /// Board management for Stage 3 (15×15 Classic Word Tile Board)
 
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
 use super::components::PremiumSquare;
+use super::{Direction, MIN_TILES_FOR_EXCHANGE};
 use rand::seq::SliceRandom;
-use rand::thread_rng;
+use rand::Rng;
+
+/// Size of one board square in world units
+pub const SQUARE_SIZE: f32 = 40.0;
+/// World position of the top-left square (row 0, col 0)
+pub const BOARD_OFFSET_X: f32 = -300.0;
+pub const BOARD_OFFSET_Y: f32 = 300.0;
+
+/// Rack symbol for a blank tile
+pub const BLANK: char = '_';
+
+/// A tile sitting on the board
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct PlacedTile {
+    /// Letter shown on the tile (the designated letter for a blank)
+    pub letter: char,
+    /// true if the tile is a blank standing in for `letter`
+    pub is_blank: bool,
+}
 
 /// 15×15 word tile game board
-#[derive(Resource)]
+#[derive(Resource, Clone, Serialize, Deserialize)]
 pub struct Board {
-    pub grid: [[Option<char>; 15]; 15],
+    pub grid: [[Option<PlacedTile>; 15]; 15],
     pub premium_squares: [[PremiumSquare; 15]; 15],
 }
 
@@ -89,24 +109,60 @@ impl Board {
 
     /// Place a letter at position
     pub fn place(&mut self, row: usize, col: usize, letter: char) -> Result<(), String> {
+        self.place_tile(row, col, PlacedTile { letter, is_blank: false })
+    }
+
+    /// Place a blank at position, standing in for `letter`
+    pub fn place_blank(&mut self, row: usize, col: usize, letter: char) -> Result<(), String> {
+        self.place_tile(row, col, PlacedTile { letter, is_blank: true })
+    }
+
+    /// Place a tile from a move
+    pub fn place_placement(&mut self, placement: &TilePlacement) -> Result<(), String> {
+        self.place_tile(
+            placement.row,
+            placement.col,
+            PlacedTile { letter: placement.letter, is_blank: placement.is_blank },
+        )
+    }
+
+    fn place_tile(&mut self, row: usize, col: usize, tile: PlacedTile) -> Result<(), String> {
         if row >= 15 || col >= 15 {
             return Err("Position out of bounds".to_string());
         }
         if self.grid[row][col].is_some() {
             return Err("Square already occupied".to_string());
         }
-        self.grid[row][col] = Some(letter);
+        self.grid[row][col] = Some(tile);
         Ok(())
     }
 
-    /// Get letter at position
+    /// Remove the tile at position (used when a challenged phony is taken back)
+    pub fn remove(&mut self, row: usize, col: usize) -> Option<PlacedTile> {
+        if row >= 15 || col >= 15 {
+            return None;
+        }
+        self.grid[row][col].take()
+    }
+
+    /// Get letter at position (the designated letter for a blank)
     pub fn get(&self, row: usize, col: usize) -> Option<char> {
-        if row >= 15 && col >= 15 {
+        self.get_tile(row, col).map(|tile| tile.letter)
+    }
+
+    /// Get the tile at position, including its blank flag
+    pub fn get_tile(&self, row: usize, col: usize) -> Option<PlacedTile> {
+        if row >= 15 || col >= 15 {
             return None;
         }
         self.grid[row][col]
     }
 
+    /// Check if the tile at position is a blank
+    pub fn is_blank(&self, row: usize, col: usize) -> bool {
+        self.get_tile(row, col).is_some_and(|tile| tile.is_blank)
+    }
+
     /// Check if position is empty
     pub fn is_empty(&self, row: usize, col: usize) -> bool {
         self.get(row, col).is_none()
@@ -120,6 +176,82 @@ impl Board {
         self.premium_squares[row][col]
     }
 
+    /// Reads the word running through `origin` along `direction`
+    ///
+    /// `placements` are treated as already on the board, so this sees the
+    /// board as it will look after the move. Returns every square of the
+    /// word in reading order (a single square if no word is formed).
+    pub fn word_through(
+        &self,
+        placements: &[TilePlacement],
+        origin: (usize, usize),
+        direction: Direction,
+    ) -> Vec<WordSquare> {
+        let square_at = |(row, col): (usize, usize)| -> Option<WordSquare> {
+            if let Some(p) = placements.iter().find(|p| p.row == row && p.col == col) {
+                return Some(WordSquare { row, col, letter: p.letter, is_blank: p.is_blank, is_new: true });
+            }
+            self.get_tile(row, col).map(|tile| WordSquare {
+                row,
+                col,
+                letter: tile.letter,
+                is_blank: tile.is_blank,
+                is_new: false,
+            })
+        };
+        let step = |(row, col): (usize, usize), forward: bool| -> Option<(usize, usize)> {
+            let (row, col) = match (direction, forward) {
+                (Direction::Horizontal, true) => (row, col + 1),
+                (Direction::Horizontal, false) => (row, col.checked_sub(1)?),
+                (Direction::Vertical, true) => (row + 1, col),
+                (Direction::Vertical, false) => (row.checked_sub(1)?, col),
+            };
+            (row < 15 && col < 15).then_some((row, col))
+        };
+
+        // Walk back to the first letter of the word
+        let mut start = origin;
+        while let Some(prev) = step(start, false) {
+            if square_at(prev).is_none() {
+                break;
+            }
+            start = prev;
+        }
+
+        let mut squares = Vec::new();
+        let mut current = Some(start);
+        while let Some(position) = current {
+            let Some(square) = square_at(position) else {
+                break;
+            };
+            squares.push(square);
+            current = step(position, true);
+        }
+        squares
+    }
+
+    /// Direction of the main word formed by `placements`
+    ///
+    /// Tiles sharing a row play across and tiles sharing a column play down;
+    /// a single tile plays along whichever line it touches existing tiles in.
+    pub fn main_direction(&self, placements: &[TilePlacement]) -> Direction {
+        match placements {
+            [first, second, ..] if first.row == second.row => Direction::Horizontal,
+            [_, _, ..] => Direction::Vertical,
+            [single] => {
+                let (row, col) = (single.row, single.col);
+                let touches_row = (col > 0 && self.get(row, col - 1).is_some())
+                    || self.get(row, col + 1).is_some();
+                if touches_row {
+                    Direction::Horizontal
+                } else {
+                    Direction::Vertical
+                }
+            }
+            [] => Direction::Horizontal,
+        }
+    }
+
     /// Check if board is empty (first move)
     pub fn is_board_empty(&self) -> bool {
         for row in &self.grid {
@@ -133,8 +265,32 @@ impl Board {
     }
 }
 
+/// A single tile placed on the board as part of a move
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct TilePlacement {
+    pub row: usize,
+    pub col: usize,
+    /// Letter played (the designated letter for a blank)
+    pub letter: char,
+    pub is_blank: bool,
+}
+
+/// A square of a word read off the board by [`Board::word_through`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WordSquare {
+    pub row: usize,
+    pub col: usize,
+    pub letter: char,
+    pub is_blank: bool,
+    /// true if the tile is placed by the move being read
+    pub is_new: bool,
+}
+
 /// Tile bag for drawing random tiles
-#[derive(Resource)]
+///
+/// A new bag holds the full distribution in order; `reset` shuffles it
+/// from the game's RNG when a game starts.
+#[derive(Resource, Clone, Serialize, Deserialize)]
 pub struct TileBag {
     pub tiles: Vec<char>,
     pub remaining_count: usize,
@@ -150,7 +306,7 @@ impl Default for TileBag {
 }
 
 impl TileBag {
-    /// Create standard tile distribution (100 tiles total)
+    /// Create standard tile distribution (100 tiles total, unshuffled)
     fn create_tile_distribution() -> Vec<char> {
         let mut tiles = Vec::new();
 
@@ -161,7 +317,7 @@ impl TileBag {
             ('K', 1), ('L', 4), ('M', 2), ('N', 6), ('O', 8),
             ('P', 2), ('Q', 1), ('R', 6), ('S', 4), ('T', 6),
             ('U', 4), ('V', 2), ('W', 2), ('X', 1), ('Y', 2),
-            ('Z', 1), ('_', 2), // 2 blank tiles represented as '_'
+            ('Z', 1), (BLANK, 2), // 2 blank tiles
         ];
 
         for (letter, count) in &distribution {
@@ -170,10 +326,6 @@ impl TileBag {
             }
         }
 
-        // Shuffle tiles
-        let mut rng = thread_rng();
-        tiles.shuffle(&mut rng);
-
         tiles
     }
 
@@ -196,16 +348,35 @@ impl TileBag {
     }
 
     /// Return tiles to bag (for exchanges)
-    pub fn return_tiles(&mut self, tiles: Vec<char>) {
+    pub fn return_tiles(&mut self, tiles: Vec<char>, rng: &mut impl Rng) {
         self.tiles.extend(tiles);
-        let mut rng = thread_rng();
-        self.tiles.shuffle(&mut rng);
+        self.tiles.shuffle(rng);
         self.remaining_count = self.tiles.len();
     }
 
-    /// Reset the bag to full distribution
-    pub fn reset(&mut self) {
+    /// Exchange tiles: draws replacements, then returns `tiles` to the bag
+    ///
+    /// Only allowed while at least `MIN_TILES_FOR_EXCHANGE` tiles remain.
+    pub fn exchange(&mut self, tiles: Vec<char>, rng: &mut impl Rng) -> Result<Vec<char>, String> {
+        if tiles.is_empty() {
+            return Err("No tiles to exchange".to_string());
+        }
+        if self.tiles.len() < MIN_TILES_FOR_EXCHANGE {
+            return Err(format!(
+                "Exchange needs at least {} tiles in the bag",
+                MIN_TILES_FOR_EXCHANGE
+            ));
+        }
+
+        let drawn = self.draw_tiles(tiles.len());
+        self.return_tiles(tiles, rng);
+        Ok(drawn)
+    }
+
+    /// Reset the bag to a freshly shuffled full distribution
+    pub fn reset(&mut self, rng: &mut impl Rng) {
         self.tiles = Self::create_tile_distribution();
+        self.tiles.shuffle(rng);
         self.remaining_count = self.tiles.len();
     }
 
@@ -226,14 +397,9 @@ pub fn spawn_board(
     board: Res<Board>,
     asset_server: Res<AssetServer>,
 ) {
-    const SQUARE_SIZE: f32 = 40.0;
-    const BOARD_OFFSET_X: f32 = -300.0;
-    const BOARD_OFFSET_Y: f32 = 300.0;
-
     for row in 0..15 {
         for col in 0..15 {
-            let x = BOARD_OFFSET_X + (col as f32 * SQUARE_SIZE);
-            let y = BOARD_OFFSET_Y - (row as f32 * SQUARE_SIZE);
+            let Vec2 { x, y } = square_to_world(row, col);
 
             let premium = board.get_premium(row, col);
             let color = get_premium_color(premium);
@@ -274,8 +440,50 @@ pub fn spawn_board(
     }
 }
 
+/// World position of the center of square (row, col)
+pub fn square_to_world(row: usize, col: usize) -> Vec2 {
+    Vec2::new(
+        BOARD_OFFSET_X + (col as f32 * SQUARE_SIZE),
+        BOARD_OFFSET_Y - (row as f32 * SQUARE_SIZE),
+    )
+}
+
+/// Board square under a world position, if any
+pub fn world_to_square(position: Vec2) -> Option<(usize, usize)> {
+    let col = ((position.x - BOARD_OFFSET_X) / SQUARE_SIZE + 0.5).floor();
+    let row = ((BOARD_OFFSET_Y - position.y) / SQUARE_SIZE + 0.5).floor();
+
+    if (0.0..15.0).contains(&row) && (0.0..15.0).contains(&col) {
+        Some((row as usize, col as usize))
+    } else {
+        None
+    }
+}
+
+/// Standard coordinate for a play: "8H" across (row first), "H8" down
+pub fn play_coordinate(position: (usize, usize), direction: Direction) -> String {
+    let (row, col) = position;
+    let column = (b'A' + col as u8) as char;
+    match direction {
+        Direction::Horizontal => format!("{}{}", row + 1, column),
+        Direction::Vertical => format!("{}{}", column, row + 1),
+    }
+}
+
+#[cfg(test)]
+impl Board {
+    /// An otherwise empty board with `word` across from (row, col)
+    pub fn with_word(word: &str, row: usize, col: usize) -> Self {
+        let mut board = Board::default();
+        for (i, letter) in word.chars().enumerate() {
+            board.place(row, col + i, letter).unwrap();
+        }
+        board
+    }
+}
+
 /// Get color for premium square
-fn get_premium_color(premium: PremiumSquare) -> Color {
+pub fn get_premium_color(premium: PremiumSquare) -> Color {
     use PremiumSquare::*;
     match premium {
         Normal => Color::srgb(0.85, 0.85, 0.75),
@@ -288,7 +496,7 @@ fn get_premium_color(premium: PremiumSquare) -> Color {
 }
 
 /// Get label for premium square
-fn get_premium_label(premium: PremiumSquare) -> &'static str {
+pub fn get_premium_label(premium: PremiumSquare) -> &'static str {
     use PremiumSquare::*;
     match premium {
         DoubleLetter => "DL",
@@ -299,3 +507,52 @@ fn get_premium_label(premium: PremiumSquare) -> &'static str {
         Normal => "",
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_exchange_keeps_bag_size() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let mut bag = TileBag::default();
+        bag.reset(&mut rng);
+        let rack = bag.draw_tiles(7);
+        let before = bag.count();
+
+        let drawn = bag.exchange(rack[..3].to_vec(), &mut rng).unwrap();
+        assert_eq!(drawn.len(), 3);
+        assert_eq!(bag.count(), before);
+    }
+
+    #[test]
+    fn test_exchange_needs_full_bag() {
+        let mut bag = TileBag::default();
+        let remaining = bag.count() - 6;
+        bag.draw_tiles(remaining);
+
+        assert!(bag.exchange(vec!['A'], &mut StdRng::seed_from_u64(7)).is_err());
+        assert_eq!(bag.count(), 6);
+    }
+
+    #[test]
+    fn test_same_seed_same_bag() {
+        let shuffled = |seed| {
+            let mut bag = TileBag::default();
+            bag.reset(&mut StdRng::seed_from_u64(seed));
+            bag.tiles
+        };
+
+        assert_eq!(shuffled(1), shuffled(1));
+        assert_ne!(shuffled(1), shuffled(2));
+        assert_eq!(shuffled(1).len(), 100);
+    }
+
+    #[test]
+    fn test_play_coordinate() {
+        assert_eq!(play_coordinate((7, 7), Direction::Horizontal), "8H");
+        assert_eq!(play_coordinate((0, 14), Direction::Vertical), "O1");
+    }
+}
@@ -0,0 +1,219 @@
+/// Challenge rules for Stage 3 (Classic Board)
+///
+/// Under any rule except `Void`, a play with invalid words stays on the
+/// board until the opponent accepts or challenges it. A challenged phony is
+/// taken back and scores nothing; what an unsuccessful challenge costs
+/// depends on the rule.
+
+use serde::{Deserialize, Serialize};
+use super::{MoveKind, Stage3State, Turn, Direction};
+use super::board::{Board, TileBag, TilePlacement};
+use super::scoring::RACK_SIZE;
+
+pub use crate::plugins::rules::ChallengeRule;
+
+/// Bonus awarded to the challenged player for a valid play under `FivePoint`
+pub const FIVE_POINT_BONUS: u32 = 5;
+
+/// A play on the board waiting for the opponent to accept or challenge it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PendingChallenge {
+    /// Who made the play
+    pub player: Turn,
+    pub placements: Vec<TilePlacement>,
+    /// Rack tiles the play used (blanks as BLANK)
+    pub tiles_used: Vec<char>,
+    pub word: String,
+    /// Words formed that are not in the lexicon
+    pub phonies: Vec<String>,
+    pub score: u32,
+    /// Position of the play in `moves_history`
+    pub record_index: usize,
+    /// Scoreless-turn count before the play was made
+    pub scoreless_before: u32,
+}
+
+/// How a pending play was settled
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ChallengeOutcome {
+    /// Opponent accepted the play
+    Accepted,
+    /// Challenge succeeded: the phony was taken back
+    PhonyRemoved,
+    /// Challenge failed: the play stands
+    PlayStands,
+}
+
+/// Settles the pending play, accepting it or resolving a challenge
+///
+/// The player who made the play draws replacement tiles only once it
+/// stands. Whose turn comes next is left to the caller, except under
+/// `Double` where a failed challenge costs the challenger their turn.
+pub fn resolve_challenge(
+    state: &mut Stage3State,
+    board: &mut Board,
+    tile_bag: &mut TileBag,
+    rule: ChallengeRule,
+    challenged: bool,
+) -> Option<ChallengeOutcome> {
+    let pending = state.pending_challenge.take()?;
+    let challenger = other(pending.player);
+
+    if challenged && !pending.phonies.is_empty() {
+        // Take the phony back: tiles return to the rack and the points are lost
+        for placement in &pending.placements {
+            board.remove(placement.row, placement.col);
+        }
+        *score_of(state, pending.player) -= pending.score;
+        rack_of(state, pending.player).extend(pending.tiles_used.iter().copied());
+
+        if let Some(record) = state.moves_history.get_mut(pending.record_index) {
+            record.kind = MoveKind::PhonyWithdrawn;
+            record.score = 0;
+        }
+        state.scoreless_turns = pending.scoreless_before + 1;
+        return Some(ChallengeOutcome::PhonyRemoved);
+    }
+
+    // The play stands, so its player refills their rack
+    let rack = rack_of(state, pending.player);
+    let leave = rack.clone();
+    let tiles_to_draw = RACK_SIZE.saturating_sub(rack.len());
+    let drawn = tile_bag.draw_tiles(tiles_to_draw);
+    rack_of(state, pending.player).extend(drawn);
+
+    if !challenged {
+        return Some(ChallengeOutcome::Accepted);
+    }
+
+    match rule {
+        ChallengeRule::Double => {
+            let rack = rack_of(state, challenger).clone();
+            state.record_move(challenger, MoveKind::LostChallenge, String::new(), 0, (7, 7), Direction::Horizontal).rack = rack;
+            state.current_turn = pending.player;
+        }
+        ChallengeRule::FivePoint => {
+            *score_of(state, pending.player) += FIVE_POINT_BONUS;
+            state.record_move(
+                pending.player,
+                MoveKind::ChallengeBonus,
+                String::new(),
+                FIVE_POINT_BONUS,
+                (7, 7),
+                Direction::Horizontal,
+            )
+            .rack = leave;
+        }
+        ChallengeRule::Single | ChallengeRule::Void => {}
+    }
+
+    Some(ChallengeOutcome::PlayStands)
+}
+
+fn other(player: Turn) -> Turn {
+    match player {
+        Turn::Player => Turn::AI,
+        Turn::AI => Turn::Player,
+    }
+}
+
+fn score_of(state: &mut Stage3State, player: Turn) -> &mut u32 {
+    match player {
+        Turn::Player => &mut state.player_score,
+        Turn::AI => &mut state.ai_score,
+    }
+}
+
+fn rack_of(state: &mut Stage3State, player: Turn) -> &mut Vec<char> {
+    match player {
+        Turn::Player => &mut state.player_rack,
+        Turn::AI => &mut state.ai_rack,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Player has just played "TX" (a phony) for 9 points at the center
+    fn setup(phony: bool) -> (Stage3State, Board, TileBag) {
+        let mut state = Stage3State::default();
+        let mut board = Board::default();
+        let mut tile_bag = TileBag::default();
+
+        state.player_rack = vec!['A', 'B', 'C', 'D', 'E'];
+        let placements = vec![
+            TilePlacement { row: 7, col: 7, letter: 'T', is_blank: false },
+            TilePlacement { row: 7, col: 8, letter: 'X', is_blank: false },
+        ];
+        for p in &placements {
+            board.place_placement(p).unwrap();
+        }
+        state.player_score = 9;
+        state.record_move(Turn::Player, MoveKind::Play, "TX".to_string(), 9, (7, 7), Direction::Horizontal);
+        state.pending_challenge = Some(PendingChallenge {
+            player: Turn::Player,
+            placements,
+            tiles_used: vec!['T', 'X'],
+            word: "TX".to_string(),
+            phonies: if phony { vec!["TX".to_string()] } else { Vec::new() },
+            score: 9,
+            record_index: 0,
+            scoreless_before: 0,
+        });
+        tile_bag.draw_tiles(10);
+
+        (state, board, tile_bag)
+    }
+
+    #[test]
+    fn test_successful_challenge_removes_phony() {
+        let (mut state, mut board, mut tile_bag) = setup(true);
+        let bag_before = tile_bag.count();
+
+        let outcome = resolve_challenge(&mut state, &mut board, &mut tile_bag, ChallengeRule::Single, true);
+
+        assert_eq!(outcome, Some(ChallengeOutcome::PhonyRemoved));
+        assert!(board.is_board_empty());
+        assert_eq!(state.player_score, 0);
+        assert_eq!(state.player_rack.len(), 7);
+        assert_eq!(tile_bag.count(), bag_before);
+        assert_eq!(state.moves_history[0].kind, MoveKind::PhonyWithdrawn);
+        assert_eq!(state.scoreless_turns, 1);
+    }
+
+    #[test]
+    fn test_accepted_play_draws_tiles() {
+        let (mut state, mut board, mut tile_bag) = setup(true);
+
+        let outcome = resolve_challenge(&mut state, &mut board, &mut tile_bag, ChallengeRule::Double, false);
+
+        assert_eq!(outcome, Some(ChallengeOutcome::Accepted));
+        assert_eq!(board.get(7, 8), Some('X'));
+        assert_eq!(state.player_score, 9);
+        assert_eq!(state.player_rack.len(), RACK_SIZE);
+    }
+
+    #[test]
+    fn test_failed_challenge_penalties() {
+        // Double: the challenger loses their turn
+        let (mut state, mut board, mut tile_bag) = setup(false);
+        state.current_turn = Turn::AI;
+        let outcome = resolve_challenge(&mut state, &mut board, &mut tile_bag, ChallengeRule::Double, true);
+        assert_eq!(outcome, Some(ChallengeOutcome::PlayStands));
+        assert_eq!(state.current_turn, Turn::Player);
+        assert_eq!(state.moves_history.last().unwrap().kind, MoveKind::LostChallenge);
+
+        // Five-point: the challenged player gets the bonus
+        let (mut state, mut board, mut tile_bag) = setup(false);
+        resolve_challenge(&mut state, &mut board, &mut tile_bag, ChallengeRule::FivePoint, true);
+        assert_eq!(state.player_score, 9 + FIVE_POINT_BONUS);
+
+        // Single: no penalty either way
+        let (mut state, mut board, mut tile_bag) = setup(false);
+        state.current_turn = Turn::AI;
+        resolve_challenge(&mut state, &mut board, &mut tile_bag, ChallengeRule::Single, true);
+        assert_eq!(state.player_score, 9);
+        assert_eq!(state.current_turn, Turn::AI);
+    }
+}
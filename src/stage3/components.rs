@@ -1,6 +1,7 @@
 /// Components for Stage 3 (Classic Board)
 
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
 
 /// Marker for board tiles (placed on 15×15 grid)
 #[derive(Component)]
@@ -9,8 +10,13 @@ pub struct BoardTile {
     pub position: (usize, usize), // Row, col (0-14)
     pub is_locked: bool,          // true once move is confirmed
     pub is_preview: bool,         // true for move preview
+    pub is_blank: bool,           // Blank standing in for `letter`
 }
 
+/// Translucent tile showing where a suggested play would go
+#[derive(Component)]
+pub struct HintGhost;
+
 /// Marker for rack tiles (7 tiles in player's hand)
 #[derive(Component)]
 pub struct RackTile {
@@ -21,7 +27,7 @@ pub struct RackTile {
 }
 
 /// Premium square types on the board
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub enum PremiumSquare {
     Normal,
     DoubleLetter,  // DL
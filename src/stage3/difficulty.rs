@@ -1,6 +1,10 @@
 /// Difficulty levels for Stage 3 AI opponent
 
+use std::time::Duration;
 use bevy::prelude::*;
+use rand::Rng;
+use super::endgame::EndgameBudget;
+use super::simulation::SimulationSettings;
 
 /// AI difficulty level configuration
 #[derive(Debug, Clone)]
@@ -8,12 +12,19 @@ pub struct AIDifficulty {
     pub level: u8,
     pub name: &'static str,
     pub move_quality: f32,      // 0.0-1.0, higher = better moves
-    pub vocabulary_size: f32,   // 0.0-1.0, percentage of lexicon used
-    pub think_time_ms: u32,     // Simulated thinking time
+    pub vocabulary_size: f32,   // 0.0-1.0, most common share of the lexicon known
+    pub sim_candidates: usize,  // Moves simulated ahead (0 = static ranking only)
+    pub sim_iterations: u32,    // Sampled opponent racks per simulation
+    pub sim_plies: u8,          // Replies played out after each candidate
+    pub time_budget_ms: u32,    // Wall-clock limit for simulation
+    pub endgame_nodes: u64,     // Positions searched once the bag is empty (0 = plays greedily)
     pub uses_strategic_play: bool, // Plans ahead, blocks player
     pub error_rate: f32,        // 0.0-1.0, chance of suboptimal move
 }
 
+/// Most endgame positions a background (AI-vs-AI) game searches
+const BACKGROUND_ENDGAME_NODES: u64 = 2_000;
+
 /// 5 difficulty levels for AI
 pub const AI_DIFFICULTIES: [AIDifficulty; 5] = [
     // Level 1: Beginner AI
@@ -21,8 +32,12 @@ pub const AI_DIFFICULTIES: [AIDifficulty; 5] = [
         level: 1,
         name: "Beginner AI",
         move_quality: 0.3,      // Often plays short words
-        vocabulary_size: 0.4,   // Only knows common words
-        think_time_ms: 1000,
+        vocabulary_size: 0.03,  // Only knows common words
+        sim_candidates: 0,
+        sim_iterations: 0,
+        sim_plies: 0,
+        time_budget_ms: 0,
+        endgame_nodes: 0,
         uses_strategic_play: false,
         error_rate: 0.4,        // 40% chance of making a mistake
     },
@@ -31,8 +46,12 @@ pub const AI_DIFFICULTIES: [AIDifficulty; 5] = [
         level: 2,
         name: "Novice AI",
         move_quality: 0.5,
-        vocabulary_size: 0.6,
-        think_time_ms: 1500,
+        vocabulary_size: 0.08,
+        sim_candidates: 0,
+        sim_iterations: 0,
+        sim_plies: 0,
+        time_budget_ms: 0,
+        endgame_nodes: 0,
         uses_strategic_play: false,
         error_rate: 0.25,
     },
@@ -41,8 +60,12 @@ pub const AI_DIFFICULTIES: [AIDifficulty; 5] = [
         level: 3,
         name: "Intermediate AI",
         move_quality: 0.7,
-        vocabulary_size: 0.8,
-        think_time_ms: 2000,
+        vocabulary_size: 0.25,
+        sim_candidates: 0,
+        sim_iterations: 0,
+        sim_plies: 0,
+        time_budget_ms: 0,
+        endgame_nodes: 500,
         uses_strategic_play: true,  // Starts using strategy
         error_rate: 0.15,
     },
@@ -51,8 +74,12 @@ pub const AI_DIFFICULTIES: [AIDifficulty; 5] = [
         level: 4,
         name: "Advanced AI",
         move_quality: 0.85,
-        vocabulary_size: 0.95,
-        think_time_ms: 2500,
+        vocabulary_size: 0.6,
+        sim_candidates: 5,
+        sim_iterations: 100,
+        sim_plies: 2,
+        time_budget_ms: 800,
+        endgame_nodes: 5_000,
         uses_strategic_play: true,
         error_rate: 0.08,
     },
@@ -62,7 +89,11 @@ pub const AI_DIFFICULTIES: [AIDifficulty; 5] = [
         name: "Expert AI",
         move_quality: 0.98,     // Nearly perfect play
         vocabulary_size: 1.0,   // Knows entire lexicon
-        think_time_ms: 3000,
+        sim_candidates: 12,
+        sim_iterations: 400,
+        sim_plies: 2,
+        time_budget_ms: 2500,
+        endgame_nodes: 50_000,  // Expert: solves most endgames outright
         uses_strategic_play: true,
         error_rate: 0.02,       // Very rare mistakes
     },
@@ -78,12 +109,23 @@ pub fn get_ai_difficulty(level: u8) -> &'static AIDifficulty {
 
 impl AIDifficulty {
     /// Calculate if AI should make a suboptimal move
-    pub fn should_make_error(&self) -> bool {
-        use rand::Rng;
-        let mut rng = rand::thread_rng();
+    pub fn should_make_error(&self, rng: &mut impl Rng) -> bool {
         rng.gen::<f32>() < self.error_rate
     }
 
+    /// Share of the ranked move list (from the top) skipped before choosing
+    ///
+    /// Calibrated as (1 - move_quality)²: a Beginner plays around its
+    /// median move, an Expert almost always its best.
+    pub fn move_percentile(&self) -> f32 {
+        (1.0 - self.move_quality.clamp(0.0, 1.0)).powi(2)
+    }
+
+    /// Index of the move to aim for in a ranked list of `count` moves
+    pub fn target_move_index(&self, count: usize) -> usize {
+        (self.move_percentile() * count.saturating_sub(1) as f32).round() as usize
+    }
+
     /// Get maximum word length AI will consider
     pub fn max_word_length(&self) -> usize {
         match self.level {
@@ -118,6 +160,97 @@ impl AIDifficulty {
         self.level >= 4
     }
 
+    /// Chance of challenging a play that really is a phony
+    pub fn phony_detection_rate(&self) -> f32 {
+        match self.level {
+            1 => 0.3,
+            2 => 0.5,
+            3 => 0.7,
+            4 => 0.9,
+            5 => 1.0,  // Expert: knows every word
+            _ => 0.7,
+        }
+    }
+
+    /// Chance of wrongly challenging a valid play
+    pub fn false_challenge_rate(&self) -> f32 {
+        match self.level {
+            1 => 0.15,
+            2 => 0.1,
+            3 => 0.05,
+            4 => 0.02,
+            5 => 0.0,
+            _ => 0.05,
+        }
+    }
+
+    /// Chance of trying a phony instead of a real word
+    pub fn phony_rate(&self) -> f32 {
+        match self.level {
+            1 => 0.15,
+            2 => 0.08,
+            3 => 0.03,
+            _ => 0.0,  // Advanced and up never bluff
+        }
+    }
+
+    /// Decide whether to challenge a play (`is_phony` is the truth the AI may not see)
+    pub fn should_challenge(&self, is_phony: bool, rng: &mut impl Rng) -> bool {
+        let rate = if is_phony {
+            self.phony_detection_rate()
+        } else {
+            self.false_challenge_rate()
+        };
+        rng.gen::<f32>() < rate
+    }
+
+    /// Decide whether to try a phony this turn
+    pub fn should_play_phony(&self, rng: &mut impl Rng) -> bool {
+        rng.gen::<f32>() < self.phony_rate()
+    }
+
+    /// Simulation limits, or `None` for levels that rank moves statically
+    pub fn simulation_settings(&self) -> Option<SimulationSettings> {
+        (self.sim_candidates > 1 && self.sim_iterations > 0).then_some(SimulationSettings {
+            iterations: self.sim_iterations,
+            plies: self.sim_plies,
+            time_budget: (self.time_budget_ms > 0).then(|| Duration::from_millis(self.time_budget_ms as u64)),
+        })
+    }
+
+    /// Endgame search limits, or `None` for levels that keep playing greedily
+    pub fn endgame_budget(&self) -> Option<EndgameBudget> {
+        (self.endgame_nodes > 0).then_some(EndgameBudget {
+            max_nodes: self.endgame_nodes,
+            time_budget: None,
+        })
+    }
+
+    /// The same player with a search cheap enough for background games
+    ///
+    /// Moves are ranked statically and endgames get a small node budget,
+    /// so a tournament's AI-vs-AI games don't slow the player's opponent.
+    pub fn background(&self) -> AIDifficulty {
+        AIDifficulty {
+            sim_candidates: 0,
+            sim_iterations: 0,
+            sim_plies: 0,
+            endgame_nodes: self.endgame_nodes.min(BACKGROUND_ENDGAME_NODES),
+            ..self.clone()
+        }
+    }
+
+    /// The same player limited by iteration count alone
+    ///
+    /// Used when a game replays a chosen seed, so the simulation runs the
+    /// same number of iterations however fast the machine is.
+    pub fn replayable(&self) -> AIDifficulty {
+        AIDifficulty {
+            time_budget_ms: 0,
+            ..self.clone()
+        }
+    }
+
     /// Get number of candidate moves to consider
     pub fn candidate_move_count(&self) -> usize {
         match self.level {
@@ -155,10 +288,69 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_challenge_skill_progression() {
+        for i in 0..4 {
+            let current = &AI_DIFFICULTIES[i];
+            let next = &AI_DIFFICULTIES[i + 1];
+
+            assert!(next.phony_detection_rate() >= current.phony_detection_rate());
+            assert!(next.false_challenge_rate() <= current.false_challenge_rate());
+            assert!(next.phony_rate() <= current.phony_rate());
+        }
+        assert_eq!(AI_DIFFICULTIES[4].phony_rate(), 0.0);
+    }
+
     #[test]
     fn test_strategic_play() {
         assert!(!AI_DIFFICULTIES[0].uses_strategic_play); // Level 1
         assert!(!AI_DIFFICULTIES[1].uses_strategic_play); // Level 2
         assert!(AI_DIFFICULTIES[2].uses_strategic_play);  // Level 3+
     }
+
+    #[test]
+    fn test_move_quality_percentile() {
+        assert_eq!(get_ai_difficulty(1).target_move_index(101), 49);
+        assert_eq!(get_ai_difficulty(5).target_move_index(101), 0);
+        assert_eq!(get_ai_difficulty(1).target_move_index(0), 0);
+
+        for i in 0..4 {
+            assert!(AI_DIFFICULTIES[i + 1].move_percentile() <= AI_DIFFICULTIES[i].move_percentile());
+        }
+    }
+
+    #[test]
+    fn test_endgame_budget_grows_with_level() {
+        assert!(get_ai_difficulty(2).endgame_budget().is_none());
+
+        let expert = get_ai_difficulty(5).endgame_budget().unwrap();
+        let intermediate = get_ai_difficulty(3).endgame_budget().unwrap();
+        assert!(expert.max_nodes > intermediate.max_nodes);
+    }
+
+    #[test]
+    fn test_simulation_reserved_for_top_levels() {
+        assert!(get_ai_difficulty(3).simulation_settings().is_none());
+        assert!(get_ai_difficulty(4).simulation_settings().is_some());
+
+        let expert = get_ai_difficulty(5).simulation_settings().unwrap();
+        let advanced = get_ai_difficulty(4).simulation_settings().unwrap();
+        assert!(expert.iterations >= advanced.iterations);
+        assert!(expert.time_budget >= advanced.time_budget);
+
+        // Replayed seeds drop the wall-clock limit
+        let replayed = get_ai_difficulty(5).replayable().simulation_settings().unwrap();
+        assert_eq!(replayed.iterations, expert.iterations);
+        assert!(replayed.time_budget.is_none());
+    }
+
+    #[test]
+    fn test_background_search_is_cheap() {
+        let expert = get_ai_difficulty(5).background();
+        assert!(expert.simulation_settings().is_none());
+        assert_eq!(expert.endgame_budget().unwrap().max_nodes, BACKGROUND_ENDGAME_NODES);
+        assert_eq!(expert.move_quality, get_ai_difficulty(5).move_quality);
+
+        assert!(get_ai_difficulty(2).background().endgame_budget().is_none());
+    }
 }
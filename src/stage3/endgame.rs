@@ -0,0 +1,375 @@
+/// Endgame solver for Stage 3 (Classic Board)
+///
+/// Once the bag is empty both racks are known, so the rest of the game can
+/// be searched exactly. The solver runs negamax with alpha-beta pruning over
+/// the move generator's plays for each side, plus a pass. Going out ends the
+/// game and earns twice the opponent's leftover tiles (they're added to the
+/// mover and taken from the opponent); two passes in a row end it with both
+/// sides losing their own tiles. Search deepens one ply at a time until a
+/// full solve or the node/time budget runs out, keeping the deepest result.
+
+use bevy::prelude::*;
+use bevy::tasks::{AsyncComputeTaskPool, Task};
+use bevy::utils::{Duration, Instant};
+use super::{Stage3State, Turn};
+use super::ai::{poll_task, AIMove};
+use super::board::{play_coordinate, Board, TileBag};
+use super::leave::LeaveTable;
+use super::movegen::generate_moves;
+use super::scoring::rack_value;
+use super::systems::PlayerMoveBuilder;
+use crate::lexicon::Lexicon;
+use crate::scoring::ScoreCalculator;
+
+/// Deepest search attempted (every play uses at least one tile, so two full
+/// racks and a pass each can't take more)
+const MAX_DEPTH: u32 = 16;
+
+/// Limits for one endgame search
+#[derive(Debug, Clone, Copy)]
+pub struct EndgameBudget {
+    /// Positions visited before the search stops deepening
+    pub max_nodes: u64,
+    /// Wall-clock safety cap, or `None` to let the node count alone decide
+    /// (as the AI does, so a replayed seed searches the same); the first ply
+    /// is always searched in full
+    pub time_budget: Option<Duration>,
+}
+
+/// Budget used when the player asks for an analysis
+pub const ANALYSIS_BUDGET: EndgameBudget = EndgameBudget {
+    max_nodes: 20_000,
+    time_budget: Some(Duration::from_secs(3)),
+};
+
+/// Result of an endgame search
+#[derive(Debug, Clone)]
+pub struct EndgameSolution {
+    /// Best line for both sides, starting with the side to move (`None` = pass)
+    pub line: Vec<Option<AIMove>>,
+    /// Spread the side to move gains by the end of the line, leftovers included
+    pub value: i32,
+    /// Plies searched
+    pub depth: u32,
+    /// Positions visited
+    pub nodes: u64,
+    /// True if every line was followed to the end of the game
+    pub is_exact: bool,
+}
+
+impl EndgameSolution {
+    /// First move of the best line (`None` = pass)
+    pub fn best_move(&self) -> Option<&AIMove> {
+        self.line.first().and_then(|m| m.as_ref())
+    }
+}
+
+/// Finds the best play for the side holding `rack` against `opponent_rack`
+pub fn solve_endgame(
+    board: &Board,
+    rack: &[char],
+    opponent_rack: &[char],
+    lexicon: &Lexicon,
+    scorer: &ScoreCalculator,
+    budget: EndgameBudget,
+) -> EndgameSolution {
+    let mut search = Search {
+        lexicon,
+        scorer,
+        budget,
+        start: Instant::now(),
+        nodes: 0,
+        can_abort: false,
+        aborted: false,
+        hit_depth_limit: false,
+        principal: None,
+    };
+    let mut solution = EndgameSolution {
+        line: vec![None],
+        value: 0,
+        depth: 0,
+        nodes: 0,
+        is_exact: false,
+    };
+
+    for depth in 1..=MAX_DEPTH {
+        search.hit_depth_limit = false;
+        search.can_abort = depth > 1;
+
+        let Some((value, line)) = search.negamax(board, rack, opponent_rack, 0, depth, -i32::MAX, i32::MAX) else {
+            break;
+        };
+
+        search.principal = line.first().cloned().flatten();
+        solution = EndgameSolution {
+            line,
+            value,
+            depth,
+            nodes: search.nodes,
+            is_exact: !search.hit_depth_limit,
+        };
+        if solution.is_exact {
+            break;
+        }
+    }
+
+    solution.nodes = search.nodes;
+    solution
+}
+
+struct Search<'a> {
+    lexicon: &'a Lexicon,
+    scorer: &'a ScoreCalculator,
+    budget: EndgameBudget,
+    start: Instant,
+    nodes: u64,
+    /// Whether the budget may cut the current iteration short
+    can_abort: bool,
+    aborted: bool,
+    /// Set when some line stopped before the game ended
+    hit_depth_limit: bool,
+    /// Best first move from the previous iteration, tried first
+    principal: Option<AIMove>,
+}
+
+impl Search<'_> {
+    /// Value of the position for the side to move, with the line that gets it
+    ///
+    /// Returns `None` if the budget ran out.
+    #[allow(clippy::too_many_arguments)]
+    fn negamax(
+        &mut self,
+        board: &Board,
+        rack: &[char],
+        opponent_rack: &[char],
+        passes: u8,
+        depth: u32,
+        mut alpha: i32,
+        beta: i32,
+    ) -> Option<(i32, Vec<Option<AIMove>>)> {
+        self.nodes += 1;
+        if self.can_abort
+            && (self.nodes > self.budget.max_nodes
+                || self.budget.time_budget.is_some_and(|budget| self.start.elapsed() > budget))
+        {
+            self.aborted = true;
+        }
+        if self.aborted {
+            return None;
+        }
+
+        // Both sides passed: the game ends and everyone loses their tiles
+        if passes >= 2 {
+            let value = rack_value(opponent_rack, self.scorer) as i32 - rack_value(rack, self.scorer) as i32;
+            return Some((value, Vec::new()));
+        }
+        if depth == 0 {
+            self.hit_depth_limit = true;
+            return Some((0, Vec::new()));
+        }
+
+        let mut moves: Vec<Option<AIMove>> = generate_moves(board, rack, self.lexicon, self.scorer)
+            .into_iter()
+            .map(Some)
+            .collect();
+        // Only the root sees the previous iteration's best move
+        if let Some(principal) = self.principal.take() {
+            if let Some(i) = moves.iter().position(|m| same_play(m.as_ref(), &principal)) {
+                let first = moves.remove(i);
+                moves.insert(0, first);
+            }
+        }
+        moves.push(None);
+
+        let mut best: Option<(i32, Vec<Option<AIMove>>)> = None;
+
+        for candidate in moves {
+            let (value, rest) = match &candidate {
+                Some(play) => {
+                    let leave = LeaveTable::leave_after(rack, &play.tiles_used);
+                    if leave.is_empty() {
+                        // Going out ends the game
+                        let bonus = 2 * rack_value(opponent_rack, self.scorer) as i32;
+                        (play.score as i32 + bonus, Vec::new())
+                    } else {
+                        let mut next = board.clone();
+                        for placement in &play.placements {
+                            let _ = next.place_placement(placement);
+                        }
+                        let (value, rest) = self.negamax(&next, opponent_rack, &leave, 0, depth - 1, -beta, -alpha)?;
+                        (play.score as i32 - value, rest)
+                    }
+                }
+                None => {
+                    let (value, rest) =
+                        self.negamax(board, opponent_rack, rack, passes + 1, depth - 1, -beta, -alpha)?;
+                    (-value, rest)
+                }
+            };
+
+            if best.as_ref().is_none_or(|(best_value, _)| value > *best_value) {
+                let mut line = vec![candidate];
+                line.extend(rest);
+                best = Some((value, line));
+            }
+            alpha = alpha.max(value);
+            if alpha >= beta {
+                break;
+            }
+        }
+
+        best
+    }
+}
+
+fn same_play(candidate: Option<&AIMove>, other: &AIMove) -> bool {
+    candidate.is_some_and(|m| {
+        m.placements.len() == other.placements.len()
+            && m.placements.iter().zip(&other.placements).all(|(a, b)| {
+                (a.row, a.col, a.letter, a.is_blank) == (b.row, b.col, b.letter, b.is_blank)
+            })
+    })
+}
+
+/// Describes a move for the analysis view
+pub fn describe_play(play: Option<&AIMove>) -> String {
+    match play {
+        Some(play) => format!(
+            "{} {} ({})",
+            play_coordinate(play.position, play.direction),
+            play.word,
+            play.score
+        ),
+        None => "Pass".to_string(),
+    }
+}
+
+/// Endgame analysis requested by the player
+#[derive(Resource, Default)]
+pub struct EndgameAnalysis {
+    /// Whether the analysis panel is open
+    pub is_visible: bool,
+    /// Solution for the position it was computed in
+    pub solution: Option<EndgameSolution>,
+    /// Length of the move history when it was computed
+    pub computed_at: usize,
+    /// Solver run for `computed_at`, dropped if the player's turn moves on first
+    pub search: Option<Task<EndgameSolution>>,
+}
+
+/// Toggle the endgame analysis with 'A' on the player's turn once the bag is empty
+///
+/// The search runs on the async compute pool; the panel says so until it's done.
+#[allow(clippy::too_many_arguments)]
+pub fn toggle_endgame_analysis(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut analysis: ResMut<EndgameAnalysis>,
+    state: Res<Stage3State>,
+    move_builder: Res<PlayerMoveBuilder>,
+    board: Res<Board>,
+    tile_bag: Res<TileBag>,
+    lexicon: Res<Lexicon>,
+    scorer: Res<ScoreCalculator>,
+) {
+    let available = state.is_active
+        && state.current_turn == Turn::Player
+        && state.pending_challenge.is_none()
+        && !move_builder.awaiting_blank_letter()
+        && tile_bag.is_empty();
+    let position = state.moves_history.len();
+
+    if let Some(solution) = poll_task(&mut analysis.bypass_change_detection().search) {
+        info!(
+            "Endgame analysis: depth {}, {} nodes, value {:+}",
+            solution.depth, solution.nodes, solution.value
+        );
+        analysis.solution = Some(solution);
+    }
+
+    // The analysis only describes the position it was computed in
+    if analysis.computed_at != position && (analysis.is_visible || analysis.search.is_some()) {
+        analysis.is_visible = false;
+        analysis.search = None;
+    }
+    if analysis.is_visible && !available {
+        analysis.is_visible = false;
+    }
+
+    if !available || !keyboard.just_pressed(KeyCode::KeyA) {
+        return;
+    }
+
+    if analysis.is_visible {
+        analysis.is_visible = false;
+        return;
+    }
+
+    if analysis.search.is_none() && (analysis.solution.is_none() || analysis.computed_at != position) {
+        let (board, rack, opponent_rack) = (board.clone(), state.player_rack.clone(), state.ai_rack.clone());
+        let (lexicon, scorer) = (lexicon.clone(), scorer.clone());
+        analysis.solution = None;
+        analysis.computed_at = position;
+        analysis.search = Some(AsyncComputeTaskPool::get().spawn(async move {
+            solve_endgame(&board, &rack, &opponent_rack, &lexicon, &scorer, ANALYSIS_BUDGET)
+        }));
+    }
+    analysis.is_visible = true;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn budget() -> EndgameBudget {
+        EndgameBudget {
+            max_nodes: 100_000,
+            time_budget: None,
+        }
+    }
+
+    #[test]
+    fn test_goes_out_when_possible() {
+        let lexicon = Lexicon::from_words(["CAT", "CATS", "AT"], "Test");
+        let scorer = ScoreCalculator::new();
+        let board = Board::with_word("CAT", 7, 6);
+
+        let solution = solve_endgame(&board, &['S'], &['Q', 'Z'], &lexicon, &scorer, budget());
+
+        // CATS for 6, then twice the opponent's Q and Z
+        let best = solution.best_move().unwrap();
+        assert_eq!(best.word, "CATS");
+        assert_eq!(solution.value, 6 + 2 * (10 + 10));
+        assert!(solution.is_exact);
+    }
+
+    #[test]
+    fn test_passes_when_stuck() {
+        let lexicon = Lexicon::from_words(["CAT"], "Test");
+        let scorer = ScoreCalculator::new();
+        let board = Board::with_word("CAT", 7, 6);
+
+        // Neither side can play: both lose their own tiles
+        let solution = solve_endgame(&board, &['Q'], &['Z', 'E'], &lexicon, &scorer, budget());
+
+        assert!(solution.best_move().is_none());
+        assert_eq!(solution.value, (10 + 1) - 10);
+        assert!(solution.is_exact);
+        assert_eq!(describe_play(solution.best_move()), "Pass");
+    }
+
+    #[test]
+    fn test_blocks_opponent_going_out() {
+        let lexicon = Lexicon::from_words(["CAT", "CATS"], "Test");
+        let scorer = ScoreCalculator::new();
+        let board = Board::with_word("CAT", 7, 6);
+
+        // Passing would let the opponent hook their S and go out; taking
+        // the hook first scores 6 and leaves both sides stuck
+        let solution = solve_endgame(&board, &['S', 'V'], &['S'], &lexicon, &scorer, budget());
+
+        assert_eq!(solution.best_move().map(|m| m.word.as_str()), Some("CATS"));
+        assert_eq!(solution.value, 6 + 1 - 4);
+        assert_eq!(describe_play(solution.best_move()), "8G CATS (6)");
+        assert!(solution.is_exact);
+    }
+}
@@ -0,0 +1,597 @@
+/// Stage 3 game records in GCG format
+///
+/// GCG is the plain-text game record read by most word-game analysis tools.
+/// Every finished game is written to the games folder in the user data
+/// directory: one line per move with the mover's rack, the play's
+/// coordinate (`8H` across, `H8` down), its word and the running score.
+/// Tiles already on the board show as `.` and blanks in lowercase;
+/// exchanges, passes, withdrawn phonies, challenge bonuses and the
+/// leftover-tile adjustments each get their own line.
+///
+/// `parse_gcg` reads a record back, rebuilding the board move by move so
+/// any position in the game can be looked at again.
+
+use std::fs;
+use std::path::PathBuf;
+use bevy::prelude::*;
+use super::{Direction, GameOverReason, MoveKind, Stage3State, Turn};
+use super::board::{play_coordinate, Board, TilePlacement, BLANK};
+use super::scoring::score_move;
+use crate::lexicon::Lexicon;
+use crate::plugins::rng::GameRng;
+use crate::plugins::state::LastStageCompleted;
+use crate::scoring::ScoreCalculator;
+
+const BOARD_SIZE: usize = 15;
+
+/// Nicknames Stage 3 games are written with (player 1 always moves first)
+const NICKNAMES: [&str; 2] = ["Player", "AI"];
+const FULL_NAMES: [&str; 2] = ["Player", "TileMania AI"];
+
+/// What a GCG line did
+#[derive(Debug, Clone, PartialEq)]
+pub enum GcgAction {
+    /// Tiles placed on the board
+    Play {
+        /// First square of the main word
+        position: (usize, usize),
+        direction: Direction,
+        /// The whole main word, including tiles already on the board
+        word: String,
+        placements: Vec<TilePlacement>,
+    },
+    /// Tiles swapped with the bag
+    Exchange(Vec<char>),
+    Pass,
+    /// The previous play taken back after a successful challenge
+    Withdrawn,
+    /// Points for an unsuccessful challenge against this player
+    ChallengeBonus,
+    /// Points for going out, from the opponent's leftover tiles
+    EndRack(Vec<char>),
+    /// Value of this player's own leftover tiles
+    RackPenalty(Vec<char>),
+    /// Overtime penalty
+    TimePenalty,
+}
+
+/// One move line of a GCG record
+#[derive(Debug, Clone, PartialEq)]
+pub struct GcgMove {
+    /// 0 for player 1, 1 for player 2
+    pub player: usize,
+    /// Rack before the move (may be empty if the record doesn't give it)
+    pub rack: Vec<char>,
+    pub action: GcgAction,
+    pub score: i32,
+    /// Player's running total after the move
+    pub total: i32,
+    /// `#note` lines following the move
+    pub note: Option<String>,
+}
+
+/// A game read from a GCG record
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct GcgGame {
+    /// Nicknames of player 1 and player 2
+    pub nicknames: [String; 2],
+    pub full_names: [String; 2],
+    pub lexicon: Option<String>,
+    pub moves: Vec<GcgMove>,
+}
+
+impl GcgGame {
+    /// Board after the first `count` moves
+    pub fn board_after(&self, count: usize) -> Board {
+        let mut board = Board::default();
+        for index in 0..count.min(self.moves.len()) {
+            apply_move(&mut board, &self.moves, index);
+        }
+        board
+    }
+
+    /// Running totals of both players after the first `count` moves
+    pub fn scores_after(&self, count: usize) -> [i32; 2] {
+        let mut scores = [0, 0];
+        for gcg_move in self.moves.iter().take(count) {
+            scores[gcg_move.player] = gcg_move.total;
+        }
+        scores
+    }
+}
+
+/// Writes a game to GCG text
+pub fn export_gcg(state: &Stage3State, scorer: &ScoreCalculator, lexicon: &str, seed: u64) -> String {
+    let mut lines = vec!["#character-encoding UTF-8".to_string()];
+    for (index, nickname) in NICKNAMES.iter().enumerate() {
+        lines.push(format!("#player{} {} {}", index + 1, nickname, FULL_NAMES[index]));
+    }
+    lines.push(format!("#lexicon {}", lexicon));
+    lines.push(format!("#id tilemania {}", seed));
+
+    let mut board = Board::default();
+    let mut totals = [0i32; 2];
+    let mut push = |lines: &mut Vec<String>, player: usize, rack: String, action: String, score: i32| {
+        totals[player] += score;
+        // An empty rack or action leaves its field out rather than a gap
+        let fields: Vec<&str> = [rack.as_str(), action.as_str()].into_iter().filter(|f| !f.is_empty()).collect();
+        lines.push(format!(">{}: {} {:+} {}", NICKNAMES[player], fields.join(" "), score, totals[player]));
+    };
+
+    for record in &state.moves_history {
+        let player = player_index(record.player);
+        let rack = rack_text(&record.rack);
+
+        match &record.kind {
+            MoveKind::Play | MoveKind::PhonyWithdrawn => {
+                let Some(first) = record.placements.first() else {
+                    continue;
+                };
+                let direction = board.main_direction(&record.placements);
+                let squares = board.word_through(&record.placements, (first.row, first.col), direction);
+                let coordinate = play_coordinate((squares[0].row, squares[0].col), direction);
+                let word: String = squares
+                    .iter()
+                    .map(|square| match (square.is_new, square.is_blank) {
+                        (false, _) => '.',
+                        (true, true) => square.letter.to_ascii_lowercase(),
+                        (true, false) => square.letter,
+                    })
+                    .collect();
+
+                if record.kind == MoveKind::Play {
+                    push(&mut lines, player, rack, format!("{} {}", coordinate, word), record.score as i32);
+                    for placement in &record.placements {
+                        let _ = board.place_placement(placement);
+                    }
+                } else {
+                    // The phony went down with its score before being taken back
+                    let score = score_move(&board, &record.placements, direction, scorer) as i32;
+                    push(&mut lines, player, rack.clone(), format!("{} {}", coordinate, word), score);
+                    push(&mut lines, player, rack, "--".to_string(), -score);
+                }
+            }
+            MoveKind::Exchange(tiles) => {
+                push(&mut lines, player, rack, format!("-{}", rack_text(tiles)), 0);
+            }
+            MoveKind::Pass => push(&mut lines, player, rack, "-".to_string(), 0),
+            MoveKind::LostChallenge => {
+                push(&mut lines, player, rack, "-".to_string(), 0);
+                lines.push("#note Lost challenge".to_string());
+            }
+            MoveKind::ChallengeBonus => {
+                push(&mut lines, player, rack, "(challenge)".to_string(), record.score as i32);
+            }
+        }
+    }
+
+    // Leftover tiles: going out collects the opponent's, otherwise each side loses its own
+    if let Some(adjustments) = state.end_adjustments {
+        let racks = [&state.player_rack, &state.ai_rack];
+        let adjustments = [adjustments.0, adjustments.1];
+        let went_out = match state.game_over_reason {
+            Some(GameOverReason::RackOut(turn)) => Some(player_index(turn)),
+            _ => None,
+        };
+
+        if let Some(winner) = went_out {
+            let loser = 1 - winner;
+            let leftover = rack_text(racks[loser]);
+            push(&mut lines, winner, String::new(), format!("({})", leftover), adjustments[winner]);
+            push(&mut lines, loser, leftover.clone(), format!("({})", leftover), adjustments[loser]);
+        } else {
+            for player in 0..2 {
+                if !racks[player].is_empty() {
+                    let leftover = rack_text(racks[player]);
+                    push(&mut lines, player, leftover.clone(), format!("({})", leftover), adjustments[player]);
+                }
+            }
+        }
+    }
+
+    lines.push(String::new());
+    lines.join("\n")
+}
+
+/// Reads a GCG record, checking every play against the board as it goes
+pub fn parse_gcg(text: &str) -> Result<GcgGame, String> {
+    let mut game = GcgGame::default();
+    let mut board = Board::default();
+
+    for (line_number, line) in text.lines().enumerate() {
+        let line = line.trim();
+        let error = |message: String| format!("Line {}: {}", line_number + 1, message);
+
+        if let Some(pragma) = line.strip_prefix('#') {
+            let (keyword, rest) = pragma.split_once(char::is_whitespace).unwrap_or((pragma, ""));
+            let rest = rest.trim();
+            match keyword {
+                "player1" | "player2" => {
+                    let index = if keyword == "player1" { 0 } else { 1 };
+                    let (nickname, full_name) = rest.split_once(char::is_whitespace).unwrap_or((rest, rest));
+                    game.nicknames[index] = nickname.to_string();
+                    game.full_names[index] = full_name.trim().to_string();
+                }
+                "lexicon" => game.lexicon = Some(rest.to_string()),
+                "note" => {
+                    if let Some(last) = game.moves.last_mut() {
+                        match &mut last.note {
+                            Some(note) => {
+                                note.push('\n');
+                                note.push_str(rest);
+                            }
+                            None => last.note = Some(rest.to_string()),
+                        }
+                    }
+                }
+                _ => {}
+            }
+            continue;
+        }
+
+        let Some(body) = line.strip_prefix('>') else {
+            continue;
+        };
+        let (nickname, fields) = body.split_once(':').ok_or_else(|| error("missing ':' after nickname".to_string()))?;
+        let player = game
+            .nicknames
+            .iter()
+            .position(|known| known == nickname.trim())
+            .ok_or_else(|| error(format!("unknown player '{}'", nickname.trim())))?;
+
+        let gcg_move = parse_move(player, fields, &board, &game.moves).map_err(error)?;
+        game.moves.push(gcg_move);
+        apply_move(&mut board, &game.moves, game.moves.len() - 1);
+    }
+
+    if game.nicknames.iter().any(|nickname| nickname.is_empty()) {
+        return Err("Record must name both players with #player1 and #player2".to_string());
+    }
+    Ok(game)
+}
+
+/// Parses the part of a move line after the nickname
+fn parse_move(player: usize, fields: &str, board: &Board, previous: &[GcgMove]) -> Result<GcgMove, String> {
+    let mut tokens: Vec<&str> = fields.split_whitespace().collect();
+    if tokens.len() < 3 {
+        return Err(format!("expected a move, score and total in '{}'", fields.trim()));
+    }
+    let total = parse_score(tokens.pop().unwrap_or_default())?;
+    let score = parse_score(tokens.pop().unwrap_or_default())?;
+
+    // The rack is left out of end-of-game lines like ">Joe: (EIO) +6 400"
+    let rack = if tokens.len() > 1 || !tokens[0].starts_with('(') {
+        parse_tiles(tokens.remove(0))?
+    } else {
+        Vec::new()
+    };
+
+    let action = match tokens.as_slice() {
+        [coordinate, word] => {
+            let (position, direction) = parse_coordinate(coordinate)?;
+            let (word, placements) = parse_word(word, position, direction, board)?;
+            GcgAction::Play { position, direction, word, placements }
+        }
+        ["--"] => {
+            let withdrawn = previous.iter().rev().find(|m| m.player == player);
+            if !matches!(withdrawn, Some(GcgMove { action: GcgAction::Play { .. }, .. })) {
+                return Err("'--' must follow a play by the same player".to_string());
+            }
+            GcgAction::Withdrawn
+        }
+        ["-"] => GcgAction::Pass,
+        ["(challenge)"] => GcgAction::ChallengeBonus,
+        ["(time)"] => GcgAction::TimePenalty,
+        [token] if token.starts_with('-') => GcgAction::Exchange(parse_tiles(&token[1..])?),
+        [token] if token.starts_with('(') && token.ends_with(')') => {
+            let tiles = parse_tiles(&token[1..token.len() - 1])?;
+            if score < 0 || (!rack.is_empty() && rack == tiles) {
+                GcgAction::RackPenalty(tiles)
+            } else {
+                GcgAction::EndRack(tiles)
+            }
+        }
+        _ => return Err(format!("unrecognised move '{}'", fields.trim())),
+    };
+
+    Ok(GcgMove { player, rack, action, score, total, note: None })
+}
+
+/// Puts move `index` on the board (or takes back the play it withdraws)
+fn apply_move(board: &mut Board, moves: &[GcgMove], index: usize) {
+    let gcg_move = &moves[index];
+    match &gcg_move.action {
+        GcgAction::Play { placements, .. } => {
+            for placement in placements {
+                let _ = board.place_placement(placement);
+            }
+        }
+        GcgAction::Withdrawn => {
+            let withdrawn = moves[..index].iter().rev().find(|m| m.player == gcg_move.player);
+            if let Some(GcgMove { action: GcgAction::Play { placements, .. }, .. }) = withdrawn {
+                for placement in placements {
+                    board.remove(placement.row, placement.col);
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+/// "8H" is row 8 across from column H; "H8" is column H down from row 8
+fn parse_coordinate(coordinate: &str) -> Result<((usize, usize), Direction), String> {
+    let invalid = || format!("invalid coordinate '{}'", coordinate);
+    if !coordinate.is_ascii() {
+        return Err(invalid());
+    }
+    let upper = coordinate.to_ascii_uppercase();
+    let starts_with_digit = upper.starts_with(|c: char| c.is_ascii_digit());
+    let (digits, letters, direction) = if starts_with_digit {
+        let split = upper.find(|c: char| !c.is_ascii_digit()).ok_or_else(invalid)?;
+        (&upper[..split], &upper[split..], Direction::Horizontal)
+    } else {
+        (&upper[1..], &upper[..1], Direction::Vertical)
+    };
+
+    let row = digits.parse::<usize>().map_err(|_| invalid())?.checked_sub(1).ok_or_else(invalid)?;
+    let mut letters = letters.chars();
+    let col = match (letters.next(), letters.next()) {
+        (Some(letter @ 'A'..='Z'), None) => (letter as u8 - b'A') as usize,
+        _ => return Err(invalid()),
+    };
+    if row >= BOARD_SIZE || col >= BOARD_SIZE {
+        return Err(invalid());
+    }
+    Ok(((row, col), direction))
+}
+
+/// Reads a played word, returning the full word and the tiles it puts down
+///
+/// `.` and letters in parentheses stand for tiles already on the board;
+/// lowercase letters are blanks.
+fn parse_word(
+    word: &str,
+    position: (usize, usize),
+    direction: Direction,
+    board: &Board,
+) -> Result<(String, Vec<TilePlacement>), String> {
+    let mut letters = String::new();
+    let mut placements = Vec::new();
+    let (mut row, mut col) = position;
+    let mut played_through = false;
+
+    for symbol in word.chars() {
+        match symbol {
+            '(' => {
+                played_through = true;
+                continue;
+            }
+            ')' => {
+                played_through = false;
+                continue;
+            }
+            _ => {}
+        }
+        if row >= BOARD_SIZE || col >= BOARD_SIZE {
+            return Err(format!("'{}' runs off the board", word));
+        }
+
+        match board.get(row, col) {
+            Some(existing) if symbol == '.' || played_through || symbol.eq_ignore_ascii_case(&existing) => {
+                letters.push(existing);
+            }
+            Some(existing) => {
+                return Err(format!("'{}' conflicts with {} already on the board", word, existing));
+            }
+            None if symbol == '.' || played_through => {
+                return Err(format!("'{}' plays through an empty square", word));
+            }
+            None if symbol.is_ascii_alphabetic() => {
+                let letter = symbol.to_ascii_uppercase();
+                letters.push(letter);
+                placements.push(TilePlacement { row, col, letter, is_blank: symbol.is_ascii_lowercase() });
+            }
+            None => return Err(format!("invalid letter '{}' in '{}'", symbol, word)),
+        }
+
+        match direction {
+            Direction::Horizontal => col += 1,
+            Direction::Vertical => row += 1,
+        }
+    }
+
+    if placements.is_empty() {
+        return Err(format!("'{}' places no tiles", word));
+    }
+    Ok((letters, placements))
+}
+
+fn parse_score(token: &str) -> Result<i32, String> {
+    token.parse().map_err(|_| format!("invalid score '{}'", token))
+}
+
+/// Rack letters, with '?' for a blank
+fn parse_tiles(text: &str) -> Result<Vec<char>, String> {
+    text.chars()
+        .map(|tile| match tile {
+            '?' => Ok(BLANK),
+            'A'..='Z' => Ok(tile),
+            _ => Err(format!("invalid tile '{}' in '{}'", tile, text)),
+        })
+        .collect()
+}
+
+/// Rack letters as written in a record, with '?' for a blank
+pub fn rack_text(tiles: &[char]) -> String {
+    tiles.iter().map(|&tile| if tile == BLANK { '?' } else { tile }).collect()
+}
+
+fn player_index(turn: Turn) -> usize {
+    match turn {
+        Turn::Player => 0,
+        Turn::AI => 1,
+    }
+}
+
+/// Folder finished games are written to
+pub fn games_dir() -> PathBuf {
+    dirs::data_dir()
+        .map(|dir| dir.join("tilemania").join("games"))
+        .unwrap_or_else(|| PathBuf::from("games"))
+}
+
+/// Saved game records, newest first
+pub fn game_record_files() -> Vec<PathBuf> {
+    let Ok(entries) = fs::read_dir(games_dir()) else {
+        return Vec::new();
+    };
+    let mut files: Vec<(std::time::SystemTime, PathBuf)> = entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().is_some_and(|ext| ext.eq_ignore_ascii_case("gcg")))
+        .map(|path| {
+            let modified = fs::metadata(&path).and_then(|m| m.modified()).unwrap_or(std::time::UNIX_EPOCH);
+            (modified, path)
+        })
+        .collect();
+    files.sort_by_key(|(modified, _)| std::cmp::Reverse(*modified));
+    files.into_iter().map(|(_, path)| path).collect()
+}
+
+/// The last finished game, as saved
+#[derive(Resource, Default)]
+pub struct SavedGameRecord {
+    pub path: Option<PathBuf>,
+    /// The record read back, ready for review
+    pub game: Option<GcgGame>,
+}
+
+/// Write the finished game to the games folder as it reaches the results screen
+pub fn save_game_record(
+    state: Res<Stage3State>,
+    scorer: Res<ScoreCalculator>,
+    lexicon: Res<Lexicon>,
+    rng: Res<GameRng>,
+    last_stage: Res<LastStageCompleted>,
+    mut saved: ResMut<SavedGameRecord>,
+) {
+    saved.path = None;
+    saved.game = None;
+    if *last_stage != LastStageCompleted::Stage3 || state.moves_history.is_empty() {
+        return;
+    }
+
+    let dir = games_dir();
+    let file_name = format!("stage3-{}.gcg", chrono::Local::now().format("%Y%m%d-%H%M%S"));
+    let path = dir.join(file_name);
+    let record = export_gcg(&state, &scorer, &lexicon.lexicon_name, rng.seed());
+    saved.game = parse_gcg(&record).ok();
+
+    match fs::create_dir_all(&dir).and_then(|_| fs::write(&path, record)) {
+        Ok(()) => {
+            info!("💾 Saved game record to {:?}", path);
+            saved.path = Some(path);
+        }
+        Err(e) => warn!("⚠️ Failed to save game record: {}", e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn placements(word: &str, row: usize, col: usize, direction: Direction) -> Vec<TilePlacement> {
+        word.chars()
+            .enumerate()
+            .map(|(i, letter)| {
+                let (row, col) = match direction {
+                    Direction::Horizontal => (row, col + i),
+                    Direction::Vertical => (row + i, col),
+                };
+                TilePlacement { row, col, letter: letter.to_ascii_uppercase(), is_blank: letter.is_ascii_lowercase() }
+            })
+            .collect()
+    }
+
+    fn play(state: &mut Stage3State, turn: Turn, rack: &str, tiles: Vec<TilePlacement>, score: u32) {
+        let record = state.record_move(turn, MoveKind::Play, String::new(), score, (7, 7), Direction::Horizontal);
+        record.rack = rack.chars().collect();
+        record.placements = tiles;
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let scorer = ScoreCalculator::new();
+        let mut state = Stage3State::default();
+        play(&mut state, Turn::Player, "CATXYZ_", placements("CAT", 7, 6, Direction::Horizontal), 30);
+        play(&mut state, Turn::AI, "OWENSAB", placements("Ow", 8, 7, Direction::Vertical), 25);
+        state.record_move(Turn::Player, MoveKind::Exchange(vec!['X', '_']), String::new(), 0, (7, 7), Direction::Horizontal)
+            .rack = "XYZ_QQQ".chars().collect();
+        state.record_move(Turn::AI, MoveKind::Pass, String::new(), 0, (7, 7), Direction::Horizontal)
+            .rack = "ENSABQQ".chars().collect();
+        state.player_rack = vec!['Q'];
+        state.ai_rack = vec!['E', 'Z'];
+        state.player_score = 30;
+        state.ai_score = 25;
+        state.finish_game(GameOverReason::ScorelessTurns, &scorer);
+
+        let text = export_gcg(&state, &scorer, "TEST", 7);
+        assert!(text.contains(">Player: CATXYZ? 8G CAT +30 30"));
+        assert!(text.contains(">AI: OWENSAB H8 .Ow +25 25"));
+        assert!(text.contains(">Player: XYZ?QQQ -X? +0 30"));
+
+        let game = parse_gcg(&text).unwrap();
+        assert_eq!(game.nicknames, ["Player".to_string(), "AI".to_string()]);
+        assert_eq!(game.lexicon.as_deref(), Some("TEST"));
+        assert_eq!(game.moves.len(), 6);
+        assert_eq!(game.moves[2].action, GcgAction::Exchange(vec!['X', BLANK]));
+        assert_eq!(game.moves[4].action, GcgAction::RackPenalty(vec!['Q']));
+        assert_eq!(game.scores_after(game.moves.len()), [state.player_score as i32, state.ai_score as i32]);
+
+        match &game.moves[1].action {
+            GcgAction::Play { word, placements, .. } => {
+                assert_eq!(word, "AOW");
+                assert_eq!(placements[1], TilePlacement { row: 9, col: 7, letter: 'W', is_blank: true });
+            }
+            other => panic!("expected a play, got {:?}", other),
+        }
+
+        let board = game.board_after(2);
+        assert_eq!(board.get(7, 6), Some('C'));
+        assert_eq!(board.get(9, 7), Some('W'));
+        assert!(board.is_blank(9, 7));
+        assert!(game.board_after(1).is_empty(8, 7));
+    }
+
+    #[test]
+    fn test_withdrawn_phony() {
+        let scorer = ScoreCalculator::new();
+        let mut state = Stage3State::default();
+        play(&mut state, Turn::Player, "ZQXAAAA", placements("ZQX", 7, 7, Direction::Horizontal), 0);
+        state.moves_history[0].kind = MoveKind::PhonyWithdrawn;
+
+        let text = export_gcg(&state, &scorer, "TEST", 1);
+        let game = parse_gcg(&text).unwrap();
+        assert_eq!(game.moves.len(), 2);
+        assert_eq!(game.moves[1].action, GcgAction::Withdrawn);
+        assert_eq!(game.moves[0].score, -game.moves[1].score);
+        assert!(game.board_after(1).get(7, 7).is_some());
+        assert!(game.board_after(2).is_board_empty());
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let header = "#player1 A Ann\n#player2 B Bob\n";
+        assert!(parse_gcg(&format!("{}>A: ABC 8H ABC +10 10", header)).is_ok());
+        assert!(parse_gcg(&format!("{}>C: ABC 8H ABC +10 10", header)).is_err());
+        assert!(parse_gcg(&format!("{}>A: ABC 8H A.C +10 10", header)).is_err());
+        assert!(parse_gcg(&format!("{}>A: ABC 8Z ABC +10 10", header)).is_err());
+        assert!(parse_gcg(">A: ABC 8H ABC +10 10").is_err());
+
+        // Played-through letters may be written out in parentheses
+        let text = format!("{}>A: ABC 8H ABC +10 10\n>B: DEF H8 (A)D +3 3\n#note nice", header);
+        let game = parse_gcg(&text).unwrap();
+        assert_eq!(game.moves[1].note.as_deref(), Some("nice"));
+        assert_eq!(game.board_after(2).get(8, 7), Some('D'));
+    }
+}
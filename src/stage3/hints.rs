@@ -0,0 +1,151 @@
+/// Move hints for the human player in Stage 3
+///
+/// Pressing H (when `Stage3Config.allow_hints` is on) runs the AI move
+/// generator on the player's rack and lists the best plays by equity, score
+/// plus rack leave. Pressing H again steps through the list, ghosting the
+/// selected play on the board, and closes it after the last one. Each
+/// position the player asks about counts once towards `hints_used`. The
+/// search runs on the async compute pool, so large racks and blanks don't
+/// stall the frame.
+
+use bevy::prelude::*;
+use bevy::tasks::{AsyncComputeTaskPool, Task};
+use super::{Stage3Config, Stage3State, Turn};
+use super::ai::{apply_leave_equity, poll_task, AIMove};
+use super::board::Board;
+use super::leave::LeaveTable;
+use super::movegen::generate_moves;
+use super::systems::PlayerMoveBuilder;
+use crate::lexicon::Lexicon;
+use crate::scoring::ScoreCalculator;
+
+/// Number of plays a hint lists
+pub const HINT_COUNT: usize = 5;
+
+/// Hints for the player's current position
+#[derive(Resource, Default)]
+pub struct HintState {
+    /// Whether the hint list is open
+    pub is_visible: bool,
+    /// Best plays, highest equity first
+    pub suggestions: Vec<AIMove>,
+    /// Suggestion ghosted on the board
+    pub selected: usize,
+    /// Length of the move history when the suggestions were computed
+    pub computed_at: Option<usize>,
+    /// Move generation for `computed_at`, dropped if the position changes first
+    pub search: Option<Task<Vec<AIMove>>>,
+}
+
+impl HintState {
+    /// Whether the suggestions are still being searched for
+    pub fn is_searching(&self) -> bool {
+        self.search.is_some()
+    }
+
+    /// The suggestion being previewed, if the list is open
+    pub fn selected_move(&self) -> Option<&AIMove> {
+        self.suggestions.get(self.selected).filter(|_| self.is_visible)
+    }
+}
+
+/// The `count` best plays for `rack`, by equity
+pub fn suggest_moves(
+    board: &Board,
+    rack: &[char],
+    lexicon: &Lexicon,
+    scorer: &ScoreCalculator,
+    leaves: &LeaveTable,
+    count: usize,
+) -> Vec<AIMove> {
+    let mut moves = generate_moves(board, rack, lexicon, scorer);
+    apply_leave_equity(&mut moves, rack, leaves);
+    moves.sort_by(|a, b| b.equity.total_cmp(&a.equity));
+    moves.truncate(count);
+    moves
+}
+
+/// Open, step through and close the hint list with 'H'
+#[allow(clippy::too_many_arguments)]
+pub fn handle_hint_input(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut hints: ResMut<HintState>,
+    mut state: ResMut<Stage3State>,
+    config: Res<Stage3Config>,
+    move_builder: Res<PlayerMoveBuilder>,
+    board: Res<Board>,
+    lexicon: Res<Lexicon>,
+    scorer: Res<ScoreCalculator>,
+    leaves: Res<LeaveTable>,
+) {
+    let available = config.allow_hints
+        && state.is_active
+        && state.current_turn == Turn::Player
+        && state.pending_challenge.is_none()
+        && !move_builder.exchange_mode
+        && !move_builder.awaiting_blank_letter();
+
+    if let Some(suggestions) = poll_task(&mut hints.bypass_change_detection().search) {
+        info!("Hint {}: {} plays suggested", state.hints_used, suggestions.len());
+        hints.suggestions = suggestions;
+    }
+
+    // Suggestions only describe the position they were computed in
+    let position = state.moves_history.len();
+    if hints.computed_at != Some(position) && hints.is_searching() {
+        hints.search = None;
+        hints.computed_at = None;
+    }
+    if hints.is_visible && (!available || hints.computed_at != Some(position)) {
+        hints.is_visible = false;
+    }
+
+    if !available || !keyboard.just_pressed(KeyCode::KeyH) {
+        return;
+    }
+
+    if hints.is_visible {
+        // Keep the list open until there is something to step through
+        if hints.is_searching() {
+            return;
+        }
+        // Next suggestion, closing after the last
+        hints.selected += 1;
+        if hints.selected >= hints.suggestions.len() {
+            hints.is_visible = false;
+        }
+        return;
+    }
+
+    if hints.computed_at != Some(position) {
+        let (board, rack) = (board.clone(), state.player_rack.clone());
+        let (lexicon, scorer, leaves) = (lexicon.clone(), scorer.clone(), leaves.clone());
+        hints.suggestions.clear();
+        hints.computed_at = Some(position);
+        hints.search = Some(AsyncComputeTaskPool::get().spawn(async move {
+            suggest_moves(&board, &rack, &lexicon, &scorer, &leaves, HINT_COUNT)
+        }));
+        state.hints_used += 1;
+    }
+    hints.selected = 0;
+    hints.is_visible = true;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_suggestions_ranked_by_equity() {
+        let lexicon = Lexicon::from_words(["CAT", "CATS", "AT", "TA"], "Test");
+        let scorer = ScoreCalculator::new();
+        let leaves = LeaveTable::default();
+        let board = Board::with_word("CAT", 7, 6);
+
+        let suggestions = suggest_moves(&board, &['S', 'A', 'T'], &lexicon, &scorer, &leaves, 3);
+
+        assert!(!suggestions.is_empty() && suggestions.len() <= 3);
+        assert!(suggestions.windows(2).all(|pair| pair[0].equity >= pair[1].equity));
+        assert!(suggestions.iter().all(|m| lexicon.is_valid(&m.word)));
+    }
+}
@@ -0,0 +1,246 @@
+/// Rack-leave evaluation for the Stage 3 AI
+///
+/// A move's equity is its score plus the value of the tiles it leaves on
+/// the rack. Leave values come from a plain-text table: one leave per line
+/// followed by its value, where single tiles give the base value and longer
+/// entries adjust for combinations (duplicates, QU, ER and the like). Blanks
+/// are written as '?'. A built-in table is used when no file is present.
+
+use bevy::prelude::*;
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use super::board::BLANK;
+
+/// Leave table looked for at startup before falling back to the built-in one
+pub const LEAVE_TABLE_PATH: &str = "assets/leaves/leaves.txt";
+
+/// Built-in leave values (points of equity, roughly per tile kept)
+const BUILTIN_LEAVES: &str = "
+# Single tiles
+? 20.0
+A 0.5
+B -2.0
+C -0.5
+D 0.0
+E 1.5
+F -2.0
+G -2.5
+H 0.5
+I -0.5
+J -1.5
+K -1.5
+L -0.5
+M 0.0
+N 0.0
+O -1.5
+P -0.5
+Q -7.0
+R 1.0
+S 7.5
+T 0.0
+U -3.0
+V -5.0
+W -3.5
+X 3.0
+Y -0.5
+Z 2.0
+
+# Duplicates clog the rack
+AA -3.0
+EE -2.0
+II -4.0
+OO -3.0
+UU -5.0
+NN -2.5
+LL -2.0
+TT -2.0
+DD -2.0
+GG -3.5
+SS -3.0
+??  -5.0
+VV -4.0
+WW -4.0
+
+# Combinations that play well together
+QU 6.0
+ER 1.5
+ES 1.5
+RS 1.0
+ST 1.0
+IN 0.5
+ING 3.0
+ERS 1.0
+";
+
+/// Per-tile and per-combination leave values
+#[derive(Resource, Debug, Clone)]
+pub struct LeaveTable {
+    /// Value of keeping a single tile
+    singles: HashMap<char, f32>,
+    /// Adjustment for keeping every tile of a combination (sorted tiles)
+    combos: Vec<(Vec<char>, f32)>,
+}
+
+impl Default for LeaveTable {
+    fn default() -> Self {
+        Self::builtin()
+    }
+}
+
+impl LeaveTable {
+    /// The built-in table
+    pub fn builtin() -> Self {
+        Self::parse(BUILTIN_LEAVES).expect("built-in leave table is valid")
+    }
+
+    /// Loads a table from a file, falling back to the built-in one
+    pub fn load_default() -> Self {
+        if Path::new(LEAVE_TABLE_PATH).exists() {
+            match Self::load_from_file(LEAVE_TABLE_PATH) {
+                Ok(table) => return table,
+                Err(e) => warn!("Ignoring leave table {}: {}", LEAVE_TABLE_PATH, e),
+            }
+        }
+        Self::builtin()
+    }
+
+    /// Loads a table from a text file
+    pub fn load_from_file<P: AsRef<Path>>(path: P) -> Result<Self, String> {
+        let contents = fs::read_to_string(path.as_ref())
+            .map_err(|e| format!("Failed to read leave table: {}", e))?;
+        Self::parse(&contents)
+    }
+
+    /// Parses "LEAVE VALUE" lines; blank lines and '#' comments are skipped
+    pub fn parse(contents: &str) -> Result<Self, String> {
+        let mut singles = HashMap::new();
+        let mut combos = Vec::new();
+
+        for (number, line) in contents.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let mut parts = line.split_whitespace();
+            let (Some(leave), Some(value), None) = (parts.next(), parts.next(), parts.next()) else {
+                return Err(format!("Line {}: expected \"LEAVE VALUE\"", number + 1));
+            };
+            let value: f32 = value
+                .parse()
+                .map_err(|_| format!("Line {}: invalid value {}", number + 1, value))?;
+
+            let mut tiles = Vec::new();
+            for c in leave.chars() {
+                match c.to_ascii_uppercase() {
+                    '?' => tiles.push(BLANK),
+                    c if c.is_ascii_uppercase() => tiles.push(c),
+                    _ => return Err(format!("Line {}: invalid tile {}", number + 1, c)),
+                }
+            }
+            tiles.sort_unstable();
+
+            if let [tile] = tiles[..] {
+                singles.insert(tile, value);
+            } else {
+                combos.push((tiles, value));
+            }
+        }
+
+        Ok(Self { singles, combos })
+    }
+
+    /// Value of keeping `leave` on the rack
+    pub fn evaluate(&self, leave: &[char]) -> f32 {
+        let singles: f32 = leave
+            .iter()
+            .map(|tile| self.singles.get(tile).copied().unwrap_or(0.0))
+            .sum();
+
+        let combos: f32 = self
+            .combos
+            .iter()
+            .filter(|(tiles, _)| contains_all(leave, tiles))
+            .map(|(_, value)| value)
+            .sum();
+
+        singles + combos
+    }
+
+    /// Tiles left on `rack` after playing `tiles_used`
+    pub fn leave_after(rack: &[char], tiles_used: &[char]) -> Vec<char> {
+        let mut leave = rack.to_vec();
+        for tile in tiles_used {
+            if let Some(pos) = leave.iter().position(|t| t == tile) {
+                leave.remove(pos);
+            }
+        }
+        leave
+    }
+
+    /// Best subset of `rack` to keep when exchanging
+    pub fn best_keep(&self, rack: &[char]) -> Vec<char> {
+        let count = rack.len().min(16);
+        (0..(1u32 << count))
+            .map(|mask| {
+                (0..count)
+                    .filter(|i| mask & (1 << i) != 0)
+                    .map(|i| rack[i])
+                    .collect::<Vec<char>>()
+            })
+            .max_by(|a, b| self.evaluate(a).total_cmp(&self.evaluate(b)))
+            .unwrap_or_default()
+    }
+}
+
+/// Checks if `leave` holds every tile of `tiles` (counting duplicates)
+fn contains_all(leave: &[char], tiles: &[char]) -> bool {
+    let mut remaining = leave.to_vec();
+    tiles.iter().all(|tile| match remaining.iter().position(|t| t == tile) {
+        Some(pos) => {
+            remaining.remove(pos);
+            true
+        }
+        None => false,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_table() {
+        let table = LeaveTable::parse("# comment\nS 8\n? 25\nQU 5.5\n\nQ -7").unwrap();
+
+        assert_eq!(table.evaluate(&['S']), 8.0);
+        assert_eq!(table.evaluate(&[BLANK, 'S']), 33.0);
+        assert_eq!(table.evaluate(&['Q']), -7.0);
+        assert_eq!(table.evaluate(&['U', 'Q']), -1.5); // -7 + 0 + 5.5
+        assert_eq!(table.evaluate(&[]), 0.0);
+
+        assert!(LeaveTable::parse("S eight").is_err());
+        assert!(LeaveTable::parse("S1 2").is_err());
+    }
+
+    #[test]
+    fn test_builtin_prefers_good_leaves() {
+        let table = LeaveTable::builtin();
+
+        assert!(table.evaluate(&[BLANK]) > table.evaluate(&['S']));
+        assert!(table.evaluate(&['E', 'R', 'S']) > table.evaluate(&['U', 'U', 'V']));
+        assert!(table.evaluate(&['I', 'I']) < 2.0 * table.evaluate(&['I']));
+    }
+
+    #[test]
+    fn test_leave_after_and_best_keep() {
+        let rack = ['S', 'Q', 'E', 'E', BLANK];
+        assert_eq!(LeaveTable::leave_after(&rack, &['E', 'Q']), vec!['S', 'E', BLANK]);
+
+        let keep = LeaveTable::builtin().best_keep(&['Q', 'V', 'S', BLANK, 'U']);
+        assert!(keep.contains(&'S'));
+        assert!(keep.contains(&BLANK));
+        assert!(!keep.contains(&'V'));
+    }
+}
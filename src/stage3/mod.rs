@@ -4,22 +4,43 @@
 /// Features premium squares (DW, TW, DL, TL), 7-tile rack, and turn-based play.
 
 use bevy::prelude::*;
-use crate::plugins::state::GameState;
+use serde::{Deserialize, Serialize};
+use crate::plugins::rating::{level_rating, PlayerRating};
+use crate::plugins::rng::GameRng;
+use crate::plugins::settings::GameSettings;
+use crate::plugins::state::{GameState, LastStageCompleted};
 
 pub mod components;
 pub mod difficulty;
+pub mod endgame;
+pub mod gcg;
+pub mod hints;
 pub mod systems;
 pub mod ai;
+pub mod autoplay;
+pub mod movegen;
+pub mod personality;
+pub mod scoring;
+pub mod simulation;
+pub mod validation;
 pub mod board;
+pub mod leave;
+pub mod challenge;
 pub mod ui;
 pub mod visuals;
 pub mod pause;
+pub mod review;
 pub mod audio;
+pub mod undo;
 
 use components::*;
 use systems::*;
 use ai::*;
 use board::*;
+use challenge::{ChallengeOutcome, ChallengeRule, PendingChallenge};
+use crate::lexicon::{Lexicon, WordFrequency};
+use crate::stage5::ai_personality::AIPersonality;
+use crate::scoring::ScoreCalculator;
 
 /// Stage 3 Plugin
 pub struct Stage3Plugin;
@@ -32,6 +53,17 @@ impl Plugin for Stage3Plugin {
             .init_resource::<Stage3State>()
             .init_resource::<Board>()
             .init_resource::<TileBag>()
+            .init_resource::<AIState>()
+            .init_resource::<PlayerMoveBuilder>()
+            .init_resource::<endgame::EndgameAnalysis>()
+            .init_resource::<hints::HintState>()
+            .init_resource::<undo::UndoHistory>()
+            .init_resource::<gcg::SavedGameRecord>()
+            .init_resource::<review::GameReview>()
+            .init_resource::<ResumeGame>()
+            .init_resource::<pause::PausedGame>()
+            .init_resource::<personality::PersonalityStats>()
+            .init_resource::<ScoreCalculator>()
 
             // Events
             .add_event::<audio::AudioEvent>()
@@ -39,10 +71,14 @@ impl Plugin for Stage3Plugin {
 
             // Startup systems
             .add_systems(OnEnter(GameState::Stage3Playing), (
+                load_lexicon,
+                load_leave_table,
+                load_word_frequency,
                 ui::spawn_stage3_hud,
                 spawn_board,
-                initialize_game,
-                deal_initial_tiles,
+                apply_settings,
+                // Racks are dealt from the new game's seed
+                (initialize_game, deal_initial_tiles).chain(),
             ))
 
             // Core gameplay systems
@@ -50,6 +86,15 @@ impl Plugin for Stage3Plugin {
                 handle_player_input,
                 validate_player_move,
                 execute_move,
+                execute_exchange_or_pass,
+                handle_challenge_input,
+                // Before the blank prompt can take the same key as a letter
+                endgame::toggle_endgame_analysis.before(handle_player_input),
+                hints::handle_hint_input.before(handle_player_input),
+                undo::record_turn_start,
+                undo::handle_undo_input.before(handle_player_input),
+                ai::respond_to_player_play,
+                ai::prepare_ai_vocabulary,
                 ai::calculate_ai_move,
                 ai::execute_ai_move,
                 update_turn,
@@ -59,10 +104,12 @@ impl Plugin for Stage3Plugin {
 
             // Visual systems
             .add_systems(Update, (
+                visuals::sync_board_tiles,
                 visuals::update_tile_visuals,
                 visuals::update_board_highlights,
                 visuals::update_score_popups,
                 visuals::update_move_preview,
+                visuals::sync_hint_ghosts,
             ).run_if(in_state(GameState::Stage3Playing)))
 
             // UI systems
@@ -70,6 +117,10 @@ impl Plugin for Stage3Plugin {
                 ui::update_stage3_hud,
                 ui::update_rack_display,
                 ui::update_turn_indicator,
+                ui::update_blank_prompt,
+                ui::update_challenge_prompt,
+                ui::update_endgame_panel,
+                ui::update_hint_panel,
             ).run_if(in_state(GameState::Stage3Playing)))
 
             // Pause systems
@@ -84,8 +135,27 @@ impl Plugin for Stage3Plugin {
                 audio::update_background_music,
             ).run_if(in_state(GameState::Stage3Playing)))
 
-            // Cleanup
-            .add_systems(OnExit(GameState::Stage3Playing), cleanup_stage3);
+            // Results screen
+            .add_systems(OnEnter(GameState::Results), (gcg::save_game_record, rate_game, ui::spawn_results_screen).chain())
+            .add_systems(Update, ui::handle_results_buttons.run_if(in_state(GameState::Results)))
+
+            // Game review
+            .add_systems(OnEnter(GameState::Stage3Review), (
+                load_lexicon,
+                load_leave_table,
+                review::open_review,
+                review::spawn_review_screen,
+            ))
+            .add_systems(Update, (
+                review::handle_review_input,
+                review::analyze_review_position,
+                review::update_review_board,
+                review::update_review_panel,
+            ).chain().run_if(in_state(GameState::Stage3Review)))
+            .add_systems(OnExit(GameState::Stage3Review), review::cleanup_review)
+
+            // Cleanup (pausing leaves Stage3Playing too, so a running AI search is dropped either way)
+            .add_systems(OnExit(GameState::Stage3Playing), (cleanup_stage3, ai::cancel_ai_search));
     }
 }
 
@@ -94,8 +164,14 @@ impl Plugin for Stage3Plugin {
 pub struct Stage3Config {
     pub difficulty: u8,
     pub time_limit_seconds: u32, // 0 = unlimited
-    pub ai_think_time_ms: u32,   // Simulated AI delay
     pub allow_hints: bool,
+    /// Whether turns can be taken back (practice games only)
+    pub allow_undo: bool,
+    pub challenge_rule: ChallengeRule,
+    /// Playing style for the AI (None = plain equity)
+    pub personality: Option<AIPersonality>,
+    /// Game of a Stage 5 match: the results screen leads back to the bracket
+    pub tournament_game: bool,
 }
 
 impl Default for Stage3Config {
@@ -103,14 +179,17 @@ impl Default for Stage3Config {
         Self {
             difficulty: 3,
             time_limit_seconds: 1800, // 30 minutes default
-            ai_think_time_ms: 2000,
             allow_hints: true,
+            allow_undo: true,
+            challenge_rule: ChallengeRule::Void,
+            personality: None,
+            tournament_game: false,
         }
     }
 }
 
 /// Game state for Stage 3
-#[derive(Resource)]
+#[derive(Resource, Clone, Serialize, Deserialize)]
 pub struct Stage3State {
     pub player_score: u32,
     pub ai_score: u32,
@@ -121,6 +200,16 @@ pub struct Stage3State {
     pub moves_history: Vec<MoveRecord>,
     pub is_active: bool,
     pub game_over_reason: Option<GameOverReason>,
+    /// Consecutive turns (both sides) that scored nothing
+    pub scoreless_turns: u32,
+    /// Leftover-tile adjustments applied at the end (player, AI)
+    pub end_adjustments: Option<(i32, i32)>,
+    /// Play waiting for the opponent to accept or challenge it
+    pub pending_challenge: Option<PendingChallenge>,
+    /// Most recent challenge: who challenged and how it went
+    pub last_challenge: Option<(Turn, ChallengeOutcome)>,
+    /// Positions the player asked for hints in
+    pub hints_used: u32,
 }
 
 impl Default for Stage3State {
@@ -135,12 +224,112 @@ impl Default for Stage3State {
             moves_history: Vec::new(),
             is_active: true,
             game_over_reason: None,
+            scoreless_turns: 0,
+            end_adjustments: None,
+            pending_challenge: None,
+            last_challenge: None,
+            hints_used: 0,
+        }
+    }
+}
+
+impl Stage3State {
+    /// Appends a move to the history and tracks scoreless turns
+    ///
+    /// Returns the new record so callers can fill in the rack and tiles.
+    pub fn record_move(
+        &mut self,
+        player: Turn,
+        kind: MoveKind,
+        word: String,
+        score: u32,
+        position: (usize, usize),
+        direction: Direction,
+    ) -> &mut MoveRecord {
+        self.last_challenge = None;
+        if score == 0 {
+            self.scoreless_turns += 1;
+        } else {
+            self.scoreless_turns = 0;
+        }
+
+        let turn_number = self.moves_history.len() as u32 + 1;
+        self.moves_history.push(MoveRecord {
+            turn_number,
+            player,
+            kind,
+            word,
+            score,
+            position,
+            direction,
+            rack: Vec::new(),
+            placements: Vec::new(),
+        });
+        self.moves_history.last_mut().expect("move was just recorded")
+    }
+}
+
+impl Stage3State {
+    /// Ends the game, applying the leftover-tile adjustments to both scores
+    pub fn finish_game(&mut self, reason: GameOverReason, scorer: &ScoreCalculator) {
+        let went_out = match reason {
+            GameOverReason::RackOut(player) => Some(player),
+            _ => None,
+        };
+        let (player_adjust, ai_adjust) =
+            scoring::end_of_game_adjustments(&self.player_rack, &self.ai_rack, went_out, scorer);
+
+        self.player_score = self.player_score.saturating_add_signed(player_adjust);
+        self.ai_score = self.ai_score.saturating_add_signed(ai_adjust);
+        self.end_adjustments = Some((player_adjust, ai_adjust));
+        self.is_active = false;
+        self.game_over_reason = Some(reason);
+    }
+
+    /// Who won, or None for a tie
+    pub fn winner(&self) -> Option<Turn> {
+        match self.player_score.cmp(&self.ai_score) {
+            std::cmp::Ordering::Greater => Some(Turn::Player),
+            std::cmp::Ordering::Less => Some(Turn::AI),
+            std::cmp::Ordering::Equal => None,
         }
     }
 }
 
+/// A game in progress, saved so it can be picked up later
+#[derive(Clone, Serialize, Deserialize)]
+pub struct BoardGameSnapshot {
+    pub state: Stage3State,
+    pub board: Board,
+    /// Tiles left in the bag, in drawing order
+    pub bag: TileBag,
+    /// Seed the game started from
+    pub seed: u64,
+}
+
+impl BoardGameSnapshot {
+    pub fn capture(state: &Stage3State, board: &Board, bag: &TileBag, rng: &GameRng) -> Self {
+        Self {
+            state: state.clone(),
+            board: board.clone(),
+            bag: bag.clone(),
+            seed: rng.seed(),
+        }
+    }
+}
+
+/// Game to pick up the next time Stage 3 starts, instead of dealing a new one
+#[derive(Resource, Default)]
+pub struct ResumeGame(pub Option<BoardGameSnapshot>);
+
+/// Fewest tiles that must be left in the bag to exchange
+pub const MIN_TILES_FOR_EXCHANGE: usize = 7;
+
+/// Consecutive scoreless turns (passes, exchanges, zero-point plays) that end the game
+pub const MAX_SCORELESS_TURNS: u32 = 6;
+
 /// Whose turn it is
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub enum Turn {
     Player,
     AI,
@@ -157,42 +346,150 @@ pub struct MoveEvent {
 }
 
 /// Direction for word placement
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub enum Direction {
     Horizontal,
     Vertical,
 }
 
+/// What a player did on their turn
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum MoveKind {
+    /// Tiles placed on the board
+    Play,
+    /// Tiles swapped with the bag (the tiles given back)
+    Exchange(Vec<char>),
+    Pass,
+    /// A play taken back after a successful challenge
+    PhonyWithdrawn,
+    /// Turn lost to an unsuccessful challenge (double challenge)
+    LostChallenge,
+    /// Points awarded for an unsuccessful challenge against this player (five-point rule)
+    ChallengeBonus,
+}
+
 /// Record of a move
-#[derive(Clone)]
+#[derive(Clone, Serialize, Deserialize)]
 pub struct MoveRecord {
     pub turn_number: u32,
     pub player: Turn,
+    pub kind: MoveKind,
     pub word: String,
     pub score: u32,
     pub position: (usize, usize),
     pub direction: Direction,
+    /// Mover's rack before the move
+    pub rack: Vec<char>,
+    /// Tiles put on the board (plays only)
+    pub placements: Vec<TilePlacement>,
 }
 
 /// Reason for game over
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub enum GameOverReason {
     TimeExpired,
     NoMovesAvailable,
     TileBagEmpty,
     PlayerQuit,
+    /// Six scoreless turns in a row
+    ScorelessTurns,
+    /// A player used their last tile after the bag ran out
+    RackOut(Turn),
+}
+
+/// Load the word list used for validation and AI move generation
+pub(crate) fn load_lexicon(
+    mut commands: Commands,
+    lexicon: Option<Res<Lexicon>>,
+) {
+    if lexicon.is_some() {
+        return;
+    }
+
+    match Lexicon::load_default() {
+        Ok(lexicon) => {
+            info!("Loaded {} lexicon ({} words) for Stage 3",
+                  lexicon.lexicon_name, lexicon.word_count());
+            commands.insert_resource(lexicon);
+        }
+        Err(e) => {
+            error!("Failed to load lexicon: {}", e);
+            error!("Stage 3 will not function correctly without a word list!");
+        }
+    }
+}
+
+/// Load the rack-leave table used to rank AI moves by equity
+pub(crate) fn load_leave_table(
+    mut commands: Commands,
+    leaves: Option<Res<leave::LeaveTable>>,
+) {
+    if leaves.is_none() {
+        commands.insert_resource(leave::LeaveTable::load_default());
+    }
+}
+
+/// Load the commonness list used to size AI vocabularies
+fn load_word_frequency(
+    mut commands: Commands,
+    frequency: Option<Res<WordFrequency>>,
+) {
+    if frequency.is_none() {
+        commands.insert_resource(WordFrequency::load_default());
+    }
+}
+
+/// Take the challenge rule for a practice game from Settings
+///
+/// Tournament games keep the rule their match was set up with.
+fn apply_settings(
+    settings: Res<GameSettings>,
+    mut config: ResMut<Stage3Config>,
+) {
+    if !config.tournament_game {
+        config.challenge_rule = settings.gameplay.challenge_rule;
+    }
 }
 
 /// Initialize game
+#[allow(clippy::too_many_arguments)]
 fn initialize_game(
     mut state: ResMut<Stage3State>,
     mut board: ResMut<Board>,
     mut tile_bag: ResMut<TileBag>,
+    mut analysis: ResMut<endgame::EndgameAnalysis>,
+    mut hints: ResMut<hints::HintState>,
+    mut history: ResMut<undo::UndoHistory>,
+    mut rng: ResMut<GameRng>,
+    mut resume: ResMut<ResumeGame>,
+    mut paused: ResMut<pause::PausedGame>,
 ) {
+    // Coming back from the pause menu carries on with the same game
+    if paused.0 {
+        paused.0 = false;
+        return;
+    }
+
     // Reset game state
     *state = Stage3State::default();
+    *analysis = endgame::EndgameAnalysis::default();
+    *hints = hints::HintState::default();
+    *history = undo::UndoHistory::default();
     board.clear();
-    tile_bag.reset();
+
+    // A resumed game keeps its seed, board and bag
+    if let Some(snapshot) = resume.0.take() {
+        rng.replay(snapshot.seed);
+        rng.start_game();
+        *state = snapshot.state;
+        *board = snapshot.board;
+        *tile_bag = snapshot.bag;
+        info!("Resumed Stage 3 game at turn {}", state.moves_history.len() + 1);
+        return;
+    }
+
+    rng.start_game();
+    tile_bag.reset(&mut *rng);
 }
 
 /// Deal initial tiles to both players
@@ -200,6 +497,11 @@ fn deal_initial_tiles(
     mut state: ResMut<Stage3State>,
     mut tile_bag: ResMut<TileBag>,
 ) {
+    // A resumed game's racks are already dealt
+    if !state.player_rack.is_empty() {
+        return;
+    }
+
     // Deal 7 tiles to player
     state.player_rack = tile_bag.draw_tiles(7);
 
@@ -207,13 +509,44 @@ fn deal_initial_tiles(
     state.ai_rack = tile_bag.draw_tiles(7);
 }
 
+/// Rate a finished practice game against the AI's level
+///
+/// Tournament games are left to Stage 5, which rates the match as a whole.
+pub fn rate_game(
+    state: Res<Stage3State>,
+    config: Res<Stage3Config>,
+    last_stage: Res<LastStageCompleted>,
+    mut rating: ResMut<PlayerRating>,
+) {
+    if *last_stage != LastStageCompleted::Stage3 {
+        return;
+    }
+    rating.last_change = None;
+    if config.tournament_game || state.moves_history.is_empty() {
+        return;
+    }
+
+    let score = match state.winner() {
+        Some(Turn::Player) => 1.0,
+        Some(Turn::AI) => 0.0,
+        None => 0.5,
+    };
+    let opponent = format!("AI level {}", config.difficulty);
+    rating.record(&opponent, level_rating(config.difficulty), score, false);
+    if let Err(e) = rating.save() {
+        warn!("⚠️ Rating not saved: {}", e);
+    }
+}
+
 /// Cleanup Stage 3 entities
 fn cleanup_stage3(
     mut commands: Commands,
     entities: Query<Entity, Or<(
         With<BoardTile>,
         With<RackTile>,
+        With<HintGhost>,
         With<ui::Stage3HUD>,
+        With<ui::RackDisplay>,
     )>>,
 ) {
     for entity in entities.iter() {
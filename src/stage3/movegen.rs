@@ -0,0 +1,425 @@
+/// Anchor-based move generator for Stage 3
+///
+/// Classic Appel–Jacobson search: every empty square touching a tile is an
+/// anchor, cross-checks restrict which letters may sit on each empty square,
+/// and words are grown leftwards from the rack, then extended right through
+/// any tiles already on the board. Prefixes are followed node by node in the
+/// lexicon's DAWG, so dead ends are pruned as soon as they appear. A blank on
+/// the rack is tried as every letter the DAWG allows at that point.
+
+use std::cmp::Reverse;
+use std::collections::HashSet;
+use super::Direction;
+use super::ai::AIMove;
+use super::board::{Board, TilePlacement, BLANK};
+use super::scoring::score_move;
+use crate::lexicon::{Dawg, Lexicon, NodeId};
+use crate::scoring::ScoreCalculator;
+
+const BOARD_SIZE: usize = 15;
+const CENTER: usize = 7;
+
+/// Bit set with one bit per letter A-Z
+const ALL_LETTERS: u32 = (1 << 26) - 1;
+
+/// Generates every legal move for `rack` on `board`
+///
+/// # Returns
+/// * `Vec<AIMove>` - All moves, fully scored and sorted by score (highest first)
+pub fn generate_moves(
+    board: &Board,
+    rack: &[char],
+    lexicon: &Lexicon,
+    scorer: &ScoreCalculator,
+) -> Vec<AIMove> {
+    let mut moves = Vec::new();
+    let mut seen = HashSet::new();
+
+    for direction in [Direction::Horizontal, Direction::Vertical] {
+        for line in 0..BOARD_SIZE {
+            let mut generator = LineGenerator::new(board, direction, line, lexicon, scorer);
+            generator.generate(rack);
+
+            // Single-tile moves are found once per direction; keep the first
+            for ai_move in generator.moves {
+                let mut key: Vec<(usize, usize, char, bool)> = ai_move
+                    .placements
+                    .iter()
+                    .map(|p| (p.row, p.col, p.letter, p.is_blank))
+                    .collect();
+                key.sort();
+
+                if seen.insert(key) {
+                    moves.push(ai_move);
+                }
+            }
+        }
+    }
+
+    moves.sort_by_key(|m| Reverse(m.score));
+    moves
+}
+
+/// Searches a single row (horizontal) or column (vertical) for moves
+struct LineGenerator<'a> {
+    board: &'a Board,
+    lexicon: &'a Lexicon,
+    dawg: &'a Dawg,
+    scorer: &'a ScoreCalculator,
+    direction: Direction,
+    line: usize,
+    cells: [Option<char>; BOARD_SIZE],
+    cross_checks: [u32; BOARD_SIZE],
+    anchors: [bool; BOARD_SIZE],
+    moves: Vec<AIMove>,
+}
+
+impl<'a> LineGenerator<'a> {
+    fn new(
+        board: &'a Board,
+        direction: Direction,
+        line: usize,
+        lexicon: &'a Lexicon,
+        scorer: &'a ScoreCalculator,
+    ) -> Self {
+        let mut generator = Self {
+            board,
+            lexicon,
+            dawg: lexicon.dawg(),
+            scorer,
+            direction,
+            line,
+            cells: [None; BOARD_SIZE],
+            cross_checks: [ALL_LETTERS; BOARD_SIZE],
+            anchors: [false; BOARD_SIZE],
+            moves: Vec::new(),
+        };
+
+        let board_empty = board.is_board_empty();
+
+        for index in 0..BOARD_SIZE {
+            let (row, col) = generator.position(index);
+            generator.cells[index] = board.get(row, col);
+
+            if generator.cells[index].is_some() {
+                continue;
+            }
+
+            generator.anchors[index] = if board_empty {
+                row == CENTER && col == CENTER
+            } else {
+                board.has_neighbor(row, col)
+            };
+            generator.cross_checks[index] = generator.cross_check(row, col);
+        }
+
+        generator
+    }
+
+    /// Board coordinates of square `index` along this line
+    fn position(&self, index: usize) -> (usize, usize) {
+        match self.direction {
+            Direction::Horizontal => (self.line, index),
+            Direction::Vertical => (index, self.line),
+        }
+    }
+
+    /// Letters that may be placed at (row, col) without forming an invalid cross-word
+    fn cross_check(&self, row: usize, col: usize) -> u32 {
+        let (before, after) = perpendicular_fragments(self.board, row, col, self.direction);
+
+        // With tiles on one side only, the letters allowed are that fragment's hooks
+        match (before.is_empty(), after.is_empty()) {
+            (true, true) => return ALL_LETTERS,
+            (true, false) => return letter_mask(self.lexicon.front_hooks(&after)),
+            (false, true) => return letter_mask(self.lexicon.back_hooks(&before)),
+            (false, false) => {}
+        }
+
+        let Some(node) = self.dawg.walk(Dawg::ROOT, &before) else {
+            return 0;
+        };
+
+        let mut allowed = 0;
+        for (letter, child) in self.dawg.edges(node) {
+            let completes_word = self
+                .dawg
+                .walk(child, &after)
+                .is_some_and(|end| self.dawg.is_terminal(end));
+            if completes_word {
+                allowed |= letter_bit(letter);
+            }
+        }
+        allowed
+    }
+
+    fn generate(&mut self, rack: &[char]) {
+        let mut rack = rack.to_vec();
+
+        for anchor in 0..BOARD_SIZE {
+            if !self.anchors[anchor] {
+                continue;
+            }
+
+            if anchor > 0 && self.cells[anchor - 1].is_some() {
+                // Left part is fixed by the tiles already on the board
+                let mut start = anchor;
+                while start > 0 && self.cells[start - 1].is_some() {
+                    start -= 1;
+                }
+                let mut partial: String = self.cells[start..anchor].iter().flatten().collect();
+                if let Some(node) = self.dawg.walk(Dawg::ROOT, &partial) {
+                    let mut placed = Vec::new();
+                    self.extend_right(&mut partial, node, anchor, anchor, &mut placed, &mut rack);
+                }
+            } else {
+                // Left part may use empty, non-anchor squares before the anchor
+                let mut limit = 0;
+                while limit < anchor
+                    && self.cells[anchor - limit - 1].is_none()
+                    && !self.anchors[anchor - limit - 1]
+                {
+                    limit += 1;
+                }
+                self.left_part(&mut String::new(), &mut Vec::new(), Dawg::ROOT, limit, anchor, &mut rack);
+            }
+        }
+    }
+
+    fn left_part(
+        &mut self,
+        partial: &mut String,
+        blanks: &mut Vec<bool>,
+        node: NodeId,
+        limit: usize,
+        anchor: usize,
+        rack: &mut Vec<char>,
+    ) {
+        let start = anchor - partial.len();
+        let mut placed: Vec<(usize, char, bool)> = partial
+            .chars()
+            .zip(blanks.iter())
+            .enumerate()
+            .map(|(offset, (letter, &is_blank))| (start + offset, letter, is_blank))
+            .collect();
+        self.extend_right(partial, node, anchor, anchor, &mut placed, rack);
+
+        if limit == 0 {
+            return;
+        }
+
+        let dawg = self.dawg;
+        for (letter, child) in dawg.edges(node) {
+            for tile in [letter, BLANK] {
+                let Some(index) = rack.iter().position(|&t| t == tile) else {
+                    continue;
+                };
+
+                rack.remove(index);
+                partial.push(letter);
+                blanks.push(tile == BLANK);
+                self.left_part(partial, blanks, child, limit - 1, anchor, rack);
+                blanks.pop();
+                partial.pop();
+                rack.insert(index, tile);
+            }
+        }
+    }
+
+    fn extend_right(
+        &mut self,
+        partial: &mut String,
+        node: NodeId,
+        square: usize,
+        anchor: usize,
+        placed: &mut Vec<(usize, char, bool)>,
+        rack: &mut Vec<char>,
+    ) {
+        if square < BOARD_SIZE {
+            if let Some(letter) = self.cells[square] {
+                if let Some(child) = self.dawg.child(node, letter) {
+                    partial.push(letter);
+                    self.extend_right(partial, child, square + 1, anchor, placed, rack);
+                    partial.pop();
+                }
+                return;
+            }
+        }
+
+        // Square is empty or off the board, so the word may end here
+        if square > anchor && !placed.is_empty() && partial.len() >= 2 && self.dawg.is_terminal(node) {
+            self.record_move(partial, square - partial.len(), placed);
+        }
+
+        if square >= BOARD_SIZE {
+            return;
+        }
+
+        let dawg = self.dawg;
+        for (letter, child) in dawg.edges(node) {
+            if self.cross_checks[square] & letter_bit(letter) == 0 {
+                continue;
+            }
+            for tile in [letter, BLANK] {
+                let Some(index) = rack.iter().position(|&t| t == tile) else {
+                    continue;
+                };
+
+                rack.remove(index);
+                partial.push(letter);
+                placed.push((square, letter, tile == BLANK));
+                self.extend_right(partial, child, square + 1, anchor, placed, rack);
+                placed.pop();
+                partial.pop();
+                rack.insert(index, tile);
+            }
+        }
+    }
+
+    fn record_move(&mut self, word: &str, start: usize, placed: &[(usize, char, bool)]) {
+        let placements: Vec<TilePlacement> = placed
+            .iter()
+            .map(|&(index, letter, is_blank)| {
+                let (row, col) = self.position(index);
+                TilePlacement { row, col, letter, is_blank }
+            })
+            .collect();
+
+        let score = score_move(self.board, &placements, self.direction, self.scorer);
+
+        self.moves.push(AIMove {
+            word: word.to_string(),
+            position: self.position(start),
+            direction: self.direction,
+            score,
+            equity: score as f32,
+            // Blanks come off the rack as BLANK, not as the letter they stand for
+            tiles_used: placed
+                .iter()
+                .map(|&(_, letter, is_blank)| if is_blank { BLANK } else { letter })
+                .collect(),
+            placements,
+        });
+    }
+}
+
+/// Cross-check bit for an uppercase letter
+fn letter_bit(letter: char) -> u32 {
+    if letter.is_ascii_uppercase() {
+        1 << (letter as u32 - 'A' as u32)
+    } else {
+        0
+    }
+}
+
+/// Bit set of `letters`
+fn letter_mask(letters: impl IntoIterator<Item = char>) -> u32 {
+    letters.into_iter().fold(0, |mask, letter| mask | letter_bit(letter))
+}
+
+/// Letters directly before and after (row, col) perpendicular to `direction`
+fn perpendicular_fragments(board: &Board, row: usize, col: usize, direction: Direction) -> (String, String) {
+    let mut before = Vec::new();
+    let mut after = String::new();
+
+    match direction {
+        Direction::Horizontal => {
+            let mut r = row;
+            while r > 0 {
+                let Some(letter) = board.get(r - 1, col) else { break };
+                before.push(letter);
+                r -= 1;
+            }
+            let mut r = row + 1;
+            while let Some(letter) = board.get(r, col) {
+                after.push(letter);
+                r += 1;
+            }
+        }
+        Direction::Vertical => {
+            let mut c = col;
+            while c > 0 {
+                let Some(letter) = board.get(row, c - 1) else { break };
+                before.push(letter);
+                c -= 1;
+            }
+            let mut c = col + 1;
+            while let Some(letter) = board.get(row, c) {
+                after.push(letter);
+                c += 1;
+            }
+        }
+    }
+
+    (before.into_iter().rev().collect(), after)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_lexicon(words: &[&str]) -> Lexicon {
+        Lexicon::from_words(words, "Test")
+    }
+
+    #[test]
+    fn test_first_move_covers_center() {
+        let lexicon = test_lexicon(&["CAT", "AT", "TA"]);
+        let board = Board::default();
+        let moves = generate_moves(&board, &['C', 'A', 'T', 'Q'], &lexicon, &ScoreCalculator::new());
+
+        assert!(!moves.is_empty());
+        for m in &moves {
+            assert!(m.placements.iter().any(|p| p.row == 7 && p.col == 7));
+        }
+
+        // CAT on the center star: (3 + 1 + 1) doubled
+        let cat = moves.iter().find(|m| m.word == "CAT").unwrap();
+        assert_eq!(cat.score, 10);
+    }
+
+    #[test]
+    fn test_extends_through_existing_tiles() {
+        let lexicon = test_lexicon(&["CAT", "CATS", "SCAT"]);
+        let board = Board::with_word("CAT", 7, 6);
+
+        let moves = generate_moves(&board, &['S'], &lexicon, &ScoreCalculator::new());
+        let words: Vec<&str> = moves.iter().map(|m| m.word.as_str()).collect();
+
+        assert!(words.contains(&"CATS"));
+        assert!(words.contains(&"SCAT"));
+        for m in &moves {
+            assert_eq!(m.tiles_used, vec!['S']);
+        }
+    }
+
+    #[test]
+    fn test_blank_tries_every_designation() {
+        let lexicon = test_lexicon(&["QI", "XI", "ZA"]);
+        let board = Board::default();
+        let moves = generate_moves(&board, &['I', BLANK], &lexicon, &ScoreCalculator::new());
+
+        let words: Vec<&str> = moves.iter().map(|m| m.word.as_str()).collect();
+        assert!(words.contains(&"QI"));
+        assert!(words.contains(&"XI"));
+
+        // The blank scores nothing: QI with a blank Q is just I(1) doubled
+        let qi = moves.iter().find(|m| m.word == "QI").unwrap();
+        assert_eq!(qi.score, 2);
+        assert!(qi.placements.iter().any(|p| p.letter == 'Q' && p.is_blank));
+        assert!(qi.tiles_used.contains(&BLANK));
+    }
+
+    #[test]
+    fn test_cross_checks_reject_invalid_cross_words() {
+        let lexicon = test_lexicon(&["AT", "TO", "OX"]);
+        let board = Board::with_word("AT", 7, 7);
+
+        // 'O' below 'A' would form vertical "AO", which is not a word
+        let moves = generate_moves(&board, &['O'], &lexicon, &ScoreCalculator::new());
+        assert!(moves
+            .iter()
+            .all(|m| !m.placements.iter().any(|p| p.row == 8 && p.col == 7)));
+        assert!(moves.iter().any(|m| m.word == "TO"));
+    }
+}
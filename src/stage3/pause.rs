@@ -15,15 +15,21 @@ pub enum PauseButton {
     Quit,
 }
 
+/// A game paused mid-play, to be picked up as it was on Resume rather than dealt again
+#[derive(Resource, Default)]
+pub struct PausedGame(pub bool);
+
 /// Handle ESC key to toggle pause
 pub fn handle_pause_input(
     keyboard: Res<ButtonInput<KeyCode>>,
     current_state: Res<State<GameState>>,
     mut next_state: ResMut<NextState<GameState>>,
+    mut paused: ResMut<PausedGame>,
 ) {
     if keyboard.just_pressed(KeyCode::Escape) {
         match current_state.get() {
             GameState::Stage3Playing => {
+                paused.0 = true;
                 next_state.set(GameState::Stage3Paused);
             }
             GameState::Stage3Paused => {
@@ -174,6 +180,7 @@ pub fn handle_pause_menu_buttons(
     mut interaction_query: Query<(&Interaction, &PauseButton, &mut BackgroundColor), Changed<Interaction>>,
     mut next_state: ResMut<NextState<GameState>>,
     pause_menu_query: Query<Entity, With<PauseMenu>>,
+    mut paused: ResMut<PausedGame>,
 ) {
     for (interaction, button, mut color) in interaction_query.iter_mut() {
         match *interaction {
@@ -184,8 +191,10 @@ pub fn handle_pause_menu_buttons(
 
                 match button {
                     PauseButton::Resume => next_state.set(GameState::Stage3Playing),
-                    PauseButton::Restart => next_state.set(GameState::MainMenu),
-                    PauseButton::Quit => next_state.set(GameState::MainMenu),
+                    PauseButton::Restart | PauseButton::Quit => {
+                        paused.0 = false;
+                        next_state.set(GameState::MainMenu);
+                    }
                 }
             }
             Interaction::Hovered => {
@@ -0,0 +1,251 @@
+/// Personality-driven move selection for the Stage 3 AI
+///
+/// A Stage 5 `AIPersonality` turns into a `MoveBias` that reshapes the
+/// equity of every candidate: `scoring_priority` scales the raw points,
+/// `blocking_priority` charges for triple-word lanes and hot spots the move
+/// opens to the opponent, and `prefers_high_risk` turns those same openings
+/// into a bonus. Stats kept per personality show how differently each style
+/// actually plays.
+
+use std::collections::HashMap;
+use bevy::prelude::*;
+use super::ai::AIMove;
+use super::board::{Board, TilePlacement};
+use super::components::PremiumSquare;
+use crate::stage5::ai_personality::{AIPersonality, MoveBias};
+
+const BOARD_SIZE: usize = 15;
+
+/// Distance from a tile within which an ordinary play can reach a triple-word square
+const LANE_REACH: usize = 4;
+
+/// Equity charged per triple-word lane opened, at full blocking priority
+const TRIPLE_LANE_PENALTY: f32 = 10.0;
+/// Equity charged per hot spot opened, at full blocking priority
+const HOT_SPOT_PENALTY: f32 = 4.0;
+/// Equity a risk-taker adds per opening of either kind
+const RISK_BONUS: f32 = 3.0;
+
+/// Scoring chances a move hands the opponent
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Openings {
+    /// Empty triple-word squares newly within reach of a tile
+    pub triple_word_lanes: u32,
+    /// Empty double-word and triple-letter squares touching the new tiles
+    pub hot_spots: u32,
+}
+
+impl Openings {
+    pub fn total(&self) -> u32 {
+        self.triple_word_lanes + self.hot_spots
+    }
+}
+
+/// Openings created by playing `placements` on `board`
+pub fn openings_created(board: &Board, placements: &[TilePlacement]) -> Openings {
+    let mut after = board.clone();
+    for placement in placements {
+        let _ = after.place_placement(placement);
+    }
+
+    let lanes_before = open_triple_lanes(board);
+    let lanes_after = open_triple_lanes(&after);
+
+    let mut hot_spots = Vec::new();
+    for placement in placements {
+        for (row, col) in neighbors(placement.row, placement.col) {
+            let premium = after.get_premium(row, col);
+            let is_hot = matches!(premium, PremiumSquare::DoubleWord | PremiumSquare::TripleLetter);
+            if is_hot && after.is_empty(row, col) && !hot_spots.contains(&(row, col)) {
+                hot_spots.push((row, col));
+            }
+        }
+    }
+
+    Openings {
+        triple_word_lanes: lanes_after.saturating_sub(lanes_before),
+        hot_spots: hot_spots.len() as u32,
+    }
+}
+
+/// Empty triple-word squares with a tile within reach along their row or column
+fn open_triple_lanes(board: &Board) -> u32 {
+    let mut open = 0;
+    for row in 0..BOARD_SIZE {
+        for col in 0..BOARD_SIZE {
+            if board.get_premium(row, col) != PremiumSquare::TripleWord || !board.is_empty(row, col) {
+                continue;
+            }
+            let reachable = (1..=LANE_REACH).any(|d| {
+                [
+                    (row.checked_sub(d), Some(col)),
+                    (Some(row + d), Some(col)),
+                    (Some(row), col.checked_sub(d)),
+                    (Some(row), Some(col + d)),
+                ]
+                .into_iter()
+                .any(|square| match square {
+                    (Some(r), Some(c)) if r < BOARD_SIZE && c < BOARD_SIZE => !board.is_empty(r, c),
+                    _ => false,
+                })
+            });
+            if reachable {
+                open += 1;
+            }
+        }
+    }
+    open
+}
+
+fn neighbors(row: usize, col: usize) -> impl Iterator<Item = (usize, usize)> {
+    [
+        (row.checked_sub(1), Some(col)),
+        (Some(row + 1), Some(col)),
+        (Some(row), col.checked_sub(1)),
+        (Some(row), Some(col + 1)),
+    ]
+    .into_iter()
+    .filter_map(|square| match square {
+        (Some(r), Some(c)) if r < BOARD_SIZE && c < BOARD_SIZE => Some((r, c)),
+        _ => None,
+    })
+}
+
+/// Reweights each move's equity (score plus leave) for a personality
+pub fn apply_move_bias(moves: &mut [AIMove], board: &Board, bias: &MoveBias) {
+    for ai_move in moves.iter_mut() {
+        let openings = openings_created(board, &ai_move.placements);
+        let leave = ai_move.equity - ai_move.score as f32;
+
+        // Scoring priority 0.5 leaves points as they are
+        let points = ai_move.score as f32 * (0.5 + bias.scoring_priority);
+        let exposure = openings.triple_word_lanes as f32 * TRIPLE_LANE_PENALTY
+            + openings.hot_spots as f32 * HOT_SPOT_PENALTY;
+        let risk = if bias.prefers_high_risk {
+            openings.total() as f32 * RISK_BONUS
+        } else {
+            0.0
+        };
+
+        ai_move.equity = points + leave - bias.blocking_priority * exposure + risk;
+    }
+}
+
+/// Running totals for one personality
+#[derive(Debug, Clone, Copy, Default)]
+pub struct StyleStats {
+    pub moves: u32,
+    pub points: u32,
+    pub triple_word_lanes_opened: u32,
+    pub hot_spots_opened: u32,
+}
+
+impl StyleStats {
+    pub fn average_points(&self) -> f32 {
+        if self.moves == 0 {
+            0.0
+        } else {
+            self.points as f32 / self.moves as f32
+        }
+    }
+
+    /// Openings of either kind per move
+    pub fn openings_per_move(&self) -> f32 {
+        if self.moves == 0 {
+            0.0
+        } else {
+            (self.triple_word_lanes_opened + self.hot_spots_opened) as f32 / self.moves as f32
+        }
+    }
+}
+
+/// Stats for every personality the AI has played as
+#[derive(Resource, Debug, Default)]
+pub struct PersonalityStats {
+    stats: HashMap<AIPersonality, StyleStats>,
+}
+
+impl PersonalityStats {
+    /// Counts a play made under `personality`
+    pub fn record(&mut self, personality: AIPersonality, score: u32, openings: Openings) {
+        let entry = self.stats.entry(personality).or_default();
+        entry.moves += 1;
+        entry.points += score;
+        entry.triple_word_lanes_opened += openings.triple_word_lanes;
+        entry.hot_spots_opened += openings.hot_spots;
+    }
+
+    pub fn get(&self, personality: AIPersonality) -> StyleStats {
+        self.stats.get(&personality).copied().unwrap_or_default()
+    }
+
+    /// One-line summary for a personality
+    pub fn summary(&self, personality: AIPersonality) -> String {
+        let stats = self.get(personality);
+        format!(
+            "{:?}: {:.1} pts/move, {:.2} openings/move over {} moves",
+            personality,
+            stats.average_points(),
+            stats.openings_per_move(),
+            stats.moves
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::leave::LeaveTable;
+    use super::super::movegen::generate_moves;
+    use super::super::ai::apply_leave_equity;
+    use crate::lexicon::Lexicon;
+    use crate::scoring::ScoreCalculator;
+
+    #[test]
+    fn test_openings_created() {
+        let board = Board::with_word("CAT", 7, 6);
+
+        // Running down column 7 to row 10 brings the TW at (14, 7) within reach
+        let down: Vec<TilePlacement> = (8..=10)
+            .map(|row| TilePlacement { row, col: 7, letter: 'E', is_blank: false })
+            .collect();
+        assert_eq!(openings_created(&board, &down).triple_word_lanes, 1);
+
+        // A single tile hooked on the end opens nothing new
+        let hook = [TilePlacement { row: 7, col: 9, letter: 'S', is_blank: false }];
+        assert_eq!(openings_created(&board, &hook), Openings::default());
+    }
+
+    #[test]
+    fn test_personalities_choose_differently() {
+        let lexicon = Lexicon::from_words(["CAT", "CATS", "TEEN", "TEENS", "AT", "ET"], "Test");
+        let scorer = ScoreCalculator::new();
+        let leaves = LeaveTable::parse("").unwrap();
+        let board = Board::with_word("CAT", 7, 6);
+        let rack = ['E', 'E', 'N', 'S'];
+
+        let choose = |personality: AIPersonality| {
+            let mut moves = generate_moves(&board, &rack, &lexicon, &scorer);
+            apply_leave_equity(&mut moves, &rack, &leaves);
+            apply_move_bias(&mut moves, &board, &personality.get_move_bias());
+            let best = moves.into_iter().max_by(|a, b| a.equity.total_cmp(&b.equity)).unwrap();
+            openings_created(&board, &best.placements).total()
+        };
+
+        // The defender keeps the board tighter than the risk-taker
+        assert!(choose(AIPersonality::Defensive) < choose(AIPersonality::Aggressive));
+    }
+
+    #[test]
+    fn test_stats() {
+        let mut stats = PersonalityStats::default();
+        let openings = Openings { triple_word_lanes: 1, hot_spots: 1 };
+        stats.record(AIPersonality::Aggressive, 30, openings);
+        stats.record(AIPersonality::Aggressive, 10, Openings::default());
+
+        let aggressive = stats.get(AIPersonality::Aggressive);
+        assert_eq!(aggressive.average_points(), 20.0);
+        assert_eq!(aggressive.openings_per_move(), 1.0);
+        assert_eq!(stats.get(AIPersonality::Defensive).moves, 0);
+    }
+}
@@ -0,0 +1,577 @@
+/// Review of finished Stage 3 games
+///
+/// Steps through a game record move by move, redrawing the board and both
+/// racks at each position. For the move about to be made, the engine's
+/// choice for the same rack is shown next to the move actually played,
+/// along with the equity (score plus leave) the played move gave away.
+///
+/// Games open from the results screen, or from the main menu, which reads
+/// the GCG files in the games folder (newest first). Any GCG record copied
+/// there can be reviewed the same way. The engine works on the async compute
+/// pool, so stepping through a game never waits on it.
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+use bevy::prelude::*;
+use bevy::tasks::{AsyncComputeTaskPool, Task};
+use super::ai::{poll_task, AIMove};
+use super::board::{
+    get_premium_color, get_premium_label, play_coordinate, square_to_world, Board, BLANK, SQUARE_SIZE,
+};
+use super::components::PremiumSquare;
+use super::endgame::describe_play;
+use super::gcg::{game_record_files, parse_gcg, rack_text, GcgAction, GcgGame, GcgMove};
+use super::hints::suggest_moves;
+use super::leave::LeaveTable;
+use super::Stage3Config;
+use crate::lexicon::Lexicon;
+use crate::plugins::state::GameState;
+use crate::scoring::ScoreCalculator;
+
+/// The engine's view of one move
+#[derive(Debug, Clone)]
+pub struct TurnAnalysis {
+    /// Highest-equity play for the mover's rack (None if nothing plays)
+    pub best: Option<AIMove>,
+    /// Equity of the move actually made
+    pub played_equity: f32,
+    /// Equity given away compared to the engine's play
+    pub equity_lost: f32,
+}
+
+/// The game being reviewed and the position shown
+#[derive(Resource, Default)]
+pub struct GameReview {
+    pub game: GcgGame,
+    /// Where the game came from, e.g. a file name
+    pub title: String,
+    /// Moves played on the board shown
+    pub position: usize,
+    /// Analysis per move index (None when the move can't be analyzed)
+    analysis: HashMap<usize, Option<TurnAnalysis>>,
+    /// Engine analysis of move `analyzing`, dropped when the review steps away
+    search: Option<Task<Option<TurnAnalysis>>>,
+    analyzing: usize,
+    /// Saved games that can be switched between, newest first
+    files: Vec<PathBuf>,
+    file_index: usize,
+}
+
+impl GameReview {
+    /// Starts reviewing `game` from its first move
+    pub fn open(&mut self, game: GcgGame, title: &str) {
+        *self = Self {
+            game,
+            title: title.to_string(),
+            ..default()
+        };
+    }
+
+    /// Opens the newest saved game, keeping the list to page through
+    pub fn open_saved_games(&mut self) -> Result<(), String> {
+        let files = game_record_files();
+        if files.is_empty() {
+            return Err("No saved games to review".to_string());
+        }
+        self.files = files;
+        self.open_file(0)
+    }
+
+    /// Opens saved game `index` of the list
+    fn open_file(&mut self, index: usize) -> Result<(), String> {
+        let path = self.files.get(index).cloned().ok_or("No such saved game")?;
+        let text = fs::read_to_string(&path).map_err(|e| format!("Failed to read {:?}: {}", path, e))?;
+        let game = parse_gcg(&text).map_err(|e| format!("{}: {}", path.display(), e))?;
+
+        let files = std::mem::take(&mut self.files);
+        let title = path.file_name().map(|name| name.to_string_lossy().into_owned()).unwrap_or_default();
+        self.open(game, &title);
+        self.files = files;
+        self.file_index = index;
+        Ok(())
+    }
+
+    /// Switches to the next older (or newer) saved game
+    fn switch_file(&mut self, older: bool) {
+        let index = if older {
+            self.file_index + 1
+        } else {
+            match self.file_index.checked_sub(1) {
+                Some(index) => index,
+                None => return,
+            }
+        };
+        if index >= self.files.len() {
+            return;
+        }
+        if let Err(e) = self.open_file(index) {
+            warn!("⚠️ {}", e);
+        }
+    }
+
+    /// Number of saved games and the one shown, if browsing saved games
+    pub fn file_position(&self) -> Option<(usize, usize)> {
+        (!self.files.is_empty()).then_some((self.file_index + 1, self.files.len()))
+    }
+
+    /// Moves to position `position` (clamped to the game)
+    pub fn go_to(&mut self, position: usize) {
+        self.position = position.min(self.game.moves.len());
+    }
+
+    /// Move about to be made at the position shown
+    pub fn next_move(&self) -> Option<&GcgMove> {
+        self.game.moves.get(self.position)
+    }
+
+    /// Move that led to the position shown
+    pub fn last_move(&self) -> Option<&GcgMove> {
+        self.position.checked_sub(1).and_then(|index| self.game.moves.get(index))
+    }
+
+    /// Both players' racks at the position shown
+    ///
+    /// A record only gives the mover's rack, so each player's rack is the
+    /// one on their next line; it hasn't changed since their last move.
+    pub fn racks(&self) -> [Vec<char>; 2] {
+        let mut racks = [None, None];
+        for gcg_move in &self.game.moves[self.position..] {
+            if racks[gcg_move.player].is_none() && !gcg_move.rack.is_empty() {
+                racks[gcg_move.player] = Some(gcg_move.rack.clone());
+            }
+        }
+        racks.map(Option::unwrap_or_default)
+    }
+
+    /// Analysis of the next move, once computed
+    pub fn analysis(&self) -> Option<&TurnAnalysis> {
+        self.analysis.get(&self.position).and_then(Option::as_ref)
+    }
+}
+
+/// Compares move `index` with the engine's best play from the same rack
+///
+/// Returns None for moves without a known rack or that aren't a choice of
+/// play, exchange or pass.
+pub fn analyze_move(
+    game: &GcgGame,
+    index: usize,
+    lexicon: &Lexicon,
+    scorer: &ScoreCalculator,
+    leaves: &LeaveTable,
+) -> Option<TurnAnalysis> {
+    let gcg_move = game.moves.get(index)?;
+    let rack = &gcg_move.rack;
+    if rack.is_empty() {
+        return None;
+    }
+
+    let kept = match &gcg_move.action {
+        GcgAction::Play { placements, .. } => {
+            let used: Vec<char> = placements.iter().map(|p| if p.is_blank { BLANK } else { p.letter }).collect();
+            LeaveTable::leave_after(rack, &used)
+        }
+        GcgAction::Exchange(tiles) => LeaveTable::leave_after(rack, tiles),
+        GcgAction::Pass => rack.clone(),
+        _ => return None,
+    };
+    // A play taken back after a challenge scored nothing in the end
+    let withdrawn = game.moves.get(index + 1).is_some_and(|next| next.action == GcgAction::Withdrawn);
+    let score = if withdrawn { 0.0 } else { gcg_move.score as f32 };
+    let played_equity = score + leaves.evaluate(&kept);
+
+    let board = game.board_after(index);
+    let best = suggest_moves(&board, rack, lexicon, scorer, leaves, 1).into_iter().next();
+    let equity_lost = best.as_ref().map_or(0.0, |best| (best.equity - played_equity).max(0.0));
+
+    Some(TurnAnalysis { best, played_equity, equity_lost })
+}
+
+/// One-line description of a recorded move
+pub fn describe_move(gcg_move: &GcgMove) -> String {
+    match &gcg_move.action {
+        GcgAction::Play { position, direction, word, .. } => {
+            format!("{} {} {:+}", play_coordinate(*position, *direction), word, gcg_move.score)
+        }
+        GcgAction::Exchange(exchanged) => format!("Exchange {}", rack_text(exchanged)),
+        GcgAction::Pass => "Pass".to_string(),
+        GcgAction::Withdrawn => format!("Phony withdrawn {:+}", gcg_move.score),
+        GcgAction::ChallengeBonus => format!("Challenge bonus {:+}", gcg_move.score),
+        GcgAction::EndRack(rack) => format!("Opponent's tiles ({}) {:+}", rack_text(rack), gcg_move.score),
+        GcgAction::RackPenalty(rack) => format!("Tiles left ({}) {:+}", rack_text(rack), gcg_move.score),
+        GcgAction::TimePenalty => format!("Time penalty {:+}", gcg_move.score),
+    }
+}
+
+/// Everything spawned for the review screen
+#[derive(Component)]
+pub struct ReviewScreen;
+
+/// Tile drawn on the review board
+#[derive(Component)]
+pub struct ReviewTile;
+
+/// Text describing the position shown
+#[derive(Component)]
+pub struct ReviewText;
+
+/// Open the newest saved game if nothing was handed to the review
+pub fn open_review(mut review: ResMut<GameReview>) {
+    if !review.game.moves.is_empty() {
+        return;
+    }
+    if let Err(e) = review.open_saved_games() {
+        warn!("⚠️ {}", e);
+    }
+}
+
+/// Spawn the empty board and the side panel
+pub fn spawn_review_screen(mut commands: Commands, asset_server: Res<AssetServer>) {
+    let board = Board::default();
+    let font: Handle<Font> = asset_server.load("fonts/FiraSans-Bold.ttf");
+
+    for row in 0..15 {
+        for col in 0..15 {
+            let Vec2 { x, y } = square_to_world(row, col);
+            let premium = board.get_premium(row, col);
+
+            commands.spawn((
+                Sprite {
+                    color: get_premium_color(premium),
+                    custom_size: Some(Vec2::splat(SQUARE_SIZE - 2.0)),
+                    ..default()
+                },
+                Transform::from_translation(Vec3::new(x, y, 0.0)),
+                ReviewScreen,
+            ));
+
+            if premium != PremiumSquare::Normal {
+                commands.spawn((
+                    Text2d::new(get_premium_label(premium)),
+                    TextFont {
+                        font: font.clone(),
+                        font_size: 12.0,
+                        ..default()
+                    },
+                    TextColor(Color::srgba(1.0, 1.0, 1.0, 0.6)),
+                    Transform::from_translation(Vec3::new(x, y, 1.0)),
+                    ReviewScreen,
+                ));
+            }
+        }
+    }
+
+    commands
+        .spawn((
+            NodeBundle {
+                node: Node {
+                    position_type: PositionType::Absolute,
+                    right: Val::Px(15.0),
+                    top: Val::Px(20.0),
+                    width: Val::Px(360.0),
+                    padding: UiRect::all(Val::Px(15.0)),
+                    flex_direction: FlexDirection::Column,
+                    row_gap: Val::Px(10.0),
+                    ..default()
+                },
+                background_color: Color::srgba(0.1, 0.1, 0.15, 0.9).into(),
+                ..default()
+            },
+            ReviewScreen,
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new("Game Review"),
+                TextFont {
+                    font: font.clone(),
+                    font_size: 26.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.5, 0.7, 1.0)),
+            ));
+
+            parent.spawn((
+                Text::new(""),
+                TextFont {
+                    font: asset_server.load("fonts/FiraSans-Medium.ttf"),
+                    font_size: 17.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.85, 0.85, 0.85)),
+                ReviewText,
+            ));
+        });
+}
+
+/// Step through the game with the arrow keys, switch games with Page Up/Down
+///
+/// Escape goes back where the game was played from: the bracket for a
+/// tournament game, otherwise the main menu.
+pub fn handle_review_input(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut review: ResMut<GameReview>,
+    config: Res<Stage3Config>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    let position = review.position;
+    if keyboard.just_pressed(KeyCode::ArrowRight) {
+        review.go_to(position + 1);
+    } else if keyboard.just_pressed(KeyCode::ArrowLeft) {
+        review.go_to(position.saturating_sub(1));
+    } else if keyboard.just_pressed(KeyCode::Home) {
+        review.go_to(0);
+    } else if keyboard.just_pressed(KeyCode::End) {
+        review.go_to(usize::MAX);
+    } else if keyboard.just_pressed(KeyCode::PageDown) {
+        review.switch_file(true);
+    } else if keyboard.just_pressed(KeyCode::PageUp) {
+        review.switch_file(false);
+    } else if keyboard.just_pressed(KeyCode::Escape) {
+        next_state.set(if config.tournament_game {
+            GameState::Stage5Playing
+        } else {
+            GameState::MainMenu
+        });
+    }
+}
+
+/// Work out the engine's move for the position shown, once per move
+pub fn analyze_review_position(
+    mut review: ResMut<GameReview>,
+    lexicon: Option<Res<Lexicon>>,
+    scorer: Res<ScoreCalculator>,
+    leaves: Option<Res<LeaveTable>>,
+) {
+    let position = review.position;
+
+    if review.search.is_some() {
+        let analyzed = review.analyzing;
+        if let Some(analysis) = poll_task(&mut review.bypass_change_detection().search) {
+            review.analysis.insert(analyzed, analysis);
+        } else if analyzed != position {
+            // Stepped on before it finished; the move is analyzed again if it comes back
+            review.bypass_change_detection().search = None;
+        } else {
+            return;
+        }
+    }
+
+    let (Some(lexicon), Some(leaves)) = (lexicon, leaves) else {
+        return;
+    };
+    if review.analysis.contains_key(&position) {
+        return;
+    }
+
+    let game = review.game.clone();
+    let (lexicon, scorer, leaves) = (lexicon.clone(), scorer.clone(), leaves.clone());
+    let task = AsyncComputeTaskPool::get().spawn(async move {
+        analyze_move(&game, position, &lexicon, &scorer, &leaves)
+    });
+    let review = review.bypass_change_detection();
+    review.search = Some(task);
+    review.analyzing = position;
+}
+
+/// Redraw the tiles for the position shown, the last move highlighted
+pub fn update_review_board(
+    mut commands: Commands,
+    review: Res<GameReview>,
+    asset_server: Res<AssetServer>,
+    tile_query: Query<Entity, With<ReviewTile>>,
+) {
+    if !review.is_changed() {
+        return;
+    }
+    for entity in tile_query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+
+    let font: Handle<Font> = asset_server.load("fonts/FiraSans-Bold.ttf");
+    let board = review.game.board_after(review.position);
+    let last_placed = match review.last_move().map(|m| &m.action) {
+        Some(GcgAction::Play { placements, .. }) => placements.clone(),
+        _ => Vec::new(),
+    };
+
+    for row in 0..15 {
+        for col in 0..15 {
+            let Some(tile) = board.get_tile(row, col) else {
+                continue;
+            };
+            let is_last = last_placed.iter().any(|p| p.row == row && p.col == col);
+            let color = if is_last {
+                Color::srgb(1.0, 0.9, 0.5)
+            } else {
+                Color::srgb(0.95, 0.95, 0.85)
+            };
+            let (text, text_color) = if tile.is_blank {
+                (tile.letter.to_ascii_lowercase().to_string(), Color::srgb(0.2, 0.4, 0.8))
+            } else {
+                (tile.letter.to_string(), Color::srgb(0.1, 0.1, 0.1))
+            };
+
+            commands
+                .spawn((
+                    Sprite {
+                        color,
+                        custom_size: Some(Vec2::splat(SQUARE_SIZE - 4.0)),
+                        ..default()
+                    },
+                    Transform::from_translation(square_to_world(row, col).extend(2.0)),
+                    ReviewTile,
+                    ReviewScreen,
+                ))
+                .with_children(|tile| {
+                    tile.spawn((
+                        Text2d::new(text),
+                        TextFont {
+                            font: font.clone(),
+                            font_size: 24.0,
+                            ..default()
+                        },
+                        TextColor(text_color),
+                        Transform::from_translation(Vec3::new(0.0, 0.0, 1.0)),
+                    ));
+                });
+        }
+    }
+}
+
+/// Describe the position shown: scores, racks, the next move and the engine's view of it
+pub fn update_review_panel(
+    review: Res<GameReview>,
+    config: Res<Stage3Config>,
+    mut text_query: Query<&mut Text, With<ReviewText>>,
+) {
+    if !review.is_changed() {
+        return;
+    }
+    let Ok(mut text) = text_query.get_single_mut() else {
+        return;
+    };
+
+    let game = &review.game;
+    let name = |player: usize| player_name(game, player);
+
+    let mut lines = Vec::new();
+    if game.moves.is_empty() {
+        lines.push("No game to review.".to_string());
+        lines.push("Finished games are saved to the games folder.".to_string());
+    } else {
+        lines.push(match review.file_position() {
+            Some((index, count)) => format!("{} ({} of {})", review.title, index, count),
+            None => review.title.clone(),
+        });
+        let scores = game.scores_after(review.position);
+        lines.push(format!("{} {}  -  {} {}", name(0), scores[0], name(1), scores[1]));
+        lines.push(format!("Move {} of {}", review.position, game.moves.len()));
+
+        for (player, rack) in review.racks().iter().enumerate() {
+            lines.push(format!("{}'s rack: {}", name(player), rack_text(rack)));
+        }
+
+        match review.next_move() {
+            Some(next) => {
+                lines.push(String::new());
+                lines.push(format!("Next: {} - {}", name(next.player), describe_move(next)));
+                match review.analysis() {
+                    Some(analysis) => {
+                        lines.push(format!("Engine: {}", describe_play(analysis.best.as_ref())));
+                        lines.push(format!(
+                            "Equity: {:.1} played, {:.1} lost",
+                            analysis.played_equity, analysis.equity_lost
+                        ));
+                    }
+                    None if review.analysis.contains_key(&review.position) => {}
+                    None => lines.push("Engine: thinking...".to_string()),
+                }
+                if let Some(note) = &next.note {
+                    lines.push(format!("Note: {}", note));
+                }
+            }
+            None => {
+                lines.push(String::new());
+                lines.push("End of game".to_string());
+            }
+        }
+    }
+
+    lines.push(String::new());
+    lines.push("Left/Right: step  Home/End: jump".to_string());
+    if review.file_position().is_some() {
+        lines.push("PgUp/PgDn: newer/older game".to_string());
+    }
+    lines.push(if config.tournament_game { "Esc: bracket" } else { "Esc: main menu" }.to_string());
+
+    **text = lines.join("\n");
+}
+
+/// Full name of a player, or their nickname if the record gives none
+fn player_name(game: &GcgGame, player: usize) -> &str {
+    let full_name = &game.full_names[player];
+    if full_name.is_empty() {
+        &game.nicknames[player]
+    } else {
+        full_name
+    }
+}
+
+/// Remove the review screen, forgetting the game so the next review starts afresh
+pub fn cleanup_review(
+    mut commands: Commands,
+    mut review: ResMut<GameReview>,
+    entities: Query<Entity, With<ReviewScreen>>,
+) {
+    for entity in entities.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+    *review = GameReview::default();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const GAME: &str = "#player1 A Ann\n#player2 B Bob\n\
+        >A: CATSXYZ 8G CAT +10 10\n\
+        >B: ENSOQQQ 9H E +5 5\n\
+        >A: SXYZAAA 8J S +6 16\n";
+
+    #[test]
+    fn test_racks_and_positions() {
+        let mut review = GameReview::default();
+        review.open(parse_gcg(GAME).unwrap(), "Test");
+
+        let racks = review.racks();
+        assert_eq!(racks[0], "CATSXYZ".chars().collect::<Vec<_>>());
+        assert_eq!(racks[1], "ENSOQQQ".chars().collect::<Vec<_>>());
+
+        review.go_to(2);
+        assert_eq!(review.racks()[0], "SXYZAAA".chars().collect::<Vec<_>>());
+        assert_eq!(review.last_move().map(|m| m.player), Some(1));
+
+        review.go_to(99);
+        assert_eq!(review.position, 3);
+        assert!(review.next_move().is_none());
+    }
+
+    #[test]
+    fn test_analysis_finds_the_better_play() {
+        let lexicon = Lexicon::from_words(["CAT", "CATS", "AT", "TA"], "Test");
+        let scorer = ScoreCalculator::new();
+        let leaves = LeaveTable::parse("").unwrap();
+        let game = parse_gcg("#player1 A Ann\n#player2 B Bob\n>A: CATSQQQ 8G CAT +10 10\n").unwrap();
+
+        let analysis = analyze_move(&game, 0, &lexicon, &scorer, &leaves).unwrap();
+        let best = analysis.best.unwrap();
+        assert_eq!(best.word, "CATS");
+        assert!(analysis.equity_lost > 0.0);
+        assert_eq!(analysis.played_equity, 10.0);
+
+        // Nothing to compare a move against without its rack
+        let game = parse_gcg("#player1 A Ann\n#player2 B Bob\n>A: (QQ) +20 20\n").unwrap();
+        assert!(analyze_move(&game, 0, &lexicon, &scorer, &leaves).is_none());
+    }
+}
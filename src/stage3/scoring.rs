@@ -0,0 +1,216 @@
+/// Move scoring for Stage 3 (Classic Board)
+///
+/// Shared by the player's moves and the AI so both sides are scored the same
+/// way: letter premiums and word multipliers only apply on squares covered
+/// this turn, every perpendicular cross-word is scored, blanks are worth
+/// nothing, and emptying a full rack earns the bingo bonus.
+
+use super::{Direction, Turn};
+use super::board::{Board, TilePlacement, WordSquare, BLANK};
+use super::components::PremiumSquare;
+use crate::scoring::ScoreCalculator;
+
+/// Number of tiles on a full rack
+pub const RACK_SIZE: usize = 7;
+
+/// Bonus for playing all seven rack tiles in a single move
+pub const BINGO_BONUS: u32 = 50;
+
+/// Scores a move: the main word plus every perpendicular cross-word formed
+///
+/// # Arguments
+/// * `board` - Board before the move is placed
+/// * `placements` - Tiles placed this turn
+/// * `direction` - Direction of the main word
+/// * `scorer` - Tile point values
+///
+/// # Returns
+/// * Total points for the move, including the bingo bonus
+pub fn score_move(
+    board: &Board,
+    placements: &[TilePlacement],
+    direction: Direction,
+    scorer: &ScoreCalculator,
+) -> u32 {
+    let Some(first) = placements.first() else {
+        return 0;
+    };
+
+    let perpendicular = match direction {
+        Direction::Horizontal => Direction::Vertical,
+        Direction::Vertical => Direction::Horizontal,
+    };
+
+    let mut total = score_word_at(board, placements, (first.row, first.col), direction, scorer);
+
+    for placement in placements {
+        total += score_word_at(board, placements, (placement.row, placement.col), perpendicular, scorer);
+    }
+
+    if placements.len() == RACK_SIZE {
+        total += BINGO_BONUS;
+    }
+
+    total
+}
+
+/// Total point value of the tiles left on a rack (blanks count zero)
+pub fn rack_value(rack: &[char], scorer: &ScoreCalculator) -> u32 {
+    rack.iter()
+        .filter(|&&tile| tile != BLANK)
+        .map(|&tile| scorer.get_tile_value(tile))
+        .sum()
+}
+
+/// End-of-game score adjustments as (player, AI)
+///
+/// Each side loses the value of its unplayed tiles. If one side went out
+/// (emptied its rack after the bag ran dry) it is awarded the opponent's
+/// leftover total instead.
+pub fn end_of_game_adjustments(
+    player_rack: &[char],
+    ai_rack: &[char],
+    went_out: Option<Turn>,
+    scorer: &ScoreCalculator,
+) -> (i32, i32) {
+    let player_left = rack_value(player_rack, scorer) as i32;
+    let ai_left = rack_value(ai_rack, scorer) as i32;
+
+    match went_out {
+        Some(Turn::Player) => (ai_left, -ai_left),
+        Some(Turn::AI) => (-player_left, player_left),
+        None => (-player_left, -ai_left),
+    }
+}
+
+/// Point value of a tile (blanks score zero whatever letter they stand for)
+fn tile_points(square: &WordSquare, scorer: &ScoreCalculator) -> u32 {
+    if square.is_blank {
+        0
+    } else {
+        scorer.get_tile_value(square.letter)
+    }
+}
+
+/// Scores the word running through `origin` in `direction` (0 if shorter than 2 letters)
+fn score_word_at(
+    board: &Board,
+    placements: &[TilePlacement],
+    origin: (usize, usize),
+    direction: Direction,
+    scorer: &ScoreCalculator,
+) -> u32 {
+    let squares = board.word_through(placements, origin, direction);
+    if squares.len() < 2 {
+        return 0;
+    }
+
+    let mut letter_total = 0;
+    let mut word_multiplier = 1;
+
+    for square in &squares {
+        // Premiums are used up once covered, so only new tiles get them
+        let mut value = tile_points(square, scorer);
+        if square.is_new {
+            match board.get_premium(square.row, square.col) {
+                PremiumSquare::DoubleLetter => value *= 2,
+                PremiumSquare::TripleLetter => value *= 3,
+                PremiumSquare::DoubleWord | PremiumSquare::Center => word_multiplier *= 2,
+                PremiumSquare::TripleWord => word_multiplier *= 3,
+                PremiumSquare::Normal => {}
+            }
+        }
+        letter_total += value;
+    }
+
+    letter_total * word_multiplier
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn placements(tiles: &[(usize, usize, char)]) -> Vec<TilePlacement> {
+        tiles
+            .iter()
+            .map(|&(row, col, letter)| TilePlacement { row, col, letter, is_blank: false })
+            .collect()
+    }
+
+    #[test]
+    fn test_score_includes_cross_words() {
+        let board = Board::with_word("AT", 7, 7);
+
+        // Playing "TA" in row 8 under "AT" also forms "AT" and "TA" vertically
+        let tiles = placements(&[(8, 7, 'T'), (8, 8, 'A')]);
+        let score = score_move(&board, &tiles, Direction::Horizontal, &ScoreCalculator::new());
+        // (8,8) is a double letter: TA = 1 + 2, AT = 1 + 1, TA = 1 + 2
+        assert_eq!(score, 8);
+    }
+
+    #[test]
+    fn test_premiums_only_apply_to_new_tiles() {
+        // Q sits on the center star, which was used up by the first move
+        let board = Board::with_word("QI", 7, 7);
+
+        let tiles = placements(&[(7, 9, 'S')]);
+        let score = score_move(&board, &tiles, Direction::Horizontal, &ScoreCalculator::new());
+        assert_eq!(score, 12); // Q(10) + I(1) + S(1), no doubling
+    }
+
+    #[test]
+    fn test_word_and_letter_multipliers() {
+        let board = Board::default();
+
+        // ZAX across the top row from the triple word at (0,0):
+        // Z on TW, A normal, X normal = (10 + 1 + 8) * 3
+        let tiles = placements(&[(0, 0, 'Z'), (0, 1, 'A'), (0, 2, 'X')]);
+        let score = score_move(&board, &tiles, Direction::Horizontal, &ScoreCalculator::new());
+        assert_eq!(score, 57);
+
+        // Z on the double letter at (0,3)
+        let tiles = placements(&[(0, 3, 'Z'), (0, 4, 'A')]);
+        let score = score_move(&board, &tiles, Direction::Horizontal, &ScoreCalculator::new());
+        assert_eq!(score, 21);
+    }
+
+    #[test]
+    fn test_blanks_score_zero() {
+        let board = Board::default();
+        let mut tiles = placements(&[(7, 7, 'A'), (7, 8, 'X')]);
+        tiles[0].is_blank = true;
+        let score = score_move(&board, &tiles, Direction::Horizontal, &ScoreCalculator::new());
+        assert_eq!(score, 16); // (0 + 8) doubled by the center star
+
+        // A blank already on the board still scores zero in later words
+        let mut board = Board::default();
+        board.place_blank(7, 7, 'A').unwrap();
+        board.place(7, 8, 'X').unwrap();
+        let tiles = placements(&[(8, 7, 'X')]);
+        let score = score_move(&board, &tiles, Direction::Vertical, &ScoreCalculator::new());
+        assert_eq!(score, 8); // blank A(0) + X(8)
+    }
+
+    #[test]
+    fn test_end_of_game_adjustments() {
+        let scorer = ScoreCalculator::new();
+        let ai_rack = ['Q', 'A', BLANK]; // 10 + 1 + 0
+
+        // Going out earns the opponent's leftovers, which they lose
+        assert_eq!(end_of_game_adjustments(&[], &ai_rack, Some(Turn::Player), &scorer), (11, -11));
+
+        // Nobody went out: both lose their own leftovers
+        assert_eq!(end_of_game_adjustments(&['Z'], &ai_rack, None, &scorer), (-10, -11));
+    }
+
+    #[test]
+    fn test_bingo_bonus() {
+        let board = Board::default();
+        let tiles = placements(&[
+            (7, 4, 'R'), (7, 5, 'E'), (7, 6, 'T'), (7, 7, 'A'),
+            (7, 8, 'I'), (7, 9, 'N'), (7, 10, 'S'),
+        ]);
+        let score = score_move(&board, &tiles, Direction::Horizontal, &ScoreCalculator::new());
+        assert_eq!(score, 7 * 2 + BINGO_BONUS);
+    }
+}
@@ -0,0 +1,217 @@
+/// Monte Carlo move simulation for the strongest Stage 3 AIs
+///
+/// The top candidates from the static ranking are each played out a few
+/// plies ahead. Every iteration samples a plausible opponent rack from the
+/// unseen tiles (the bag plus the opponent's rack, which the AI only knows
+/// as one pool), then both sides answer with their best static-equity move.
+/// A playout is worth the spread it produced plus the difference between
+/// the final leaves; the candidate with the best average wins.
+
+use bevy::utils::{Duration, Instant};
+use rand::seq::SliceRandom;
+use rand::Rng;
+use super::ai::{apply_leave_equity, AIMove};
+use super::board::Board;
+use super::leave::LeaveTable;
+use super::movegen::generate_moves;
+use super::scoring::RACK_SIZE;
+use crate::lexicon::Lexicon;
+use crate::scoring::ScoreCalculator;
+
+/// How much work a simulation may do
+#[derive(Debug, Clone, Copy)]
+pub struct SimulationSettings {
+    /// Maximum number of sampled opponent racks
+    pub iterations: u32,
+    /// Replies played after each candidate (opponent first)
+    pub plies: u8,
+    /// Wall-clock limit, or `None` to let the iteration count alone decide
+    /// (as a replayed seed does); at least one iteration always runs
+    pub time_budget: Option<Duration>,
+}
+
+/// Outcome of a simulation
+#[derive(Debug, Clone)]
+pub struct SimulationResult {
+    /// Index of the winning candidate
+    pub best_index: usize,
+    /// Average value of each candidate, in candidate order
+    pub average_spread: Vec<f32>,
+    /// Iterations completed before a limit was hit
+    pub iterations: u32,
+}
+
+/// Simulates every candidate and picks the one with the best average spread
+///
+/// Returns `None` when there are no candidates.
+#[allow(clippy::too_many_arguments)]
+pub fn simulate<R: Rng>(
+    board: &Board,
+    rack: &[char],
+    unseen: &[char],
+    candidates: &[AIMove],
+    lexicon: &Lexicon,
+    scorer: &ScoreCalculator,
+    leaves: &LeaveTable,
+    settings: SimulationSettings,
+    rng: &mut R,
+) -> Option<SimulationResult> {
+    if candidates.is_empty() {
+        return None;
+    }
+
+    let start = Instant::now();
+    let mut totals = vec![0.0f32; candidates.len()];
+    let mut iterations = 0;
+    let mut pool = unseen.to_vec();
+
+    // Every candidate sees the same sampled racks, so each iteration runs
+    // all of them before the budget is checked again
+    while iterations < settings.iterations.max(1) {
+        pool.shuffle(rng);
+        for (total, candidate) in totals.iter_mut().zip(candidates) {
+            *total += play_out(board, rack, &pool, candidate, lexicon, scorer, leaves, settings.plies);
+        }
+        iterations += 1;
+
+        if settings.time_budget.is_some_and(|budget| start.elapsed() >= budget) {
+            break;
+        }
+    }
+
+    let average_spread: Vec<f32> = totals.iter().map(|total| total / iterations as f32).collect();
+    let best_index = average_spread
+        .iter()
+        .enumerate()
+        .max_by(|a, b| a.1.total_cmp(b.1))
+        .map(|(index, _)| index)?;
+
+    Some(SimulationResult {
+        best_index,
+        average_spread,
+        iterations,
+    })
+}
+
+/// Plays `candidate`, then `plies` replies, and values the position reached
+///
+/// `pool` is the shuffled unseen tiles: the opponent's rack comes off the
+/// front and the rest is the bag.
+#[allow(clippy::too_many_arguments)]
+fn play_out(
+    board: &Board,
+    rack: &[char],
+    pool: &[char],
+    candidate: &AIMove,
+    lexicon: &Lexicon,
+    scorer: &ScoreCalculator,
+    leaves: &LeaveTable,
+    plies: u8,
+) -> f32 {
+    let mut board = board.clone();
+    let opponent_count = RACK_SIZE.min(pool.len());
+    let mut bag = pool[opponent_count..].iter().copied();
+
+    // Index 0 is the simulating side, 1 the opponent
+    let mut racks = [
+        LeaveTable::leave_after(rack, &candidate.tiles_used),
+        pool[..opponent_count].to_vec(),
+    ];
+    for placement in &candidate.placements {
+        let _ = board.place_placement(placement);
+    }
+    refill(&mut racks[0], &mut bag);
+
+    let mut spread = candidate.score as f32;
+    let mut side = 1;
+
+    for _ in 0..plies {
+        if racks[0].is_empty() || racks[1].is_empty() {
+            break;
+        }
+
+        let mut replies = generate_moves(&board, &racks[side], lexicon, scorer);
+        apply_leave_equity(&mut replies, &racks[side], leaves);
+
+        // No play means a pass; nobody exchanges inside a simulation
+        if let Some(reply) = replies.into_iter().max_by(|a, b| a.equity.total_cmp(&b.equity)) {
+            for placement in &reply.placements {
+                let _ = board.place_placement(placement);
+            }
+            racks[side] = LeaveTable::leave_after(&racks[side], &reply.tiles_used);
+            refill(&mut racks[side], &mut bag);
+
+            let score = reply.score as f32;
+            spread += if side == 0 { score } else { -score };
+        }
+
+        side = 1 - side;
+    }
+
+    spread + leaves.evaluate(&racks[0]) - leaves.evaluate(&racks[1])
+}
+
+fn refill(rack: &mut Vec<char>, bag: &mut impl Iterator<Item = char>) {
+    let missing = RACK_SIZE.saturating_sub(rack.len());
+    rack.extend(bag.take(missing));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    fn settings(iterations: u32) -> SimulationSettings {
+        SimulationSettings {
+            iterations,
+            plies: 1,
+            time_budget: None,
+        }
+    }
+
+    #[test]
+    fn test_avoids_opening_a_hook() {
+        // CAT outscores DO, but the opponent is sure to hook an S onto it
+        let lexicon = Lexicon::from_words(["CAT", "CATS", "DO"], "Test");
+        let scorer = ScoreCalculator::new();
+        let leaves = LeaveTable::parse("").unwrap();
+        let board = Board::default();
+        let rack = ['C', 'A', 'T', 'D', 'O'];
+        let unseen = ['S', 'V', 'V', 'V', 'V', 'V', 'V'];
+
+        let moves = generate_moves(&board, &rack, &lexicon, &scorer);
+        let cat = moves.iter().find(|m| m.word == "CAT").unwrap().clone();
+        let dog = moves.iter().find(|m| m.word == "DO").unwrap().clone();
+        assert!(cat.score > dog.score);
+
+        let mut rng = StdRng::seed_from_u64(7);
+        let result = simulate(
+            &board, &rack, &unseen, &[cat, dog], &lexicon, &scorer, &leaves, settings(4), &mut rng,
+        )
+        .unwrap();
+
+        assert_eq!(result.best_index, 1);
+        assert_eq!(result.iterations, 4);
+        assert!(result.average_spread[0] < result.average_spread[1]);
+    }
+
+    #[test]
+    fn test_budget_limits() {
+        let lexicon = Lexicon::from_words(["CAT"], "Test");
+        let scorer = ScoreCalculator::new();
+        let leaves = LeaveTable::builtin();
+        let board = Board::default();
+        let rack = ['C', 'A', 'T'];
+        let moves = generate_moves(&board, &rack, &lexicon, &scorer);
+        let mut rng = StdRng::seed_from_u64(1);
+
+        // An exhausted time budget still runs one iteration
+        let mut rushed = settings(100);
+        rushed.time_budget = Some(Duration::ZERO);
+        let result = simulate(&board, &rack, &['E'; 20], &moves, &lexicon, &scorer, &leaves, rushed, &mut rng).unwrap();
+        assert_eq!(result.iterations, 1);
+
+        assert!(simulate(&board, &rack, &[], &[], &lexicon, &scorer, &leaves, settings(3), &mut rng).is_none());
+    }
+}
@@ -1,19 +1,89 @@
 /// Core gameplay systems for Stage 3
 
 use bevy::prelude::*;
-use super::{Stage3State, Stage3Config, Turn, Direction, MoveEvent, GameOverReason};
-use super::board::{Board, TileBag};
+use super::{Stage3State, Stage3Config, Turn, Direction, MoveEvent, MoveKind, GameOverReason, MAX_SCORELESS_TURNS};
+use super::board::{world_to_square, Board, TileBag, TilePlacement, BLANK};
+use super::scoring::{score_move, RACK_SIZE};
+use super::challenge::{resolve_challenge, PendingChallenge};
+use super::validation::{MoveError, ValidatedMove};
 use super::components::*;
 use crate::lexicon::Lexicon;
+use crate::plugins::rng::GameRng;
+use crate::plugins::state::{GameState, LastStageCompleted};
 use crate::scoring::ScoreCalculator;
 
 /// Player input state for building moves
 #[derive(Resource, Default)]
 pub struct PlayerMoveBuilder {
+    /// Rack tiles placed this turn, in placement order
     pub selected_rack_indices: Vec<usize>,
+    /// Board square each placed rack tile sits on (parallel to `selected_rack_indices`)
     pub placement_positions: Vec<(usize, usize)>,
+    /// Letter each placed tile plays as (parallel to `selected_rack_indices`);
+    /// `None` while a blank waits for the player to choose its letter
+    pub placed_letters: Vec<Option<char>>,
     pub placement_direction: Option<Direction>,
     pub is_building: bool,
+    /// Rack tile picked up and waiting to be placed on the board
+    pub held_rack_index: Option<usize>,
+    /// Set when the player submits the move for validation
+    pub submitted: bool,
+    /// Move that passed validation and is ready to execute
+    pub validated: Option<ValidatedMove>,
+    /// Why the last submitted move was rejected (shown in the HUD)
+    pub last_error: Option<MoveError>,
+    /// Rack clicks mark tiles to exchange instead of picking them up
+    pub exchange_mode: bool,
+    /// Rack tiles marked for exchange
+    pub exchange_indices: Vec<usize>,
+    pub exchange_requested: bool,
+    pub pass_requested: bool,
+}
+
+impl PlayerMoveBuilder {
+    /// Tiles placed so far, paired with their letters from `rack`
+    ///
+    /// Blanks use the letter the player chose for them; a blank still
+    /// waiting for its letter is left out.
+    pub fn placements(&self, rack: &[char]) -> Vec<TilePlacement> {
+        self.placement_positions
+            .iter()
+            .zip(&self.selected_rack_indices)
+            .zip(&self.placed_letters)
+            .filter_map(|((&(row, col), &idx), &letter)| {
+                Some(TilePlacement { row, col, letter: letter?, is_blank: *rack.get(idx)? == BLANK })
+            })
+            .collect()
+    }
+
+    /// Checks if the last placed tile is a blank still waiting for its letter
+    pub fn awaiting_blank_letter(&self) -> bool {
+        self.placed_letters.last().is_some_and(|letter| letter.is_none())
+    }
+
+    /// Takes back the last placed tile
+    pub fn undo_placement(&mut self) {
+        self.selected_rack_indices.pop();
+        self.placement_positions.pop();
+        self.placed_letters.pop();
+        self.is_building = !self.placement_positions.is_empty();
+    }
+
+    /// Takes every placed tile back to the rack
+    pub fn clear(&mut self) {
+        self.selected_rack_indices.clear();
+        self.placement_positions.clear();
+        self.placed_letters.clear();
+        self.placement_direction = None;
+        self.is_building = false;
+        self.held_rack_index = None;
+        self.submitted = false;
+        self.validated = None;
+        self.exchange_mode = false;
+        self.exchange_indices.clear();
+        self.exchange_requested = false;
+        self.pass_requested = false;
+    }
 }
 
 /// Handle player input (tile selection, placement, submission)
@@ -23,57 +93,128 @@ pub fn handle_player_input(
     windows: Query<&Window>,
     camera: Query<(&Camera, &GlobalTransform)>,
     mut move_builder: ResMut<PlayerMoveBuilder>,
-    mut rack_query: Query<(&mut RackTile, &Transform)>,
-    board_query: Query<(&BoardSquare, &Transform)>,
+    rack_query: Query<(&Interaction, &RackTile), Changed<Interaction>>,
+    board: Res<Board>,
     state: Res<Stage3State>,
 ) {
-    // Only accept input during player's turn
-    if state.current_turn != Turn::Player || !state.is_active {
+    // Only accept input during player's turn, once the AI's play is settled
+    if state.current_turn != Turn::Player || !state.is_active || state.pending_challenge.is_some() {
+        return;
+    }
+
+    // A freshly placed blank needs its letter before anything else happens
+    if move_builder.awaiting_blank_letter() {
+        if let Some(letter) = pressed_letter(&keyboard) {
+            if let Some(slot) = move_builder.placed_letters.last_mut() {
+                *slot = Some(letter);
+            }
+        } else if keyboard.just_pressed(KeyCode::Backspace) {
+            move_builder.undo_placement();
+        }
+        return;
+    }
+
+    // Toggle exchange mode with 'S' (takes any placed tiles back first)
+    if keyboard.just_pressed(KeyCode::KeyS) {
+        let entering = !move_builder.exchange_mode;
+        move_builder.clear();
+        move_builder.exchange_mode = entering;
+        move_builder.last_error = None;
+    }
+
+    // Pass with 'X' (P is pause)
+    if keyboard.just_pressed(KeyCode::KeyX) {
+        move_builder.clear();
+        move_builder.pass_requested = true;
         return;
     }
 
-    // Get cursor position in world coordinates
-    let window = windows.single();
-    let (camera, camera_transform) = camera.single();
-
-    if let Some(cursor_pos) = window.cursor_position() {
-        // Convert cursor to world position
-        // (Simplified - real implementation needs proper screen-to-world conversion)
-
-        // Handle tile selection from rack
-        if mouse.just_pressed(MouseButton::Left) {
-            for (i, (mut tile, _transform)) in rack_query.iter_mut().enumerate() {
-                // Check if cursor is over this rack tile
-                // If yes, toggle selection
-                tile.is_selected = !tile.is_selected;
-
-                if tile.is_selected && !move_builder.selected_rack_indices.contains(&i) {
-                    move_builder.selected_rack_indices.push(i);
-                } else if !tile.is_selected {
-                    move_builder.selected_rack_indices.retain(|&idx| idx != i);
+    if move_builder.exchange_mode {
+        // Mark rack tiles to exchange, Enter swaps them
+        for (interaction, tile) in rack_query.iter() {
+            if *interaction != Interaction::Pressed || tile.rack_index >= state.player_rack.len() {
+                continue;
+            }
+            if let Some(i) = move_builder.exchange_indices.iter().position(|&idx| idx == tile.rack_index) {
+                move_builder.exchange_indices.remove(i);
+            } else {
+                move_builder.exchange_indices.push(tile.rack_index);
+            }
+        }
+
+        let confirm = keyboard.just_pressed(KeyCode::Enter) || keyboard.just_pressed(KeyCode::Space);
+        if confirm && !move_builder.exchange_indices.is_empty() {
+            move_builder.exchange_requested = true;
+        }
+        return;
+    }
+
+    // Pick up a rack tile (click again to put it back)
+    let mut clicked_rack = false;
+    for (interaction, tile) in rack_query.iter() {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        clicked_rack = true;
+
+        let already_placed = move_builder.selected_rack_indices.contains(&tile.rack_index);
+        if tile.rack_index >= state.player_rack.len() || already_placed {
+            continue;
+        }
+
+        move_builder.held_rack_index = if move_builder.held_rack_index == Some(tile.rack_index) {
+            None
+        } else {
+            Some(tile.rack_index)
+        };
+    }
+
+    // Drop the held tile on the board square under the cursor
+    if mouse.just_pressed(MouseButton::Left) && !clicked_rack {
+        if let Some(held) = move_builder.held_rack_index {
+            let square = windows
+                .get_single()
+                .ok()
+                .and_then(|window| window.cursor_position())
+                .zip(camera.get_single().ok())
+                .and_then(|(cursor, (camera, camera_transform))| {
+                    camera.viewport_to_world_2d(camera_transform, cursor).ok()
+                })
+                .and_then(world_to_square);
+
+            if let Some(position) = square {
+                let taken = move_builder.placement_positions.contains(&position);
+                if board.is_empty(position.0, position.1) && !taken {
+                    move_builder.selected_rack_indices.push(held);
+                    move_builder.placement_positions.push(position);
+                    let letter = state.player_rack.get(held).copied();
+                    move_builder.placed_letters.push(letter.filter(|&l| l != BLANK));
+                    move_builder.held_rack_index = None;
+                    move_builder.is_building = true;
+                    move_builder.last_error = None;
                 }
             }
         }
     }
 
+    // Take back the last placed tile with Backspace
+    if keyboard.just_pressed(KeyCode::Backspace) {
+        move_builder.undo_placement();
+        move_builder.last_error = None;
+    }
+
     // Submit move with Enter/Space
     if keyboard.just_pressed(KeyCode::Enter) || keyboard.just_pressed(KeyCode::Space) {
         if !move_builder.selected_rack_indices.is_empty() {
-            move_builder.is_building = false; // Trigger validation
+            move_builder.submitted = true; // Trigger validation
         }
     }
 
     // Cancel move with Escape (already handled by pause system)
-    // Clear selection with 'C' key
+    // Clear placed tiles with 'C' key
     if keyboard.just_pressed(KeyCode::KeyC) {
-        move_builder.selected_rack_indices.clear();
-        move_builder.placement_positions.clear();
-        move_builder.is_building = false;
-
-        // Deselect all rack tiles
-        for (mut tile, _) in rack_query.iter_mut() {
-            tile.is_selected = false;
-        }
+        move_builder.clear();
+        move_builder.last_error = None;
     }
 }
 
@@ -81,148 +222,253 @@ pub fn handle_player_input(
 pub fn validate_player_move(
     mut move_builder: ResMut<PlayerMoveBuilder>,
     state: Res<Stage3State>,
+    config: Res<Stage3Config>,
     board: Res<Board>,
     lexicon: Res<Lexicon>,
 ) {
-    if move_builder.is_building || move_builder.selected_rack_indices.is_empty() {
+    if !move_builder.submitted {
         return;
     }
+    move_builder.submitted = false;
 
-    // Build word from selected tiles and positions
-    let word = build_word_from_move(&move_builder, &state);
-
-    if word.is_empty() {
-        return;
-    }
+    let placements = move_builder.placements(&state.player_rack);
 
-    // Validate word exists in lexicon
-    if !lexicon.is_valid(&word.to_uppercase()) {
-        // Invalid word - show error
-        move_builder.selected_rack_indices.clear();
-        move_builder.placement_positions.clear();
-        return;
-    }
-
-    // Validate move connects to existing tiles
-    if !board.is_board_empty() {
-        let connects = check_move_connects(&move_builder, &board);
-        if !connects {
-            // Move doesn't connect - show error
-            move_builder.selected_rack_indices.clear();
-            move_builder.placement_positions.clear();
-            return;
+    // Under challenge rules phonies are allowed through for the AI to challenge
+    let result = if config.challenge_rule.allows_phonies() {
+        board.evaluate_move(&placements, &lexicon)
+    } else {
+        board.validate_move(&placements, &lexicon)
+    };
+
+    // Invalid moves stay on the board so the player can fix them
+    match result {
+        Ok(validated) => {
+            move_builder.placement_direction = Some(validated.direction);
+            move_builder.validated = Some(validated);
+            move_builder.last_error = None;
+        }
+        Err(error) => {
+            move_builder.last_error = Some(error);
         }
     }
-
-    // Move is valid - ready to execute
 }
 
 /// Execute validated move
 pub fn execute_move(
-    mut commands: Commands,
     mut move_builder: ResMut<PlayerMoveBuilder>,
     mut state: ResMut<Stage3State>,
     mut board: ResMut<Board>,
     mut tile_bag: ResMut<TileBag>,
+    config: Res<Stage3Config>,
     scorer: Res<ScoreCalculator>,
     mut move_events: EventWriter<MoveEvent>,
 ) {
-    if move_builder.is_building || move_builder.selected_rack_indices.is_empty() {
+    let Some(validated) = move_builder.validated.take() else {
         return;
-    }
-
-    // Build word
-    let word = build_word_from_move(&move_builder, &state);
-
-    if word.is_empty() {
-        return;
-    }
-
-    // Calculate score
-    let score = calculate_move_score(&word, &move_builder, &board, &scorer);
+    };
+    let scoreless_before = state.scoreless_turns;
+    let rack_before = state.player_rack.clone();
+    let tiles_used: Vec<char> = move_builder
+        .selected_rack_indices
+        .iter()
+        .filter_map(|&idx| state.player_rack.get(idx).copied())
+        .collect();
+
+    // Score against the board as it was before this move
+    let score = score_move(&board, &validated.placements, validated.direction, &scorer);
 
     // Place tiles on board
-    for (i, &pos) in move_builder.placement_positions.iter().enumerate() {
-        let tile_idx = move_builder.selected_rack_indices[i];
-        let letter = state.player_rack[tile_idx];
-        let _ = board.place(pos.0, pos.1, letter);
+    for placement in &validated.placements {
+        let _ = board.place_placement(placement);
     }
 
     // Update player score
     state.player_score += score;
 
-    // Remove used tiles from rack
-    for &idx in move_builder.selected_rack_indices.iter().rev() {
-        state.player_rack.remove(idx);
+    // Remove used tiles from rack (highest index first so the rest stay put)
+    let mut used = move_builder.selected_rack_indices.clone();
+    used.sort_unstable_by(|a, b| b.cmp(a));
+    for idx in used {
+        if idx < state.player_rack.len() {
+            state.player_rack.remove(idx);
+        }
     }
 
-    // Draw new tiles to refill rack to 7
-    let tiles_to_draw = 7 - state.player_rack.len();
-    let new_tiles = tile_bag.draw_tiles(tiles_to_draw);
-    state.player_rack.extend(new_tiles);
+    // Draw new tiles to refill rack to 7 (under challenge rules, once the play stands)
+    if !config.challenge_rule.allows_phonies() {
+        let tiles_to_draw = RACK_SIZE.saturating_sub(state.player_rack.len());
+        let new_tiles = tile_bag.draw_tiles(tiles_to_draw);
+        state.player_rack.extend(new_tiles);
+    }
 
     // Send move event
     move_events.send(MoveEvent {
         player: Turn::Player,
-        word: word.clone(),
-        position: move_builder.placement_positions[0],
-        direction: move_builder.placement_direction.unwrap_or(Direction::Horizontal),
+        word: validated.word.clone(),
+        position: validated.position,
+        direction: validated.direction,
         score,
     });
 
     // Record move
-    let turn_number = state.moves_history.len() as u32 + 1;
-    state.moves_history.push(super::MoveRecord {
-        turn_number,
-        player: Turn::Player,
-        word: word.clone(),
+    let record = state.record_move(
+        Turn::Player,
+        MoveKind::Play,
+        validated.word.clone(),
         score,
-        position: move_builder.placement_positions[0],
-        direction: move_builder.placement_direction.unwrap_or(Direction::Horizontal),
-    });
+        validated.position,
+        validated.direction,
+    );
+    record.rack = rack_before;
+    record.placements = validated.placements.clone();
+
+    if config.challenge_rule.allows_phonies() {
+        state.pending_challenge = Some(PendingChallenge {
+            player: Turn::Player,
+            placements: validated.placements,
+            tiles_used,
+            word: validated.word,
+            phonies: validated.phonies,
+            score,
+            record_index: state.moves_history.len() - 1,
+            scoreless_before,
+        });
+    }
 
     // Clear move builder
-    move_builder.selected_rack_indices.clear();
-    move_builder.placement_positions.clear();
-    move_builder.placement_direction = None;
+    move_builder.clear();
 
     // Switch to AI turn
     state.current_turn = Turn::AI;
 }
 
-/// Update turn logic
-pub fn update_turn(
-    state: Res<Stage3State>,
-    tile_bag: Res<TileBag>,
+/// Accept (N/Enter) or challenge (Y) the AI's last play
+pub fn handle_challenge_input(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut state: ResMut<Stage3State>,
+    mut board: ResMut<Board>,
+    mut tile_bag: ResMut<TileBag>,
+    config: Res<Stage3Config>,
 ) {
-    // Check if game should end
-    if tile_bag.is_empty() && (state.player_rack.is_empty() || state.ai_rack.is_empty()) {
-        // Game ends when tile bag is empty and one player has no tiles
+    if state.current_turn != Turn::Player || !state.is_active {
+        return;
+    }
+    if !state.pending_challenge.as_ref().is_some_and(|p| p.player == Turn::AI) {
+        return;
+    }
+
+    let challenged = if keyboard.just_pressed(KeyCode::KeyY) {
+        true
+    } else if keyboard.just_pressed(KeyCode::KeyN) || keyboard.just_pressed(KeyCode::Enter) {
+        false
+    } else {
+        return;
+    };
+
+    if let Some(outcome) = resolve_challenge(&mut state, &mut board, &mut tile_bag, config.challenge_rule, challenged) {
+        if challenged {
+            state.last_challenge = Some((Turn::Player, outcome));
+        }
     }
 }
 
-/// Check for game over conditions
-pub fn check_game_over(
+/// Exchange marked rack tiles or pass, as requested by the player
+pub fn execute_exchange_or_pass(
+    mut move_builder: ResMut<PlayerMoveBuilder>,
     mut state: ResMut<Stage3State>,
-    config: Res<Stage3Config>,
-    tile_bag: Res<TileBag>,
+    mut tile_bag: ResMut<TileBag>,
+    mut rng: ResMut<GameRng>,
 ) {
-    if !state.is_active {
+    if state.current_turn != Turn::Player || !state.is_active {
         return;
     }
 
-    // Check time limit
-    if config.time_limit_seconds > 0 && state.time_remaining_ms == 0 {
-        state.is_active = false;
-        state.game_over_reason = Some(GameOverReason::TimeExpired);
+    let rack_before = state.player_rack.clone();
+    let kind = if move_builder.pass_requested {
+        MoveKind::Pass
+    } else if move_builder.exchange_requested {
+        move_builder.exchange_requested = false;
+
+        let mut indices = move_builder.exchange_indices.clone();
+        indices.sort_unstable_by(|a, b| b.cmp(a));
+        let tiles: Vec<char> = indices
+            .iter()
+            .filter_map(|&idx| state.player_rack.get(idx).copied())
+            .collect();
+
+        match tile_bag.exchange(tiles.clone(), &mut *rng) {
+            Ok(drawn) => {
+                for idx in indices {
+                    if idx < state.player_rack.len() {
+                        state.player_rack.remove(idx);
+                    }
+                }
+                state.player_rack.extend(drawn);
+                MoveKind::Exchange(tiles)
+            }
+            Err(_) => {
+                move_builder.last_error = Some(MoveError::ExchangeUnavailable);
+                return;
+            }
+        }
+    } else {
+        return;
+    };
+
+    state.record_move(Turn::Player, kind, String::new(), 0, (7, 7), Direction::Horizontal).rack = rack_before;
+    move_builder.clear();
+    move_builder.last_error = None;
+    state.current_turn = Turn::AI;
+}
+
+/// Update turn logic: ends the game once a player goes out after the bag is empty
+pub fn update_turn(
+    mut state: ResMut<Stage3State>,
+    tile_bag: Res<TileBag>,
+    scorer: Res<ScoreCalculator>,
+) {
+    // A play still open to challenge hasn't emptied the rack for good yet
+    if !state.is_active || !tile_bag.is_empty() || state.pending_challenge.is_some() {
         return;
     }
 
-    // Check if tile bag empty and both players have no moves
-    if tile_bag.is_empty() {
-        // Simplified: End game when tiles run out
-        state.is_active = false;
-        state.game_over_reason = Some(GameOverReason::TileBagEmpty);
+    let went_out = if state.player_rack.is_empty() {
+        Some(Turn::Player)
+    } else if state.ai_rack.is_empty() {
+        Some(Turn::AI)
+    } else {
+        None
+    };
+
+    if let Some(player) = went_out {
+        state.finish_game(GameOverReason::RackOut(player), &scorer);
+    }
+}
+
+/// Check for game over conditions and move to the results screen
+pub fn check_game_over(
+    mut state: ResMut<Stage3State>,
+    config: Res<Stage3Config>,
+    scorer: Res<ScoreCalculator>,
+    mut next_state: ResMut<NextState<GameState>>,
+    mut last_stage: ResMut<LastStageCompleted>,
+) {
+    if state.is_active {
+        // Check time limit
+        if config.time_limit_seconds > 0 && state.time_remaining_ms == 0 {
+            state.finish_game(GameOverReason::TimeExpired, &scorer);
+        }
+        // Six scoreless turns in a row ends the game
+        else if state.scoreless_turns >= MAX_SCORELESS_TURNS {
+            state.finish_game(GameOverReason::ScorelessTurns, &scorer);
+        }
+    }
+
+    if !state.is_active && state.game_over_reason.is_some() {
+        info!("Stage 3 game over ({:?}): Player {} - AI {}",
+              state.game_over_reason, state.player_score, state.ai_score);
+        *last_stage = LastStageCompleted::Stage3;
+        next_state.set(GameState::Results);
     }
 }
 
@@ -247,76 +493,20 @@ pub fn update_timer(
 
 // Helper functions
 
-fn build_word_from_move(
-    move_builder: &PlayerMoveBuilder,
-    state: &Stage3State,
-) -> String {
-    let mut word = String::new();
-
-    for &idx in &move_builder.selected_rack_indices {
-        if let Some(&letter) = state.player_rack.get(idx) {
-            word.push(letter);
-        }
-    }
-
-    word
-}
-
-fn check_move_connects(
-    move_builder: &PlayerMoveBuilder,
-    board: &Board,
-) -> bool {
-    // Check if at least one placed tile is adjacent to an existing tile
-    for &(row, col) in &move_builder.placement_positions {
-        // Check adjacent squares
-        let adjacent_positions = [
-            (row.wrapping_sub(1), col),
-            (row + 1, col),
-            (row, col.wrapping_sub(1)),
-            (row, col + 1),
-        ];
-
-        for &(adj_row, adj_col) in &adjacent_positions {
-            if adj_row < 15 && adj_col < 15 {
-                if board.get(adj_row, adj_col).is_some() {
-                    return true;
-                }
-            }
-        }
-    }
-
-    false
-}
-
-fn calculate_move_score(
-    word: &str,
-    move_builder: &PlayerMoveBuilder,
-    board: &Board,
-    scorer: &ScoreCalculator,
-) -> u32 {
-    // Simplified scoring - real implementation accounts for premium squares
-    let base_score = scorer.calculate_score(word, 0.0, 1.0);
-
-    // Apply premium square bonuses
-    let mut total_score = base_score;
-
-    for &(row, col) in &move_builder.placement_positions {
-        let premium = board.get_premium(row, col);
-        total_score = apply_premium_bonus(total_score, premium);
-    }
-
-    total_score
-}
-
-fn apply_premium_bonus(score: u32, premium: super::components::PremiumSquare) -> u32 {
-    use super::components::PremiumSquare::*;
-
-    match premium {
-        DoubleLetter => score + (score / 4),  // Rough approximation
-        TripleLetter => score + (score / 2),
-        DoubleWord => score * 2,
-        TripleWord => score * 3,
-        Center => score * 2,
-        Normal => score,
-    }
+/// Letter key pressed this frame, if any
+fn pressed_letter(keyboard: &ButtonInput<KeyCode>) -> Option<char> {
+    const LETTER_KEYS: [KeyCode; 26] = [
+        KeyCode::KeyA, KeyCode::KeyB, KeyCode::KeyC, KeyCode::KeyD, KeyCode::KeyE,
+        KeyCode::KeyF, KeyCode::KeyG, KeyCode::KeyH, KeyCode::KeyI, KeyCode::KeyJ,
+        KeyCode::KeyK, KeyCode::KeyL, KeyCode::KeyM, KeyCode::KeyN, KeyCode::KeyO,
+        KeyCode::KeyP, KeyCode::KeyQ, KeyCode::KeyR, KeyCode::KeyS, KeyCode::KeyT,
+        KeyCode::KeyU, KeyCode::KeyV, KeyCode::KeyW, KeyCode::KeyX, KeyCode::KeyY,
+        KeyCode::KeyZ,
+    ];
+
+    LETTER_KEYS
+        .iter()
+        .zip('A'..='Z')
+        .find(|(key, _)| keyboard.just_pressed(**key))
+        .map(|(_, letter)| letter)
 }
@@ -1,8 +1,20 @@
 /// UI for Stage 3 (Classic Board)
 
 use bevy::prelude::*;
-use super::{Stage3State, Stage3Config, Turn};
-use crate::plugins::state::GameState;
+use super::{Stage3State, Stage3Config, Turn, GameOverReason};
+use super::board::{TileBag, BLANK};
+use super::challenge::ChallengeOutcome;
+use super::components::RackTile;
+use super::endgame::{describe_play, EndgameAnalysis};
+use super::gcg::SavedGameRecord;
+use super::hints::HintState;
+use super::personality::PersonalityStats;
+use super::review::GameReview;
+use super::systems::PlayerMoveBuilder;
+use super::undo::UndoHistory;
+use crate::plugins::rating::PlayerRating;
+use crate::plugins::rng::GameRng;
+use crate::plugins::state::{GameState, LastStageCompleted};
 
 /// Marker for Stage 3 HUD
 #[derive(Component)]
@@ -16,12 +28,46 @@ pub enum HUDElement {
     Timer,
     TilesRemaining,
     TurnIndicator,
+    /// Why the last submitted move was rejected
+    MoveMessage,
+    /// Seed the game was dealt from
+    Seed,
 }
 
 /// Marker for rack display
 #[derive(Component)]
 pub struct RackDisplay;
 
+/// Marker for the "choose a letter" prompt shown while placing a blank
+#[derive(Component)]
+pub struct BlankPrompt;
+
+/// Marker for the prompt asking whether to challenge the AI's play
+#[derive(Component)]
+pub struct ChallengePrompt;
+
+/// Marker for the endgame analysis panel
+#[derive(Component)]
+pub struct EndgamePanel;
+
+/// Marker for the hint list
+#[derive(Component)]
+pub struct HintPanel;
+
+/// Marker for results screen
+#[derive(Component)]
+pub struct Stage3ResultsScreen;
+
+/// Marker for results screen buttons
+#[derive(Component)]
+pub enum ResultsButton {
+    PlayAgain,
+    /// Back to the Stage 5 bracket after a match game
+    Tournament,
+    Review,
+    MainMenu,
+}
+
 /// Spawn Stage 3 HUD
 pub fn spawn_stage3_hud(
     mut commands: Commands,
@@ -51,17 +97,38 @@ pub fn spawn_stage3_hud(
             Stage3HUD,
         ))
         .with_children(|parent| {
-            // Left: Player score
-            parent.spawn((
-                Text::new("Player: 0"),
-                TextFont {
-                    font: font_bold.clone(),
-                    font_size: 24.0,
+            // Left: Player score and the game's seed
+            parent
+                .spawn(NodeBundle {
+                    node: Node {
+                        flex_direction: FlexDirection::Column,
+                        ..default()
+                    },
                     ..default()
-                },
-                TextColor(Color::WHITE),
-                HUDElement::PlayerScore,
-            ));
+                })
+                .with_children(|left| {
+                    left.spawn((
+                        Text::new("Player: 0"),
+                        TextFont {
+                            font: font_bold.clone(),
+                            font_size: 24.0,
+                            ..default()
+                        },
+                        TextColor(Color::WHITE),
+                        HUDElement::PlayerScore,
+                    ));
+
+                    left.spawn((
+                        Text::new(""),
+                        TextFont {
+                            font: font_medium.clone(),
+                            font_size: 14.0,
+                            ..default()
+                        },
+                        TextColor(Color::srgb(0.5, 0.5, 0.6)),
+                        HUDElement::Seed,
+                    ));
+                });
 
             // Center: Timer and turn indicator
             parent
@@ -95,6 +162,17 @@ pub fn spawn_stage3_hud(
                         TextColor(Color::srgb(0.3, 0.9, 0.3)),
                         HUDElement::TurnIndicator,
                     ));
+
+                    center.spawn((
+                        Text::new(""),
+                        TextFont {
+                            font: font_medium.clone(),
+                            font_size: 14.0,
+                            ..default()
+                        },
+                        TextColor(Color::srgb(1.0, 0.4, 0.4)),
+                        HUDElement::MoveMessage,
+                    ));
                 });
 
             // Right: AI score and tiles remaining
@@ -161,17 +239,39 @@ fn spawn_rack_ui(
             RackDisplay,
         ))
         .with_children(|parent| {
-            // Placeholder for 7 rack tiles (will be populated dynamically)
-            for _ in 0..7 {
-                parent.spawn(NodeBundle {
-                    node: Node {
-                        width: Val::Px(70.0),
-                        height: Val::Px(70.0),
-                        ..default()
-                    },
-                    background_color: Color::srgb(0.7, 0.7, 0.6).into(),
-                    ..default()
-                });
+            // 7 rack tiles, letters filled in by update_rack_display
+            for rack_index in 0..7 {
+                parent
+                    .spawn((
+                        ButtonBundle {
+                            node: Node {
+                                width: Val::Px(70.0),
+                                height: Val::Px(70.0),
+                                justify_content: JustifyContent::Center,
+                                align_items: AlignItems::Center,
+                                ..default()
+                            },
+                            background_color: Color::srgb(0.7, 0.7, 0.6).into(),
+                            ..default()
+                        },
+                        RackTile {
+                            letter: ' ',
+                            rack_index,
+                            is_selected: false,
+                            is_blank: false,
+                        },
+                    ))
+                    .with_children(|tile| {
+                        tile.spawn((
+                            Text::new(""),
+                            TextFont {
+                                font: asset_server.load("fonts/FiraSans-Bold.ttf"),
+                                font_size: 36.0,
+                                ..default()
+                            },
+                            TextColor(Color::srgb(0.1, 0.1, 0.1)),
+                        ));
+                    });
             }
         });
 }
@@ -180,6 +280,8 @@ fn spawn_rack_ui(
 pub fn update_stage3_hud(
     state: Res<Stage3State>,
     tile_bag: Res<super::board::TileBag>,
+    move_builder: Res<PlayerMoveBuilder>,
+    rng: Res<GameRng>,
     mut hud_query: Query<(&HUDElement, &mut Text, Option<&mut TextColor>)>,
 ) {
     for (element, mut text, text_color) in hud_query.iter_mut() {
@@ -210,27 +312,105 @@ pub fn update_stage3_hud(
                 **text = format!("Tiles: {}", tile_bag.count());
             }
             HUDElement::TurnIndicator => {}
+            HUDElement::MoveMessage => {
+                **text = match (&move_builder.last_error, state.last_challenge) {
+                    (Some(error), _) => error.to_string(),
+                    (None, Some((challenger, outcome))) => challenge_message(challenger, outcome),
+                    (None, None) => String::new(),
+                };
+            }
+            HUDElement::Seed => {
+                **text = format!("Seed {}", rng.seed());
+            }
         }
     }
 }
 
+/// Describes how the last challenge went
+fn challenge_message(challenger: Turn, outcome: ChallengeOutcome) -> String {
+    let who = match challenger {
+        Turn::Player => "You",
+        Turn::AI => "AI",
+    };
+    match outcome {
+        ChallengeOutcome::PhonyRemoved => format!("{} challenged: phony taken back", who),
+        ChallengeOutcome::PlayStands => format!("{} challenged: the play is good", who),
+        ChallengeOutcome::Accepted => String::new(),
+    }
+}
+
 /// Update rack display
 pub fn update_rack_display(
     state: Res<Stage3State>,
-    // Rack tile updates would go here
+    move_builder: Res<PlayerMoveBuilder>,
+    mut rack_query: Query<(&mut RackTile, &mut BackgroundColor, &Children)>,
+    mut text_query: Query<&mut Text>,
 ) {
-    // Update visual representation of player's rack tiles
-    // In full implementation, would update 7 tile sprites/text
+    for (mut tile, mut background, children) in rack_query.iter_mut() {
+        let letter = state.player_rack.get(tile.rack_index).copied();
+        let placed = move_builder.selected_rack_indices.contains(&tile.rack_index);
+
+        tile.letter = letter.unwrap_or(' ');
+        tile.is_blank = letter == Some(BLANK);
+        tile.is_selected = move_builder.held_rack_index == Some(tile.rack_index)
+            || move_builder.exchange_indices.contains(&tile.rack_index);
+
+        // Empty slots and tiles already on the board are dimmed
+        background.0 = match letter {
+            None => Color::srgba(0.3, 0.3, 0.3, 0.5),
+            Some(_) if placed => Color::srgba(0.7, 0.7, 0.6, 0.3),
+            Some(_) if tile.is_selected && move_builder.exchange_mode => Color::srgb(0.9, 0.5, 0.4),
+            Some(_) if tile.is_selected => Color::srgb(1.0, 0.85, 0.4),
+            Some(_) if tile.is_blank => Color::srgb(0.95, 0.95, 0.95),
+            Some(_) => Color::srgb(0.7, 0.7, 0.6),
+        };
+
+        // Blanks have no letter until they are played
+        for &child in children.iter() {
+            if let Ok(mut text) = text_query.get_mut(child) {
+                **text = match letter {
+                    Some(letter) if !placed && letter != BLANK => letter.to_string(),
+                    _ => String::new(),
+                };
+            }
+        }
+    }
 }
 
 /// Update turn indicator
 pub fn update_turn_indicator(
     state: Res<Stage3State>,
+    config: Res<Stage3Config>,
+    move_builder: Res<PlayerMoveBuilder>,
+    tile_bag: Res<TileBag>,
+    history: Res<UndoHistory>,
     mut hud_query: Query<(&HUDElement, &mut Text, &mut TextColor)>,
 ) {
+    let mut keys = Vec::new();
+    if config.allow_hints {
+        keys.push("H: hint");
+    }
+    if config.allow_undo && history.can_undo() {
+        keys.push("U: undo");
+    }
+    if config.allow_undo && history.can_redo() {
+        keys.push("R: redo");
+    }
+    if tile_bag.is_empty() {
+        keys.push("A: analyze endgame");
+    }
+
     for (element, mut text, mut text_color) in hud_query.iter_mut() {
         if matches!(element, HUDElement::TurnIndicator) {
             match state.current_turn {
+                Turn::Player if move_builder.exchange_mode => {
+                    **text = "Exchange: pick tiles, Enter to swap, S to cancel".to_string();
+                    text_color.0 = Color::srgb(0.9, 0.5, 0.4);
+                }
+                Turn::Player if !keys.is_empty() => {
+                    **text = format!("Your Turn ({})", keys.join(", "));
+                    text_color.0 = Color::srgb(0.3, 0.9, 0.3);
+                }
                 Turn::Player => {
                     **text = "Your Turn".to_string();
                     text_color.0 = Color::srgb(0.3, 0.9, 0.3);
@@ -243,3 +423,487 @@ pub fn update_turn_indicator(
         }
     }
 }
+
+/// Show or hide the prompt asking which letter a placed blank stands for
+pub fn update_blank_prompt(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    move_builder: Res<PlayerMoveBuilder>,
+    prompt_query: Query<Entity, With<BlankPrompt>>,
+) {
+    let awaiting = move_builder.awaiting_blank_letter();
+    let shown = !prompt_query.is_empty();
+
+    if awaiting && !shown {
+        spawn_prompt(
+            &mut commands,
+            &asset_server,
+            BlankPrompt,
+            "Blank Tile",
+            "Type the letter it should stand for (A-Z)\nBackspace takes it back".to_string(),
+        );
+    } else if !awaiting && shown {
+        for entity in prompt_query.iter() {
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+}
+
+/// Show or hide the prompt asking whether to challenge the AI's play
+pub fn update_challenge_prompt(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    state: Res<Stage3State>,
+    config: Res<Stage3Config>,
+    prompt_query: Query<Entity, With<ChallengePrompt>>,
+) {
+    let pending = state
+        .pending_challenge
+        .as_ref()
+        .filter(|p| p.player == Turn::AI && state.current_turn == Turn::Player);
+    let shown = !prompt_query.is_empty();
+
+    if let (Some(pending), false) = (pending, shown) {
+        spawn_prompt(
+            &mut commands,
+            &asset_server,
+            ChallengePrompt,
+            "Challenge?",
+            format!(
+                "AI played {} for {} points ({} challenge)\nY = Challenge, N = Accept",
+                pending.word,
+                pending.score,
+                config.challenge_rule.name()
+            ),
+        );
+    } else if pending.is_none() && shown {
+        for entity in prompt_query.iter() {
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+}
+
+/// Show or hide the endgame analysis panel
+pub fn update_endgame_panel(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    analysis: Res<EndgameAnalysis>,
+    panel_query: Query<Entity, With<EndgamePanel>>,
+) {
+    if !analysis.is_changed() {
+        return;
+    }
+    for entity in panel_query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+
+    if !analysis.is_visible {
+        return;
+    }
+    let Some(solution) = analysis.solution.as_ref() else {
+        let body = "Searching the rest of the game...\nA closes".to_string();
+        spawn_prompt(&mut commands, &asset_server, EndgamePanel, "Endgame Analysis", body);
+        return;
+    };
+
+    // Alternate You/AI down the principal line
+    let mut body = String::new();
+    for (ply, play) in solution.line.iter().enumerate() {
+        let side = if ply % 2 == 0 { "You" } else { "AI" };
+        body.push_str(&format!("{}. {}: {}\n", ply + 1, side, describe_play(play.as_ref())));
+    }
+    body.push_str(&format!(
+        "\nFinal spread {:+} ({}, {} plies)\nA closes",
+        solution.value,
+        if solution.is_exact { "solved" } else { "best found" },
+        solution.depth
+    ));
+
+    spawn_prompt(&mut commands, &asset_server, EndgamePanel, "Endgame Analysis", body);
+}
+
+/// Show or hide the hint list, marking the play ghosted on the board
+pub fn update_hint_panel(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    hints: Res<HintState>,
+    panel_query: Query<Entity, With<HintPanel>>,
+) {
+    if !hints.is_changed() {
+        return;
+    }
+    for entity in panel_query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+    if !hints.is_visible {
+        return;
+    }
+
+    let mut body = String::new();
+    if hints.is_searching() {
+        body.push_str("Searching for plays...\n");
+    }
+    for (i, play) in hints.suggestions.iter().enumerate() {
+        let marker = if i == hints.selected { ">" } else { " " };
+        body.push_str(&format!(
+            "{} {}. {}  eq {:.1}\n",
+            marker,
+            i + 1,
+            describe_play(Some(play)),
+            play.equity
+        ));
+    }
+    if hints.suggestions.is_empty() && !hints.is_searching() {
+        body.push_str("No plays found\nTry exchanging (S) or passing (X)\n");
+    }
+    body.push_str("\nH: next hint");
+
+    commands
+        .spawn((
+            NodeBundle {
+                node: Node {
+                    position_type: PositionType::Absolute,
+                    right: Val::Px(15.0),
+                    top: Val::Px(90.0),
+                    width: Val::Px(280.0),
+                    padding: UiRect::all(Val::Px(15.0)),
+                    flex_direction: FlexDirection::Column,
+                    row_gap: Val::Px(8.0),
+                    ..default()
+                },
+                background_color: Color::srgba(0.1, 0.1, 0.15, 0.9).into(),
+                ..default()
+            },
+            HintPanel,
+            Stage3HUD,
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new("Hints"),
+                TextFont {
+                    font: asset_server.load("fonts/FiraSans-Bold.ttf"),
+                    font_size: 22.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.5, 0.7, 1.0)),
+            ));
+
+            parent.spawn((
+                Text::new(body),
+                TextFont {
+                    font: asset_server.load("fonts/FiraSans-Medium.ttf"),
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.8, 0.8, 0.8)),
+            ));
+        });
+}
+
+/// Spawn a small centered prompt panel
+fn spawn_prompt(
+    commands: &mut Commands,
+    asset_server: &AssetServer,
+    marker: impl Component,
+    title: &str,
+    body: String,
+) {
+    commands
+        .spawn((
+            NodeBundle {
+                node: Node {
+                    position_type: PositionType::Absolute,
+                    left: Val::Percent(50.0),
+                    top: Val::Percent(45.0),
+                    margin: UiRect::left(Val::Px(-200.0)),
+                    width: Val::Px(400.0),
+                    padding: UiRect::all(Val::Px(20.0)),
+                    flex_direction: FlexDirection::Column,
+                    align_items: AlignItems::Center,
+                    row_gap: Val::Px(8.0),
+                    ..default()
+                },
+                background_color: Color::srgba(0.1, 0.1, 0.15, 0.95).into(),
+                ..default()
+            },
+            marker,
+            Stage3HUD,
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new(title),
+                TextFont {
+                    font: asset_server.load("fonts/FiraSans-Bold.ttf"),
+                    font_size: 28.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+            ));
+
+            parent.spawn((
+                Text::new(body),
+                TextFont {
+                    font: asset_server.load("fonts/FiraSans-Medium.ttf"),
+                    font_size: 18.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.8, 0.8, 0.8)),
+                TextLayout::new_with_justify(JustifyText::Center),
+            ));
+        });
+}
+
+/// Spawn results screen with final scores and how the game ended
+#[allow(clippy::too_many_arguments)]
+pub fn spawn_results_screen(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    state: Res<Stage3State>,
+    config: Res<Stage3Config>,
+    personality_stats: Res<PersonalityStats>,
+    rng: Res<GameRng>,
+    saved_record: Res<SavedGameRecord>,
+    last_stage: Res<LastStageCompleted>,
+    rating: Res<PlayerRating>,
+) {
+    // Only spawn if this stage just completed
+    if *last_stage != LastStageCompleted::Stage3 {
+        return;
+    }
+
+    let font_bold: Handle<Font> = asset_server.load("fonts/FiraSans-Bold.ttf");
+    let font_medium: Handle<Font> = asset_server.load("fonts/FiraSans-Medium.ttf");
+
+    let (title, title_color) = match state.winner() {
+        Some(Turn::Player) => ("YOU WIN!", Color::srgb(0.3, 0.9, 0.3)),
+        Some(Turn::AI) => ("AI WINS", Color::srgb(0.9, 0.4, 0.4)),
+        None => ("TIE GAME", Color::srgb(0.9, 0.9, 1.0)),
+    };
+
+    let reason = match state.game_over_reason {
+        Some(GameOverReason::RackOut(Turn::Player)) => "You played out your rack",
+        Some(GameOverReason::RackOut(Turn::AI)) => "The AI played out its rack",
+        Some(GameOverReason::ScorelessTurns) => "Six scoreless turns in a row",
+        Some(GameOverReason::TimeExpired) => "Time expired",
+        Some(GameOverReason::NoMovesAvailable) => "No moves available",
+        Some(GameOverReason::TileBagEmpty) => "Tile bag empty",
+        Some(GameOverReason::PlayerQuit) => "Game abandoned",
+        None => "",
+    };
+
+    let (player_adjust, ai_adjust) = state.end_adjustments.unwrap_or((0, 0));
+    let words_played = state
+        .moves_history
+        .iter()
+        .filter(|m| m.player == Turn::Player && m.kind == super::MoveKind::Play)
+        .count();
+    let best_word = state
+        .moves_history
+        .iter()
+        .filter(|m| m.player == Turn::Player && m.kind == super::MoveKind::Play)
+        .max_by_key(|m| m.score);
+
+    let mut lines = vec![
+        format!("You: {} ({:+} leftover tiles)", state.player_score, player_adjust),
+        format!("AI: {} ({:+} leftover tiles)", state.ai_score, ai_adjust),
+        format!("Words Played: {}", words_played),
+    ];
+    if let Some(best) = best_word {
+        lines.push(format!("Best Word: {} ({} pts)", best.word, best.score));
+    }
+    if config.allow_hints {
+        lines.push(format!("Hints Used: {}", state.hints_used));
+    }
+    if let Some(personality) = config.personality {
+        lines.push(format!("AI Style - {}", personality_stats.summary(personality)));
+    }
+    match &rating.last_change {
+        Some(change) => lines.push(format!("Rating: {} ({:+})", rating.display(), change.delta())),
+        None if config.tournament_game => {
+            lines.push(format!("Rating: {} (rated when the match is decided)", rating.display()))
+        }
+        None => {}
+    }
+    lines.push(format!("Seed: {}", rng.seed()));
+    if let Some(file_name) = saved_record.path.as_ref().and_then(|path| path.file_name()) {
+        lines.push(format!("Game Record: {}", file_name.to_string_lossy()));
+    }
+
+    // Root container
+    commands
+        .spawn((
+            NodeBundle {
+                node: Node {
+                    width: Val::Percent(100.0),
+                    height: Val::Percent(100.0),
+                    flex_direction: FlexDirection::Column,
+                    align_items: AlignItems::Center,
+                    justify_content: JustifyContent::Center,
+                    ..default()
+                },
+                background_color: Color::srgba(0.1, 0.1, 0.15, 0.95).into(),
+                ..default()
+            },
+            Stage3ResultsScreen,
+        ))
+        .with_children(|parent| {
+            // Title
+            parent.spawn((
+                Text::new(title),
+                TextFont {
+                    font: font_bold.clone(),
+                    font_size: 56.0,
+                    ..default()
+                },
+                TextColor(title_color),
+            ));
+
+            parent.spawn((
+                Text::new(reason),
+                TextFont {
+                    font: font_medium.clone(),
+                    font_size: 22.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.7, 0.7, 0.8)),
+                Node {
+                    margin: UiRect::bottom(Val::Px(40.0)),
+                    ..default()
+                },
+            ));
+
+            // Stats container
+            parent
+                .spawn(NodeBundle {
+                    node: Node {
+                        flex_direction: FlexDirection::Column,
+                        row_gap: Val::Px(15.0),
+                        margin: UiRect::bottom(Val::Px(40.0)),
+                        ..default()
+                    },
+                    ..default()
+                })
+                .with_children(|stats| {
+                    for line in lines {
+                        stats.spawn((
+                            Text::new(line),
+                            TextFont {
+                                font: font_bold.clone(),
+                                font_size: 28.0,
+                                ..default()
+                            },
+                            TextColor(Color::WHITE),
+                        ));
+                    }
+                });
+
+            // Buttons container
+            parent
+                .spawn(NodeBundle {
+                    node: Node {
+                        flex_direction: FlexDirection::Row,
+                        column_gap: Val::Px(20.0),
+                        ..default()
+                    },
+                    ..default()
+                })
+                .with_children(|buttons| {
+                    let mut choices = if config.tournament_game {
+                        vec![(ResultsButton::Tournament, "Tournament")]
+                    } else {
+                        vec![(ResultsButton::PlayAgain, "Play Again")]
+                    };
+                    if saved_record.game.is_some() {
+                        choices.push((ResultsButton::Review, "Review"));
+                    }
+                    choices.push((ResultsButton::MainMenu, "Main Menu"));
+
+                    for (button, label) in choices {
+                        let color = results_button_color(&button);
+                        buttons
+                            .spawn((
+                                ButtonBundle {
+                                    node: Node {
+                                        width: Val::Px(200.0),
+                                        height: Val::Px(60.0),
+                                        justify_content: JustifyContent::Center,
+                                        align_items: AlignItems::Center,
+                                        ..default()
+                                    },
+                                    background_color: color.into(),
+                                    ..default()
+                                },
+                                button,
+                            ))
+                            .with_children(|button| {
+                                button.spawn((
+                                    Text::new(label),
+                                    TextFont {
+                                        font: font_bold.clone(),
+                                        font_size: 28.0,
+                                        ..default()
+                                    },
+                                    TextColor(Color::srgb(0.1, 0.1, 0.15)),
+                                ));
+                            });
+                    }
+                });
+        });
+}
+
+/// Handle results screen button clicks
+pub fn handle_results_buttons(
+    mut commands: Commands,
+    mut interaction_query: Query<
+        (&Interaction, &ResultsButton, &mut BackgroundColor),
+        Changed<Interaction>,
+    >,
+    mut next_state: ResMut<NextState<GameState>>,
+    mut last_stage: ResMut<LastStageCompleted>,
+    results_screen_query: Query<Entity, With<Stage3ResultsScreen>>,
+    saved_record: Res<SavedGameRecord>,
+    mut review: ResMut<GameReview>,
+) {
+    for (interaction, button, mut color) in interaction_query.iter_mut() {
+        match *interaction {
+            Interaction::Pressed => {
+                for entity in results_screen_query.iter() {
+                    commands.entity(entity).despawn_recursive();
+                }
+                *last_stage = LastStageCompleted::None;
+
+                match button {
+                    ResultsButton::PlayAgain => next_state.set(GameState::Stage3Playing),
+                    ResultsButton::Tournament => next_state.set(GameState::Stage5Playing),
+                    ResultsButton::Review => {
+                        if let Some(game) = &saved_record.game {
+                            review.open(game.clone(), "Last game");
+                        }
+                        next_state.set(GameState::Stage3Review);
+                    }
+                    ResultsButton::MainMenu => next_state.set(GameState::MainMenu),
+                }
+            }
+            Interaction::Hovered => {
+                let base = results_button_color(button).to_srgba();
+                *color = Color::srgb(
+                    (base.red + 0.1).min(1.0),
+                    (base.green + 0.1).min(1.0),
+                    (base.blue + 0.1).min(1.0),
+                )
+                .into();
+            }
+            Interaction::None => {
+                *color = results_button_color(button).into();
+            }
+        }
+    }
+}
+
+fn results_button_color(button: &ResultsButton) -> Color {
+    match button {
+        ResultsButton::PlayAgain | ResultsButton::Tournament => Color::srgb(0.3, 0.7, 0.3),
+        ResultsButton::Review => Color::srgb(0.4, 0.6, 0.9),
+        ResultsButton::MainMenu => Color::srgb(0.5, 0.5, 0.6),
+    }
+}
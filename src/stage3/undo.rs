@@ -0,0 +1,198 @@
+/// Undo/redo of turns in Stage 3 practice games
+///
+/// A snapshot of the game state, board and tile bag (in its exact draw
+/// order) is taken whenever the player's turn starts. U steps back to the
+/// previous snapshot, taking back the player's last move together with the
+/// AI's reply; R steps forward again. Playing on from an earlier position
+/// drops the turns that were taken back. The clock and the hint count carry
+/// on as they are, so undoing never buys time or hides hints.
+
+use bevy::prelude::*;
+use super::{Stage3Config, Stage3State, Turn};
+use super::board::{Board, TileBag};
+use super::endgame::EndgameAnalysis;
+use super::hints::HintState;
+use super::systems::PlayerMoveBuilder;
+
+/// Everything needed to put a game back exactly as it was
+#[derive(Clone)]
+pub struct GameSnapshot {
+    pub state: Stage3State,
+    pub board: Board,
+    pub tile_bag: TileBag,
+}
+
+impl GameSnapshot {
+    /// Moves played before this snapshot, which identifies the position
+    fn position(&self) -> usize {
+        self.state.moves_history.len()
+    }
+}
+
+/// Snapshots of the player's turns, the current one last
+#[derive(Resource, Default)]
+pub struct UndoHistory {
+    past: Vec<GameSnapshot>,
+    /// Turns taken back, the next one to redo last
+    future: Vec<GameSnapshot>,
+}
+
+impl UndoHistory {
+    /// Whether the position after `moves_played` moves is the latest snapshot
+    pub fn is_recorded(&self, moves_played: usize) -> bool {
+        self.past.last().is_some_and(|s| s.position() == moves_played)
+    }
+
+    /// Adds the start of a new player turn, dropping any turns taken back
+    pub fn record(&mut self, snapshot: GameSnapshot) {
+        if self.is_recorded(snapshot.position()) {
+            return;
+        }
+        self.past.push(snapshot);
+        self.future.clear();
+    }
+
+    pub fn can_undo(&self) -> bool {
+        self.past.len() > 1
+    }
+
+    pub fn can_redo(&self) -> bool {
+        !self.future.is_empty()
+    }
+
+    /// Steps back one turn pair, returning the position to restore
+    pub fn undo(&mut self) -> Option<&GameSnapshot> {
+        if !self.can_undo() {
+            return None;
+        }
+        let current = self.past.pop()?;
+        self.future.push(current);
+        self.past.last()
+    }
+
+    /// Replays the last turn pair taken back, returning the position to restore
+    pub fn redo(&mut self) -> Option<&GameSnapshot> {
+        let next = self.future.pop()?;
+        self.past.push(next);
+        self.past.last()
+    }
+}
+
+/// Snapshot the game each time the player's turn starts
+pub fn record_turn_start(
+    mut history: ResMut<UndoHistory>,
+    state: Res<Stage3State>,
+    config: Res<Stage3Config>,
+    board: Res<Board>,
+    tile_bag: Res<TileBag>,
+) {
+    let settled = state.is_active && state.current_turn == Turn::Player && state.pending_challenge.is_none();
+    if !config.allow_undo || !settled || history.is_recorded(state.moves_history.len()) {
+        return;
+    }
+
+    history.record(GameSnapshot {
+        state: state.clone(),
+        board: board.clone(),
+        tile_bag: tile_bag.clone(),
+    });
+}
+
+/// Take back the last turn pair with 'U', replay it with 'R'
+#[allow(clippy::too_many_arguments)]
+pub fn handle_undo_input(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut history: ResMut<UndoHistory>,
+    mut state: ResMut<Stage3State>,
+    mut board: ResMut<Board>,
+    mut tile_bag: ResMut<TileBag>,
+    mut move_builder: ResMut<PlayerMoveBuilder>,
+    mut hints: ResMut<HintState>,
+    mut analysis: ResMut<EndgameAnalysis>,
+    config: Res<Stage3Config>,
+) {
+    let available = config.allow_undo
+        && state.is_active
+        && state.current_turn == Turn::Player
+        && state.pending_challenge.is_none()
+        && !move_builder.awaiting_blank_letter();
+    if !available {
+        return;
+    }
+
+    let snapshot = if keyboard.just_pressed(KeyCode::KeyU) {
+        history.undo()
+    } else if keyboard.just_pressed(KeyCode::KeyR) {
+        history.redo()
+    } else {
+        None
+    };
+    let Some(snapshot) = snapshot.cloned() else {
+        return;
+    };
+
+    info!("Restoring the position after {} moves", snapshot.position());
+    let time_remaining_ms = state.time_remaining_ms;
+    let hints_used = state.hints_used;
+    *state = snapshot.state;
+    state.time_remaining_ms = time_remaining_ms;
+    state.hints_used = hints_used;
+    *board = snapshot.board;
+    *tile_bag = snapshot.tile_bag;
+
+    // Anything built on the old position no longer applies
+    move_builder.clear();
+    move_builder.last_error = None;
+    *hints = HintState::default();
+    *analysis = EndgameAnalysis::default();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::{Direction, MoveKind};
+
+    fn snapshot_after(moves: usize, bag: &[char]) -> GameSnapshot {
+        let mut state = Stage3State::default();
+        for _ in 0..moves {
+            state.record_move(Turn::Player, MoveKind::Pass, String::new(), 0, (7, 7), Direction::Horizontal);
+        }
+        GameSnapshot {
+            state,
+            board: Board::default(),
+            tile_bag: TileBag { tiles: bag.to_vec(), remaining_count: bag.len() },
+        }
+    }
+
+    #[test]
+    fn test_undo_and_redo() {
+        let mut history = UndoHistory::default();
+        history.record(snapshot_after(0, &['A', 'B', 'C']));
+        history.record(snapshot_after(2, &['C']));
+        assert!(!history.can_redo());
+
+        let restored = history.undo().unwrap();
+        assert_eq!(restored.position(), 0);
+        assert_eq!(restored.tile_bag.tiles, vec!['A', 'B', 'C']);
+        assert!(history.undo().is_none());
+
+        // Being back at a recorded position doesn't record it again
+        history.record(snapshot_after(0, &['A', 'B', 'C']));
+        assert!(history.can_redo());
+
+        assert_eq!(history.redo().unwrap().position(), 2);
+        assert!(!history.can_redo());
+    }
+
+    #[test]
+    fn test_new_move_drops_redo() {
+        let mut history = UndoHistory::default();
+        history.record(snapshot_after(0, &[]));
+        history.record(snapshot_after(2, &[]));
+        history.undo();
+
+        history.record(snapshot_after(2, &['Z']));
+        assert!(!history.can_redo());
+        assert!(history.can_undo());
+    }
+}
@@ -0,0 +1,304 @@
+/// Move validation for Stage 3 (Classic Board)
+///
+/// Full placement rules: tiles go on empty squares in a single row or column
+/// with no gaps, the first move covers the center star, later moves touch
+/// existing tiles, and every word formed (main word and cross-words) must be
+/// in the lexicon.
+
+use std::fmt;
+use super::Direction;
+use super::board::{Board, TilePlacement, WordSquare};
+use crate::lexicon::Lexicon;
+
+const BOARD_SIZE: usize = 15;
+const CENTER: (usize, usize) = (7, 7);
+
+/// Reasons a placement is rejected
+#[derive(Debug, Clone, PartialEq)]
+pub enum MoveError {
+    /// No tiles were placed
+    NoTiles,
+    /// A tile is off the 15×15 board
+    OutOfBounds,
+    /// A tile is on a square that already holds a tile (or two tiles share a square)
+    SquareOccupied,
+    /// Tiles are not all in one row or one column
+    NotInLine,
+    /// Empty squares separate the placed tiles
+    Gap,
+    /// The first move does not cover the center star
+    MissingCenter,
+    /// The move does not touch any tile already on the board
+    Disconnected,
+    /// The move only forms a single letter
+    TooShort,
+    /// A word formed by the move is not in the lexicon
+    InvalidWord(String),
+    /// Too few tiles left in the bag to exchange
+    ExchangeUnavailable,
+}
+
+impl fmt::Display for MoveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MoveError::NoTiles => write!(f, "Place at least one tile"),
+            MoveError::OutOfBounds => write!(f, "Tiles must be on the board"),
+            MoveError::SquareOccupied => write!(f, "That square is already taken"),
+            MoveError::NotInLine => write!(f, "Tiles must be in a single row or column"),
+            MoveError::Gap => write!(f, "Tiles must not leave gaps"),
+            MoveError::MissingCenter => write!(f, "First word must cover the center star"),
+            MoveError::Disconnected => write!(f, "Word must connect to tiles on the board"),
+            MoveError::TooShort => write!(f, "Words must be at least 2 letters"),
+            MoveError::InvalidWord(word) => write!(f, "{} is not a valid word", word),
+            MoveError::ExchangeUnavailable => write!(f, "Not enough tiles in the bag to exchange"),
+        }
+    }
+}
+
+/// A placement that passed every rule
+#[derive(Debug, Clone, PartialEq)]
+pub struct ValidatedMove {
+    pub placements: Vec<TilePlacement>,
+    pub direction: Direction,
+    /// Main word along `direction`
+    pub word: String,
+    /// First square of the main word
+    pub position: (usize, usize),
+    /// Every word formed, main word first
+    pub words_formed: Vec<String>,
+    /// Words formed that are not in the lexicon (only non-empty from `evaluate_move`)
+    pub phonies: Vec<String>,
+}
+
+impl Board {
+    /// Validates `placements` against the full placement rules
+    ///
+    /// # Arguments
+    /// * `placements` - Tiles placed this turn (board not yet updated)
+    /// * `lexicon` - Word list used to check every word formed
+    ///
+    /// # Returns
+    /// * `Result<ValidatedMove, MoveError>` - The words formed, or the first rule broken
+    pub fn validate_move(
+        &self,
+        placements: &[TilePlacement],
+        lexicon: &Lexicon,
+    ) -> Result<ValidatedMove, MoveError> {
+        let evaluated = self.evaluate_move(placements, lexicon)?;
+
+        match evaluated.phonies.first() {
+            Some(invalid) => Err(MoveError::InvalidWord(invalid.clone())),
+            None => Ok(evaluated),
+        }
+    }
+
+    /// Checks placement rules only, listing invalid words instead of rejecting them
+    ///
+    /// Used under challenge rules, where a phony may be played and left for
+    /// the opponent to challenge.
+    pub fn evaluate_move(
+        &self,
+        placements: &[TilePlacement],
+        lexicon: &Lexicon,
+    ) -> Result<ValidatedMove, MoveError> {
+        let direction = self.check_placement(placements)?;
+        let words = self.words_formed(placements, direction);
+        let phonies = words.iter().filter(|word| !lexicon.is_valid(word)).cloned().collect();
+
+        let main = self.word_through(placements, (placements[0].row, placements[0].col), direction);
+
+        Ok(ValidatedMove {
+            placements: placements.to_vec(),
+            direction,
+            word: spell(&main),
+            position: (main[0].row, main[0].col),
+            words_formed: words,
+            phonies,
+        })
+    }
+
+    /// Checks geometry only (line, gaps, center, connection) and returns the main direction
+    pub fn check_placement(&self, placements: &[TilePlacement]) -> Result<Direction, MoveError> {
+        if placements.is_empty() {
+            return Err(MoveError::NoTiles);
+        }
+
+        for (i, p) in placements.iter().enumerate() {
+            if p.row >= BOARD_SIZE || p.col >= BOARD_SIZE {
+                return Err(MoveError::OutOfBounds);
+            }
+            let duplicate = placements[..i].iter().any(|q| q.row == p.row && q.col == p.col);
+            if duplicate || self.get(p.row, p.col).is_some() {
+                return Err(MoveError::SquareOccupied);
+            }
+        }
+
+        let same_row = placements.iter().all(|p| p.row == placements[0].row);
+        let same_col = placements.iter().all(|p| p.col == placements[0].col);
+        if !same_row && !same_col {
+            return Err(MoveError::NotInLine);
+        }
+
+        let direction = self.main_direction(placements);
+
+        // Every square between the first and last tile must be filled
+        let main = self.word_through(placements, (placements[0].row, placements[0].col), direction);
+        if placements
+            .iter()
+            .any(|p| !main.iter().any(|s| s.row == p.row && s.col == p.col))
+        {
+            return Err(MoveError::Gap);
+        }
+
+        if self.is_board_empty() {
+            if !placements.iter().any(|p| (p.row, p.col) == CENTER) {
+                return Err(MoveError::MissingCenter);
+            }
+            if main.len() < 2 {
+                return Err(MoveError::TooShort);
+            }
+        } else {
+            let through_existing = main.iter().any(|s| !s.is_new);
+            let touches_existing = placements.iter().any(|p| self.has_neighbor(p.row, p.col));
+            if !through_existing && !touches_existing {
+                return Err(MoveError::Disconnected);
+            }
+            if self.words_formed(placements, direction).is_empty() {
+                return Err(MoveError::TooShort);
+            }
+        }
+
+        Ok(direction)
+    }
+
+    /// Every word of 2+ letters formed by `placements`, main word first
+    pub fn words_formed(&self, placements: &[TilePlacement], direction: Direction) -> Vec<String> {
+        let perpendicular = match direction {
+            Direction::Horizontal => Direction::Vertical,
+            Direction::Vertical => Direction::Horizontal,
+        };
+
+        let mut words = Vec::new();
+        let main = self.word_through(placements, (placements[0].row, placements[0].col), direction);
+        if main.len() >= 2 {
+            words.push(spell(&main));
+        }
+
+        for p in placements {
+            let cross = self.word_through(placements, (p.row, p.col), perpendicular);
+            if cross.len() >= 2 {
+                words.push(spell(&cross));
+            }
+        }
+
+        words
+    }
+
+    /// Checks if any orthogonally adjacent square holds a tile
+    pub fn has_neighbor(&self, row: usize, col: usize) -> bool {
+        (row > 0 && self.get(row - 1, col).is_some())
+            || self.get(row + 1, col).is_some()
+            || (col > 0 && self.get(row, col - 1).is_some())
+            || self.get(row, col + 1).is_some()
+    }
+}
+
+/// Spells out the letters of a word read off the board
+fn spell(squares: &[WordSquare]) -> String {
+    squares.iter().map(|s| s.letter).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tiles(list: &[(usize, usize, char)]) -> Vec<TilePlacement> {
+        list.iter()
+            .map(|&(row, col, letter)| TilePlacement { row, col, letter, is_blank: false })
+            .collect()
+    }
+
+    fn lexicon() -> Lexicon {
+        Lexicon::from_words(["CAT", "CATS", "AT", "TA", "ACT", "SCAT"], "Test")
+    }
+
+    #[test]
+    fn test_first_move_rules() {
+        let board = Board::default();
+        let lexicon = lexicon();
+
+        let ok = board.validate_move(&tiles(&[(7, 6, 'C'), (7, 7, 'A'), (7, 8, 'T')]), &lexicon);
+        assert_eq!(ok.unwrap().word, "CAT");
+
+        let off_center = board.validate_move(&tiles(&[(0, 0, 'C'), (0, 1, 'A'), (0, 2, 'T')]), &lexicon);
+        assert_eq!(off_center, Err(MoveError::MissingCenter));
+
+        let single = board.validate_move(&tiles(&[(7, 7, 'A')]), &lexicon);
+        assert_eq!(single, Err(MoveError::TooShort));
+    }
+
+    #[test]
+    fn test_line_and_gap_rules() {
+        let board = Board::default();
+        let lexicon = lexicon();
+
+        let diagonal = board.validate_move(&tiles(&[(7, 7, 'A'), (8, 8, 'T')]), &lexicon);
+        assert_eq!(diagonal, Err(MoveError::NotInLine));
+
+        let gap = board.validate_move(&tiles(&[(7, 6, 'C'), (7, 7, 'A'), (7, 9, 'T')]), &lexicon);
+        assert_eq!(gap, Err(MoveError::Gap));
+    }
+
+    #[test]
+    fn test_reads_existing_letters() {
+        let board = Board::with_word("CAT", 7, 6);
+        let lexicon = lexicon();
+
+        // S after CAT makes CATS; S before makes SCAT
+        let cats = board.validate_move(&tiles(&[(7, 9, 'S')]), &lexicon).unwrap();
+        assert_eq!(cats.word, "CATS");
+        assert_eq!(cats.position, (7, 6));
+
+        let scat = board.validate_move(&tiles(&[(7, 5, 'S')]), &lexicon).unwrap();
+        assert_eq!(scat.word, "SCAT");
+
+        let far = board.validate_move(&tiles(&[(0, 0, 'A'), (0, 1, 'T')]), &lexicon);
+        assert_eq!(far, Err(MoveError::Disconnected));
+    }
+
+    #[test]
+    fn test_cross_words_must_be_valid() {
+        let board = Board::with_word("AT", 7, 7);
+        let lexicon = lexicon();
+
+        // TA under AT: forms TA across plus AT and TA down
+        let ok = board.validate_move(&tiles(&[(8, 7, 'T'), (8, 8, 'A')]), &lexicon).unwrap();
+        assert_eq!(ok.words_formed, vec!["TA", "AT", "TA"]);
+
+        // AT under AT: forms AA and TT down, which are not words
+        let bad = board.validate_move(&tiles(&[(8, 7, 'A'), (8, 8, 'T')]), &lexicon);
+        assert_eq!(bad, Err(MoveError::InvalidWord("AA".to_string())));
+    }
+
+    #[test]
+    fn test_evaluate_move_lists_phonies() {
+        let board = Board::with_word("AT", 7, 7);
+        let lexicon = lexicon();
+
+        let evaluated = board.evaluate_move(&tiles(&[(8, 7, 'A'), (8, 8, 'T')]), &lexicon).unwrap();
+        assert_eq!(evaluated.phonies, vec!["AA", "TT"]);
+
+        // Placement rules still apply
+        let diagonal = board.evaluate_move(&tiles(&[(8, 7, 'A'), (9, 8, 'T')]), &lexicon);
+        assert_eq!(diagonal, Err(MoveError::NotInLine));
+    }
+
+    #[test]
+    fn test_occupied_squares() {
+        let board = Board::with_word("AT", 7, 7);
+        let lexicon = lexicon();
+
+        let occupied = board.validate_move(&tiles(&[(7, 7, 'C')]), &lexicon);
+        assert_eq!(occupied, Err(MoveError::SquareOccupied));
+    }
+}
@@ -1,7 +1,132 @@
 /// Visual effects for Stage 3 (Classic Board)
 
 use bevy::prelude::*;
+use super::Stage3State;
+use super::board::{square_to_world, Board, SQUARE_SIZE};
 use super::components::*;
+use super::hints::HintState;
+use super::systems::PlayerMoveBuilder;
+
+/// Respawn letter tiles for the board and the move being built
+pub fn sync_board_tiles(
+    mut commands: Commands,
+    board: Res<Board>,
+    move_builder: Res<PlayerMoveBuilder>,
+    state: Res<Stage3State>,
+    asset_server: Res<AssetServer>,
+    tile_query: Query<Entity, With<BoardTile>>,
+) {
+    if !board.is_changed() && !move_builder.is_changed() && !state.is_changed() {
+        return;
+    }
+
+    for entity in tile_query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+
+    let font: Handle<Font> = asset_server.load("fonts/FiraSans-Bold.ttf");
+
+    let locked = (0..15)
+        .flat_map(|row| (0..15).map(move |col| (row, col)))
+        .filter_map(|(row, col)| {
+            board.get_tile(row, col).map(|tile| ((row, col), tile.letter, tile.is_blank, false))
+        });
+    let preview = move_builder
+        .placements(&state.player_rack)
+        .into_iter()
+        .map(|p| ((p.row, p.col), p.letter, p.is_blank, true));
+
+    for (position, letter, is_blank, is_preview) in locked.chain(preview) {
+        let world = square_to_world(position.0, position.1);
+
+        commands
+            .spawn((
+                Sprite {
+                    custom_size: Some(Vec2::splat(SQUARE_SIZE - 4.0)),
+                    ..default()
+                },
+                Transform::from_translation(world.extend(2.0)),
+                BoardTile {
+                    letter,
+                    position,
+                    is_locked: !is_preview,
+                    is_preview,
+                    is_blank,
+                },
+            ))
+            .with_children(|tile| {
+                // Blanks show their letter in lowercase blue, like a marked-up blank
+                let (text, color) = if is_blank {
+                    (letter.to_ascii_lowercase().to_string(), Color::srgb(0.2, 0.4, 0.8))
+                } else {
+                    (letter.to_string(), Color::srgb(0.1, 0.1, 0.1))
+                };
+                tile.spawn((
+                    Text2d::new(text),
+                    TextFont {
+                        font: font.clone(),
+                        font_size: 24.0,
+                        ..default()
+                    },
+                    TextColor(color),
+                    Transform::from_translation(Vec3::new(0.0, 0.0, 1.0)),
+                ));
+            });
+    }
+}
+
+/// Ghost the selected hint's tiles onto the board
+pub fn sync_hint_ghosts(
+    mut commands: Commands,
+    hints: Res<HintState>,
+    asset_server: Res<AssetServer>,
+    ghost_query: Query<Entity, With<HintGhost>>,
+) {
+    if !hints.is_changed() {
+        return;
+    }
+
+    for entity in ghost_query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+
+    let Some(hint) = hints.selected_move() else {
+        return;
+    };
+    let font: Handle<Font> = asset_server.load("fonts/FiraSans-Bold.ttf");
+
+    for placement in &hint.placements {
+        let world = square_to_world(placement.row, placement.col);
+        let text = if placement.is_blank {
+            placement.letter.to_ascii_lowercase().to_string()
+        } else {
+            placement.letter.to_string()
+        };
+
+        commands
+            .spawn((
+                Sprite {
+                    color: Color::srgba(0.5, 0.7, 1.0, 0.5), // Translucent blue
+                    custom_size: Some(Vec2::splat(SQUARE_SIZE - 4.0)),
+                    ..default()
+                },
+                Transform::from_translation(world.extend(2.5)),
+                HintGhost,
+            ))
+            .with_children(|tile| {
+                tile.spawn((
+                    Text2d::new(text),
+                    TextFont {
+                        font: font.clone(),
+                        font_size: 24.0,
+                        ..default()
+                    },
+                    TextColor(Color::srgba(0.1, 0.2, 0.5, 0.8)),
+                    Transform::from_translation(Vec3::new(0.0, 0.0, 1.0)),
+                ));
+            });
+    }
+}
 
 /// Update tile visual states
 pub fn update_tile_visuals(
@@ -10,6 +135,8 @@ pub fn update_tile_visuals(
     for (tile, mut sprite) in tile_query.iter_mut() {
         if tile.is_preview {
             sprite.color = Color::srgba(1.0, 1.0, 0.7, 0.7); // Yellow preview
+        } else if tile.is_blank {
+            sprite.color = Color::srgb(1.0, 1.0, 1.0); // Plain white blank
         } else if tile.is_locked {
             sprite.color = Color::srgb(0.95, 0.95, 0.85); // Locked tile
         } else {
@@ -4,6 +4,9 @@
 /// 7-tile rack refreshes after each word. Score as many points as possible before time runs out.
 
 use bevy::prelude::*;
+use rand::seq::SliceRandom;
+use rand::Rng;
+use crate::plugins::rng::GameRng;
 use crate::plugins::state::GameState;
 
 pub mod components;
@@ -35,8 +38,8 @@ impl Plugin for Stage4Plugin {
             // Startup
             .add_systems(OnEnter(GameState::Stage4Playing), (
                 ui::spawn_stage4_hud,
-                initialize_game,
-                deal_initial_rack,
+                // The rack is dealt from the new game's seed
+                (initialize_game, deal_initial_rack).chain(),
             ))
 
             // Core gameplay
@@ -172,12 +175,9 @@ impl TilePool {
     }
 
     /// Draw N random tiles
-    pub fn draw(&self, count: usize) -> Vec<char> {
-        use rand::seq::SliceRandom;
-        let mut rng = rand::thread_rng();
-
+    pub fn draw(&self, count: usize, rng: &mut impl Rng) -> Vec<char> {
         self.tiles
-            .choose_multiple(&mut rng, count)
+            .choose_multiple(rng, count)
             .copied()
             .collect()
     }
@@ -195,7 +195,9 @@ pub struct WordEvent {
 fn initialize_game(
     mut state: ResMut<Stage4State>,
     mut tile_pool: ResMut<TilePool>,
+    mut rng: ResMut<GameRng>,
 ) {
+    rng.start_game();
     *state = Stage4State::default();
     *tile_pool = TilePool::new();
 }
@@ -204,8 +206,9 @@ fn initialize_game(
 fn deal_initial_rack(
     mut state: ResMut<Stage4State>,
     tile_pool: Res<TilePool>,
+    mut rng: ResMut<GameRng>,
 ) {
-    state.rack = tile_pool.draw(7);
+    state.rack = tile_pool.draw(7, &mut *rng);
 }
 
 /// Cleanup Stage 4
@@ -3,6 +3,7 @@
 use bevy::prelude::*;
 use super::{Stage4State, Stage4Config, TilePool, WordEvent};
 use crate::lexicon::Lexicon;
+use crate::plugins::rng::GameRng;
 use crate::scoring::ScoreCalculator;
 
 /// Handle tile selection from rack
@@ -137,6 +138,7 @@ pub fn refresh_rack(
     mut state: ResMut<Stage4State>,
     tile_pool: Res<TilePool>,
     mut word_events: EventReader<WordEvent>,
+    mut rng: ResMut<GameRng>,
 ) {
     for _event in word_events.read() {
         // Remove used tiles (in reverse to preserve indices)
@@ -150,7 +152,7 @@ pub fn refresh_rack(
 
         // Refill rack to 7 tiles
         let needed = 7 - state.rack.len();
-        state.rack.extend(tile_pool.draw(needed));
+        state.rack.extend(tile_pool.draw(needed, &mut *rng));
 
         // Clear selection
         state.selected_indices.clear();
@@ -2,6 +2,7 @@
 
 use bevy::prelude::*;
 use super::{Stage4State, Stage4Config};
+use crate::plugins::rng::GameRng;
 
 /// Marker for Stage 4 HUD
 #[derive(Component)]
@@ -14,6 +15,8 @@ pub enum HUDElement {
     Timer,
     Streak,
     WordCount,
+    /// Seed the game was dealt from
+    Seed,
 }
 
 /// Spawn Stage 4 HUD
@@ -73,12 +76,24 @@ pub fn spawn_stage4_hud(
                 TextColor(Color::srgb(1.0, 1.0, 1.0)),
                 HUDElement::Streak,
             ));
+
+            parent.spawn((
+                Text::new(""),
+                TextFont {
+                    font: font_bold.clone(),
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.5, 0.5, 0.6)),
+                HUDElement::Seed,
+            ));
         });
 }
 
 /// Update HUD
 pub fn update_stage4_hud(
     state: Res<Stage4State>,
+    rng: Res<GameRng>,
     mut hud_query: Query<(&HUDElement, &mut Text, &mut TextColor)>,
 ) {
     for (element, mut text, mut text_color) in hud_query.iter_mut() {
@@ -114,6 +129,9 @@ pub fn update_stage4_hud(
             HUDElement::WordCount => {
                 **text = format!("Words: {}", state.words_formed);
             }
+            HUDElement::Seed => {
+                **text = format!("Seed {}", rng.seed());
+            }
         }
     }
 }
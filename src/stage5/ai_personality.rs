@@ -1,7 +1,9 @@
 /// AI opponent personalities for Stage 5
 
+use serde::{Deserialize, Serialize};
+
 /// AI playing style personality
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum AIPersonality {
     Aggressive,  // Prioritizes high-scoring moves, takes risks
     Defensive,   // Blocks opponent opportunities, plays safe
@@ -63,17 +63,12 @@ pub fn update_background_music(
         return;
     }
 
-    // Switch to intense music for semifinals/finals
-    match tournament_state.current_round {
-        super::components::TournamentRound::Finals => {
-            if music.current_track != MusicTrack::IntenseMatch {
-                music.current_track = MusicTrack::IntenseMatch;
-            }
-        }
-        _ => {
-            if music.current_track != MusicTrack::TournamentTheme {
-                music.current_track = MusicTrack::TournamentTheme;
-            }
+    // Switch to intense music for the deciding round
+    if tournament_state.is_final_round(tournament_state.current_round) {
+        if music.current_track != MusicTrack::IntenseMatch {
+            music.current_track = MusicTrack::IntenseMatch;
         }
+    } else if music.current_track != MusicTrack::TournamentTheme {
+        music.current_track = MusicTrack::TournamentTheme;
     }
 }
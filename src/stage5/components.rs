@@ -1,16 +1,23 @@
 /// Components for Stage 5 (AI Tournaments)
 
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
 
 /// Tournament player component
-#[derive(Component, Clone)]
+#[derive(Component, Clone, Serialize, Deserialize)]
 pub struct TournamentPlayer {
     pub name: String,
     pub is_human: bool,
     pub difficulty: u8,
     pub personality: Option<super::ai_personality::AIPersonality>,
+    /// Elo rating: the player's own, or the persona's published one
+    pub rating: u32,
     pub wins: u32,
     pub losses: u32,
+    /// Bracket seed (1 = strongest)
+    pub seed: u32,
+    /// Points scored minus points conceded, over every game played
+    pub spread: i32,
 }
 
 /// Match scoreboard component
@@ -32,12 +39,17 @@ pub struct BracketNode {
     pub winner: Option<TournamentPlayer>,
 }
 
-/// Tournament round
-#[derive(Debug, Clone, Copy, PartialEq)]
-pub enum TournamentRound {
-    Quarterfinals,  // 8 → 4
-    Semifinals,     // 4 → 2
-    Finals,         // 2 → 1
+/// Tournament round, numbered from 1 (0 = not started)
+///
+/// What a round is called depends on the format; see `TournamentState::round_name`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default, Serialize, Deserialize)]
+pub struct TournamentRound(pub u32);
+
+impl TournamentRound {
+    /// Round that follows this one
+    pub fn next(self) -> Self {
+        TournamentRound(self.0 + 1)
+    }
 }
 
 /// Victory celebration component
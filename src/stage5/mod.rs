@@ -1,13 +1,18 @@
 /// Stage 5: AI Tournaments
 ///
-/// 8-player elimination tournament bracket. Player faces AI opponents with unique personalities.
-/// Win best-of-3 matches to progress through quarterfinals, semifinals, and finals.
+/// Player faces AI opponents with unique personalities in best-of-3 matches.
+/// Runs as single or double elimination, Swiss, or round robin, for 4 to 16 players.
 
 use bevy::prelude::*;
+use crate::plugins::rating::PlayerRating;
+use crate::plugins::rng::GameRng;
 use crate::plugins::state::GameState;
+use crate::stage3::{load_leave_table, load_lexicon};
 
 pub mod components;
 pub mod tournament;
+pub mod pairing;
+pub mod save;
 pub mod ai_personality;
 pub mod systems;
 pub mod ui;
@@ -26,8 +31,14 @@ impl Plugin for Stage5Plugin {
     fn build(&self, app: &mut App) {
         app
             // Resources
+            .init_resource::<TournamentConfig>()
             .init_resource::<TournamentState>()
             .init_resource::<CurrentMatch>()
+            .init_resource::<SimulatedMatches>()
+            .init_resource::<BackgroundMatchPool>()
+            .init_resource::<SavedStage3Config>()
+            .init_resource::<save::MatchGameSnapshot>()
+            .init_resource::<save::ResumeRequest>()
 
             // Events
             .add_event::<audio::AudioEvent>()
@@ -35,18 +46,30 @@ impl Plugin for Stage5Plugin {
 
             // Startup
             .add_systems(OnEnter(GameState::Stage5Playing), (
-                initialize_tournament,
-                ui::spawn_tournament_bracket,
-                start_next_match,
+                load_lexicon,
+                load_leave_table,
+                restore_stage3_config,
+                (save::resume_tournament, initialize_tournament, ui::spawn_tournament_bracket).chain(),
             ))
 
+            // Match games are played on the Stage 3 board
+            .add_systems(OnEnter(GameState::Results), record_match_game
+                .after(crate::stage3::rate_game)
+                .before(crate::stage3::ui::spawn_results_screen))
+            .add_systems(OnEnter(GameState::MainMenu), (leave_match_game, restore_stage3_config).chain())
+            .add_systems(Update, save::snapshot_match_game.run_if(in_state(GameState::Stage3Playing)))
+
             // Core gameplay
             .add_systems(Update, (
+                handle_format_keys,
+                start_next_match,
                 handle_match_gameplay,
                 process_match_result,
                 advance_tournament,
+                run_simulated_matches,
                 check_tournament_complete,
-            ).run_if(in_state(GameState::Stage5Playing)))
+                save::autosave_tournament,
+            ).chain().run_if(in_state(GameState::Stage5Playing)))
 
             // UI systems
             .add_systems(Update, (
@@ -86,11 +109,30 @@ pub struct MatchResult {
     pub opponent_score: u32,
 }
 
-/// Initialize tournament
+/// Start a new tournament, unless one is already under way (e.g. after pausing or a match game)
 fn initialize_tournament(
     mut tournament_state: ResMut<TournamentState>,
+    mut current_match: ResMut<CurrentMatch>,
+    mut simulations: ResMut<SimulatedMatches>,
+    mut rng: ResMut<GameRng>,
+    config: Res<TournamentConfig>,
+    rating: Res<PlayerRating>,
+    mut snapshot: ResMut<save::MatchGameSnapshot>,
 ) {
-    *tournament_state = TournamentState::new();
+    if tournament_state.in_progress {
+        // A match game left unfinished picks up where it was saved, or is played again
+        if snapshot.0.is_none() {
+            current_match.game_in_progress = false;
+        }
+        return;
+    }
+
+    rng.start_game();
+    *tournament_state = TournamentState::with_config(&config, rating.display());
+    tournament_state.in_progress = true;
+    *current_match = CurrentMatch::default();
+    *simulations = SimulatedMatches::default();
+    snapshot.0 = None;
 }
 
 /// Cleanup Stage 5
@@ -0,0 +1,235 @@
+/// Pairing rules for the tournament formats
+///
+/// Everything here works on player indices and knows nothing about games:
+/// callers rank the field (best first) and say who has already met.
+/// Swiss and double elimination pair down the rankings while avoiding
+/// rematches; the last Swiss round is King of the Hill (1 v 2, 3 v 4, ...)
+/// whoever has met before. Round robin uses the circle method, so nobody
+/// meets twice by construction.
+
+use std::collections::HashSet;
+
+/// Most pairings tried before giving up on avoiding rematches
+const SEARCH_BUDGET: usize = 100_000;
+
+/// Who has already met, and who has already sat out a round
+#[derive(Debug, Clone, Default)]
+pub struct PairingHistory {
+    met: HashSet<(usize, usize)>,
+    byes: HashSet<usize>,
+}
+
+impl PairingHistory {
+    pub fn add_match(&mut self, first: usize, second: usize) {
+        self.met.insert((first.min(second), first.max(second)));
+    }
+
+    pub fn add_bye(&mut self, player: usize) {
+        self.byes.insert(player);
+    }
+
+    pub fn have_met(&self, first: usize, second: usize) -> bool {
+        self.met.contains(&(first.min(second), first.max(second)))
+    }
+
+    pub fn had_bye(&self, player: usize) -> bool {
+        self.byes.contains(&player)
+    }
+}
+
+/// One round's matches, plus anyone sitting out
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RoundPairings {
+    pub matches: Vec<(usize, usize)>,
+    pub byes: Vec<usize>,
+}
+
+/// Pairs neighbouring bracket slots; a player with an empty slot beside them goes through
+pub fn elimination_pairings(slots: &[Option<usize>]) -> RoundPairings {
+    let mut pairings = RoundPairings::default();
+    for pair in slots.chunks(2) {
+        match pair {
+            [Some(first), Some(second)] => pairings.matches.push((*first, *second)),
+            [Some(player), None] | [None, Some(player)] | [Some(player)] => pairings.byes.push(*player),
+            _ => {}
+        }
+    }
+    pairings
+}
+
+/// Pairs the unbeaten and the once-beaten among themselves
+///
+/// When one player is left in each group they meet in the final.
+pub fn double_elimination_pairings(
+    unbeaten: &[usize],
+    once_beaten: &[usize],
+    history: &PairingHistory,
+) -> RoundPairings {
+    if let ([first], [second]) = (unbeaten, once_beaten) {
+        return RoundPairings {
+            matches: vec![(*first, *second)],
+            byes: Vec::new(),
+        };
+    }
+
+    let mut pairings = pair_group(unbeaten, history);
+    let losers = pair_group(once_beaten, history);
+    pairings.matches.extend(losers.matches);
+    pairings.byes.extend(losers.byes);
+    pairings
+}
+
+/// Swiss pairings for a ranked field
+///
+/// Players are paired down the rankings with the closest-ranked opponent
+/// they haven't met; `king_of_the_hill` pairs strictly by rank instead.
+pub fn swiss_pairings(ranked: &[usize], history: &PairingHistory, king_of_the_hill: bool) -> RoundPairings {
+    if !king_of_the_hill {
+        return pair_group(ranked, history);
+    }
+
+    let (players, byes) = take_bye(ranked, history);
+    RoundPairings {
+        matches: players.chunks(2).map(|pair| (pair[0], pair[1])).collect(),
+        byes,
+    }
+}
+
+/// Rounds needed for everyone to meet everyone once
+pub fn round_robin_rounds(players: usize) -> usize {
+    if players.is_multiple_of(2) {
+        players.saturating_sub(1)
+    } else {
+        players
+    }
+}
+
+/// Round `round` (from 0) of a round robin, by the circle method
+///
+/// The first player stays put while the rest rotate; with an odd field
+/// whoever is drawn against the empty seat sits out.
+pub fn round_robin_pairings(players: &[usize], round: usize) -> RoundPairings {
+    let mut seats: Vec<Option<usize>> = players.iter().copied().map(Some).collect();
+    if seats.len() % 2 == 1 {
+        seats.push(None);
+    }
+    if seats.len() < 2 {
+        return RoundPairings::default();
+    }
+
+    let turn = round % (seats.len() - 1);
+    seats[1..].rotate_right(turn);
+    let mut pairings = RoundPairings::default();
+    for i in 0..seats.len() / 2 {
+        match (seats[i], seats[seats.len() - 1 - i]) {
+            (Some(first), Some(second)) => pairings.matches.push((first, second)),
+            (Some(player), None) | (None, Some(player)) => pairings.byes.push(player),
+            (None, None) => {}
+        }
+    }
+    pairings
+}
+
+/// Pairs a ranked group, avoiding rematches where the field allows it
+fn pair_group(ranked: &[usize], history: &PairingHistory) -> RoundPairings {
+    let (players, byes) = take_bye(ranked, history);
+    let mut budget = SEARCH_BUDGET;
+    let matches = pair_avoiding_rematches(&players, history, &mut budget)
+        .unwrap_or_else(|| players.chunks(2).map(|pair| (pair[0], pair[1])).collect());
+    RoundPairings { matches, byes }
+}
+
+/// With an odd group, the lowest-ranked player who hasn't had a bye sits out
+fn take_bye(ranked: &[usize], history: &PairingHistory) -> (Vec<usize>, Vec<usize>) {
+    let mut players = ranked.to_vec();
+    if players.len().is_multiple_of(2) {
+        return (players, Vec::new());
+    }
+
+    let position = players
+        .iter()
+        .rposition(|&player| !history.had_bye(player))
+        .unwrap_or(players.len() - 1);
+    let bye = players.remove(position);
+    (players, vec![bye])
+}
+
+/// Backtracking search for pairings with no rematches, best-ranked first
+fn pair_avoiding_rematches(
+    players: &[usize],
+    history: &PairingHistory,
+    budget: &mut usize,
+) -> Option<Vec<(usize, usize)>> {
+    let Some((&first, rest)) = players.split_first() else {
+        return Some(Vec::new());
+    };
+
+    for (i, &opponent) in rest.iter().enumerate() {
+        if *budget == 0 {
+            return None;
+        }
+        *budget -= 1;
+        if history.have_met(first, opponent) {
+            continue;
+        }
+
+        let remaining: Vec<usize> = rest.iter().enumerate().filter(|&(j, _)| j != i).map(|(_, &p)| p).collect();
+        if let Some(mut matches) = pair_avoiding_rematches(&remaining, history, budget) {
+            matches.insert(0, (first, opponent));
+            return Some(matches);
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_robin_meets_everyone_once() {
+        for size in [4, 5, 8] {
+            let players: Vec<usize> = (0..size).collect();
+            let mut history = PairingHistory::default();
+            for round in 0..round_robin_rounds(size) {
+                let pairings = round_robin_pairings(&players, round);
+                assert_eq!(pairings.byes.len(), size % 2);
+                for (first, second) in pairings.matches {
+                    assert!(!history.have_met(first, second));
+                    history.add_match(first, second);
+                }
+            }
+            assert_eq!(history.met.len(), size * (size - 1) / 2);
+        }
+    }
+
+    #[test]
+    fn test_swiss_avoids_rematches() {
+        let ranked = [0, 1, 2, 3];
+        let mut history = PairingHistory::default();
+        history.add_match(0, 1);
+        history.add_match(2, 3);
+
+        let pairings = swiss_pairings(&ranked, &history, false);
+        assert_eq!(pairings.matches, vec![(0, 2), (1, 3)]);
+
+        // King of the Hill pairs by rank even if they've met
+        let pairings = swiss_pairings(&ranked, &history, true);
+        assert_eq!(pairings.matches, vec![(0, 1), (2, 3)]);
+    }
+
+    #[test]
+    fn test_byes() {
+        let mut history = PairingHistory::default();
+        history.add_bye(4);
+        let pairings = swiss_pairings(&[0, 1, 2, 3, 4], &history, false);
+        assert_eq!(pairings.byes, vec![3]);
+
+        let pairings = elimination_pairings(&[Some(0), None, Some(3), Some(4)]);
+        assert_eq!(pairings.matches, vec![(3, 4)]);
+        assert_eq!(pairings.byes, vec![0]);
+
+        let pairings = double_elimination_pairings(&[0], &[1], &history);
+        assert_eq!(pairings.matches, vec![(0, 1)]);
+    }
+}
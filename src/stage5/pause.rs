@@ -2,6 +2,7 @@
 
 use bevy::prelude::*;
 use crate::plugins::state::GameState;
+use super::TournamentState;
 
 #[derive(Component)]
 pub struct PauseMenu;
@@ -55,6 +56,7 @@ pub fn handle_pause_menu_buttons(
     interaction_query: Query<(&Interaction, &PauseButton), Changed<Interaction>>,
     mut next_state: ResMut<NextState<GameState>>,
     pause_menu_query: Query<Entity, With<PauseMenu>>,
+    mut tournament_state: ResMut<TournamentState>,
 ) {
     for (interaction, button) in interaction_query.iter() {
         if *interaction == Interaction::Pressed {
@@ -64,7 +66,12 @@ pub fn handle_pause_menu_buttons(
 
             match button {
                 PauseButton::Resume => next_state.set(GameState::Stage5Playing),
-                PauseButton::Forfeit => next_state.set(GameState::MainMenu),
+                PauseButton::Forfeit => {
+                    // Forfeiting ends the tournament; quitting leaves it to come back to
+                    tournament_state.in_progress = false;
+                    super::save::delete_saved_tournament();
+                    next_state.set(GameState::MainMenu);
+                }
                 PauseButton::Quit => next_state.set(GameState::MainMenu),
             }
         }
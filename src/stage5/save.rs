@@ -0,0 +1,208 @@
+/// Saving and resuming tournaments
+///
+/// The tournament under way is written to `tournament.ron` in the user data
+/// dir whenever it changes, and the match game being played is saved with
+/// it after every turn. Quitting leaves the file behind for the main menu's
+/// Resume button; it is removed once the tournament is won or forfeited.
+
+use std::fs;
+use std::path::PathBuf;
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use super::{CurrentMatch, TournamentConfig, TournamentState};
+use crate::plugins::rng::GameRng;
+use crate::stage3::board::{Board, TileBag};
+use crate::stage3::{BoardGameSnapshot, Stage3Config, Stage3State, Turn};
+
+/// Everything needed to pick a tournament back up
+#[derive(Serialize, Deserialize)]
+pub struct TournamentSave {
+    pub config: TournamentConfig,
+    pub tournament: TournamentState,
+    pub current_match: CurrentMatch,
+    /// The match game being played, as of its last turn
+    pub game: Option<BoardGameSnapshot>,
+}
+
+/// Latest snapshot of the match game being played
+#[derive(Resource, Default)]
+pub struct MatchGameSnapshot(pub Option<BoardGameSnapshot>);
+
+/// Set by the main menu to load the saved tournament on entering Stage 5
+#[derive(Resource, Default)]
+pub struct ResumeRequest(pub bool);
+
+/// Where the tournament is saved
+pub fn save_path() -> Option<PathBuf> {
+    dirs::data_dir().map(|dir| dir.join("tilemania").join("tournament.ron"))
+}
+
+pub fn has_saved_tournament() -> bool {
+    save_path().is_some_and(|path| path.exists())
+}
+
+pub fn load_tournament() -> Result<TournamentSave, String> {
+    let path = save_path().ok_or("No data directory")?;
+    let contents = fs::read_to_string(&path)
+        .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+    ron::from_str(&contents).map_err(|e| format!("Failed to parse {}: {}", path.display(), e))
+}
+
+pub fn write_tournament(save: &TournamentSave) -> Result<(), String> {
+    let path = save_path().ok_or("No data directory")?;
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .map_err(|e| format!("Failed to create {}: {}", parent.display(), e))?;
+    }
+
+    let contents = ron::ser::to_string_pretty(save, ron::ser::PrettyConfig::default())
+        .map_err(|e| format!("Failed to serialize tournament: {}", e))?;
+    fs::write(&path, contents).map_err(|e| format!("Failed to write {}: {}", path.display(), e))
+}
+
+/// Removes the save once there's nothing left to resume
+pub fn delete_saved_tournament() {
+    if let Some(path) = save_path().filter(|path| path.exists()) {
+        if let Err(e) = fs::remove_file(&path) {
+            warn!("Failed to remove {}: {}", path.display(), e);
+        }
+    }
+}
+
+/// Writes the tournament as it stands, with the match game snapshot if one is under way
+pub fn save_tournament(
+    config: &TournamentConfig,
+    tournament_state: &TournamentState,
+    current_match: &CurrentMatch,
+    snapshot: &MatchGameSnapshot,
+) {
+    let save = TournamentSave {
+        config: *config,
+        tournament: tournament_state.clone(),
+        current_match: current_match.clone(),
+        game: snapshot.0.clone().filter(|_| current_match.game_in_progress),
+    };
+    if let Err(e) = write_tournament(&save) {
+        warn!("Tournament not saved: {}", e);
+    }
+}
+
+/// Load the saved tournament when the main menu asked to resume one
+pub fn resume_tournament(
+    mut request: ResMut<ResumeRequest>,
+    mut config: ResMut<TournamentConfig>,
+    mut tournament_state: ResMut<TournamentState>,
+    mut current_match: ResMut<CurrentMatch>,
+    mut snapshot: ResMut<MatchGameSnapshot>,
+) {
+    if !std::mem::take(&mut request.0) {
+        return;
+    }
+
+    match load_tournament() {
+        Ok(save) => {
+            info!("Resuming tournament ({}, round {})", save.tournament.format.name(), save.tournament.current_round.0);
+            *config = save.config;
+            *tournament_state = save.tournament;
+            tournament_state.in_progress = true;
+            *current_match = save.current_match;
+            snapshot.0 = save.game;
+        }
+        Err(e) => warn!("Couldn't resume tournament: {}", e),
+    }
+}
+
+/// Save the tournament whenever it changes
+pub fn autosave_tournament(
+    config: Res<TournamentConfig>,
+    tournament_state: Res<TournamentState>,
+    current_match: Res<CurrentMatch>,
+    snapshot: Res<MatchGameSnapshot>,
+) {
+    if !tournament_state.in_progress {
+        return;
+    }
+    if tournament_state.is_complete {
+        if tournament_state.is_changed() {
+            delete_saved_tournament();
+        }
+        return;
+    }
+    if config.is_changed() || tournament_state.is_changed() || current_match.is_changed() {
+        save_tournament(&config, &tournament_state, &current_match, &snapshot);
+    }
+}
+
+/// Save the match game being played after every turn
+#[allow(clippy::too_many_arguments)]
+pub fn snapshot_match_game(
+    stage3_config: Res<Stage3Config>,
+    state: Res<Stage3State>,
+    board: Res<Board>,
+    bag: Res<TileBag>,
+    rng: Res<GameRng>,
+    config: Res<TournamentConfig>,
+    tournament_state: Res<TournamentState>,
+    current_match: Res<CurrentMatch>,
+    mut snapshot: ResMut<MatchGameSnapshot>,
+    mut last_saved: Local<Option<(usize, bool)>>,
+) {
+    if !stage3_config.tournament_game || !current_match.game_in_progress || !state.is_active {
+        return;
+    }
+
+    // The clock ticks every frame; a new move or a change of turn is what's worth saving
+    let position = (state.moves_history.len(), state.current_turn == Turn::Player);
+    if *last_saved == Some(position) {
+        return;
+    }
+    *last_saved = Some(position);
+
+    snapshot.0 = Some(BoardGameSnapshot::capture(&state, &board, &bag, &rng));
+    save_tournament(&config, &tournament_state, &current_match, &snapshot);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stage3::board::PlacedTile;
+
+    #[test]
+    fn test_save_round_trip() {
+        let mut tournament = TournamentState::new();
+        tournament.record_result(1, vec![(400, 350), (380, 390), (420, 300)]);
+        let mut current_match = CurrentMatch::new(tournament.bracket[0].player2.clone(), tournament.bracket[0].player1.clone());
+        current_match.record_game(310, 290);
+        current_match.game_in_progress = true;
+
+        let state = Stage3State {
+            player_rack: vec!['Q', 'U', 'I'],
+            player_score: 42,
+            ..default()
+        };
+        let mut board = Board::default();
+        board.grid[7][7] = Some(PlacedTile { letter: 'Z', is_blank: true });
+        let bag = TileBag::default();
+
+        let save = TournamentSave {
+            config: TournamentConfig::default(),
+            tournament,
+            current_match,
+            game: Some(BoardGameSnapshot::capture(&state, &board, &bag, &GameRng::from_seed(9))),
+        };
+        let text = ron::ser::to_string(&save).unwrap();
+        let loaded: TournamentSave = ron::from_str(&text).unwrap();
+
+        assert_eq!(loaded.tournament.current_match_index, 1);
+        assert!(loaded.tournament.bracket[1].winner.is_some());
+        assert_eq!(loaded.current_match.games, vec![(310, 290)]);
+        assert!(loaded.current_match.game_in_progress);
+
+        let game = loaded.game.unwrap();
+        assert_eq!(game.seed, 9);
+        assert_eq!(game.state.player_rack, vec!['Q', 'U', 'I']);
+        assert_eq!(game.state.player_score, 42);
+        assert_eq!(game.board.grid[7][7], Some(PlacedTile { letter: 'Z', is_blank: true }));
+        assert_eq!(game.bag.tiles, bag.tiles);
+    }
+}
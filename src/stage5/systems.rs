@@ -1,19 +1,208 @@
 /// Core gameplay systems for Stage 5 (AI Tournaments)
 
 use bevy::prelude::*;
+use bevy::tasks::{block_on, poll_once, Task, TaskPool, TaskPoolBuilder};
 use super::{TournamentState, CurrentMatch, MatchResult};
-use super::components::*;
+use super::save::{save_tournament, MatchGameSnapshot};
+use super::tournament::{auto_player, simulate_match, swiss_rounds, TournamentConfig, TournamentFormat, MAX_FIELD_SIZE, MIN_FIELD_SIZE};
+use crate::lexicon::Lexicon;
+use crate::plugins::rating::PlayerRating;
+use crate::plugins::rng::GameRng;
+use crate::plugins::state::GameState;
+use crate::scoring::ScoreCalculator;
+use crate::stage3::challenge::ChallengeRule;
+use crate::stage3::leave::LeaveTable;
+use crate::stage3::{ResumeGame, Stage3Config, Stage3State};
 
-/// Handle match gameplay (reuses Stage 3 board logic)
+/// Games of a finished match, as (player 1, player 2) scores
+type MatchTask = Task<Vec<(u32, u32)>>;
+
+/// AI-vs-AI matches being played out in the background
+///
+/// They keep running while the player is in a game of their own.
+#[derive(Resource, Default)]
+pub struct SimulatedMatches {
+    /// Bracket index of each match and the task playing it
+    pub tasks: Vec<(usize, MatchTask)>,
+}
+
+/// Thread the AI-vs-AI matches are played on
+///
+/// Kept apart from the async compute pool, so a round of background
+/// matches never holds up the search of the AI the player is facing.
+#[derive(Resource)]
+pub struct BackgroundMatchPool(pub TaskPool);
+
+impl Default for BackgroundMatchPool {
+    fn default() -> Self {
+        Self(
+            TaskPoolBuilder::new()
+                .num_threads(1)
+                .thread_name("Background Matches".to_string())
+                .build(),
+        )
+    }
+}
+
+/// The player's own Stage 3 settings, put aside while a match game is played
+#[derive(Resource, Default)]
+pub struct SavedStage3Config(pub Option<Stage3Config>);
+
+/// Handle match gameplay: Enter plays the match's next game on the Stage 3 board
+///
+/// The board is set up with the opponent's difficulty and personality;
+/// undo and hints are off and plays can be challenged, as in any match. A game left unfinished carries
+/// on from its last saved turn.
 pub fn handle_match_gameplay(
-    current_match: Res<CurrentMatch>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut current_match: ResMut<CurrentMatch>,
+    mut config: ResMut<Stage3Config>,
+    mut saved_config: ResMut<SavedStage3Config>,
+    snapshot: Res<MatchGameSnapshot>,
+    mut resume: ResMut<ResumeGame>,
+    mut next_state: ResMut<NextState<GameState>>,
 ) {
-    if !current_match.is_active {
+    if !current_match.is_active || current_match.is_match_over() || !keyboard.just_pressed(KeyCode::Enter) {
         return;
     }
+    let Some(opponent) = current_match.opponent.clone() else {
+        return;
+    };
 
-    // Match gameplay handled by Stage 3 systems
-    // This system coordinates with board play
+    let match_config = Stage3Config {
+        difficulty: opponent.difficulty.clamp(1, 5),
+        time_limit_seconds: config.time_limit_seconds,
+        allow_hints: false,
+        allow_undo: false,
+        // Played under double challenge, as in club tournaments
+        challenge_rule: ChallengeRule::Double,
+        personality: opponent.personality,
+        tournament_game: true,
+    };
+    let own_config = std::mem::replace(&mut *config, match_config);
+    saved_config.0.get_or_insert(own_config);
+
+    info!("Game {} vs {}", current_match.games.len() + 1, opponent.name);
+    resume.0 = snapshot.0.clone().filter(|_| current_match.game_in_progress);
+    current_match.game_in_progress = true;
+    next_state.set(GameState::Stage3Playing);
+}
+
+/// Feed a finished match game's final scores into the match, and save it
+///
+/// The player's rating changes when the game decides the match.
+pub fn record_match_game(
+    state: Res<Stage3State>,
+    stage3_config: Res<Stage3Config>,
+    mut current_match: ResMut<CurrentMatch>,
+    mut snapshot: ResMut<MatchGameSnapshot>,
+    config: Res<TournamentConfig>,
+    tournament_state: Res<TournamentState>,
+    mut rating: ResMut<PlayerRating>,
+) {
+    // A practice game played after leaving a match game isn't part of the match
+    if !stage3_config.tournament_game || !current_match.game_in_progress || state.is_active {
+        return;
+    }
+
+    current_match.game_in_progress = false;
+    current_match.record_game(state.player_score, state.ai_score);
+    snapshot.0 = None;
+    save_tournament(&config, &tournament_state, &current_match, &snapshot);
+    info!(
+        "Match game over: {} - {} (games {} - {})",
+        state.player_score, state.ai_score, current_match.player_games_won, current_match.opponent_games_won
+    );
+
+    // The match is rated as a whole once it's decided
+    if let Some(opponent) = current_match.opponent.as_ref().filter(|_| current_match.is_match_over()) {
+        let score = if current_match.player_won_match() { 1.0 } else { 0.0 };
+        rating.record(&opponent.name, opponent.rating, score, true);
+        if let Err(e) = rating.save() {
+            warn!("Rating not saved: {}", e);
+        }
+    }
+}
+
+/// Keep a match game quit from the pause menu in progress
+///
+/// It carries on from its last saved turn when the tournament is picked up
+/// again; with no turn saved yet it is played again from the start.
+pub fn leave_match_game(
+    stage3_config: Res<Stage3Config>,
+    mut current_match: ResMut<CurrentMatch>,
+    snapshot: Res<MatchGameSnapshot>,
+    config: Res<TournamentConfig>,
+    tournament_state: Res<TournamentState>,
+) {
+    if !stage3_config.tournament_game || !current_match.game_in_progress {
+        return;
+    }
+
+    current_match.game_in_progress = snapshot.0.is_some();
+    save_tournament(&config, &tournament_state, &current_match, &snapshot);
+    info!("Match game left unfinished; it will carry on when the tournament is resumed");
+}
+
+/// Put the player's own Stage 3 settings back once a match game is left
+pub fn restore_stage3_config(
+    mut config: ResMut<Stage3Config>,
+    mut saved_config: ResMut<SavedStage3Config>,
+) {
+    if let Some(own_config) = saved_config.0.take() {
+        *config = own_config;
+    }
+}
+
+/// Play the current round's AI-vs-AI matches in the background and record them as they finish
+pub fn run_simulated_matches(
+    mut simulations: ResMut<SimulatedMatches>,
+    pool: Res<BackgroundMatchPool>,
+    mut tournament_state: ResMut<TournamentState>,
+    lexicon: Option<Res<Lexicon>>,
+    leaves: Option<Res<LeaveTable>>,
+    scorer: Res<ScoreCalculator>,
+    mut rng: ResMut<GameRng>,
+) {
+    let mut finished = Vec::new();
+    simulations.tasks.retain_mut(|(index, task)| match block_on(poll_once(task)) {
+        Some(games) => {
+            finished.push((*index, games));
+            false
+        }
+        None => true,
+    });
+    for (index, games) in finished {
+        tournament_state.record_result(index, games);
+    }
+
+    let (Some(lexicon), Some(leaves)) = (lexicon, leaves) else {
+        return;
+    };
+    if tournament_state.is_complete {
+        return;
+    }
+
+    for index in tournament_state.round_matches() {
+        let bracket_match = &tournament_state.bracket[index];
+        let started = simulations.tasks.iter().any(|(running, _)| *running == index);
+        if bracket_match.winner.is_some() || bracket_match.involves_human() || started {
+            continue;
+        }
+
+        let players = [auto_player(&bracket_match.player1), auto_player(&bracket_match.player2)];
+        let best_of = bracket_match.best_of;
+        let lexicon = lexicon.clone();
+        let scorer = scorer.clone();
+        let leaves = leaves.clone();
+        let mut match_rng = rng.fork();
+        info!("Simulating {} vs {}", bracket_match.player1.name, bracket_match.player2.name);
+
+        let task = pool.0.spawn(async move {
+            simulate_match(players, best_of, &lexicon, &scorer, &leaves, &mut match_rng)
+        });
+        simulations.tasks.push((index, task));
+    }
 }
 
 /// Process match result when game ends
@@ -21,73 +210,126 @@ pub fn process_match_result(
     mut current_match: ResMut<CurrentMatch>,
     mut match_results: EventWriter<MatchResult>,
 ) {
-    if !current_match.is_active {
+    if !current_match.is_active || !current_match.is_match_over() {
         return;
     }
 
-    // Check if current game is over
-    // (Simplified - would check board state in real implementation)
-
-    // Determine game winner and update match score
-    // Send MatchResult event when best-of-3 is complete
+    let (player_score, opponent_score) = current_match
+        .games
+        .iter()
+        .fold((0, 0), |(a, b), &(player, opponent)| (a + player, b + opponent));
+    match_results.send(MatchResult {
+        player_won: current_match.player_won_match(),
+        player_score,
+        opponent_score,
+    });
+    current_match.is_active = false;
 }
 
 /// Advance tournament bracket
 pub fn advance_tournament(
     mut tournament_state: ResMut<TournamentState>,
     mut match_results: EventReader<MatchResult>,
+    current_match: Res<CurrentMatch>,
 ) {
     for result in match_results.read() {
-        if result.player_won {
-            // Player advances
-            tournament_state.advance_match();
-        } else {
-            // Player eliminated
-            tournament_state.is_complete = true;
-        }
+        let Some(index) = current_match.bracket_index else {
+            continue;
+        };
+        info!(
+            "Match over: {} ({} - {})",
+            if result.player_won { "you won" } else { "you lost" },
+            result.player_score,
+            result.opponent_score
+        );
 
-        // Check if round is complete
-        if tournament_state.is_round_complete() {
-            advance_to_next_round(&mut tournament_state);
-        }
+        // The bracket keeps scores as (player 1, player 2)
+        let human_first = tournament_state.bracket[index].player1.is_human;
+        let games = current_match
+            .games
+            .iter()
+            .map(|&(player, opponent)| if human_first { (player, opponent) } else { (opponent, player) })
+            .collect();
+        tournament_state.record_result(index, games);
     }
 }
 
-/// Check if tournament is complete
+/// Leave a finished tournament with Enter
 pub fn check_tournament_complete(
-    tournament_state: Res<TournamentState>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut tournament_state: ResMut<TournamentState>,
+    mut next_state: ResMut<NextState<GameState>>,
 ) {
-    if tournament_state.is_complete {
-        // Show victory/defeat screen
+    if tournament_state.is_complete && keyboard.just_pressed(KeyCode::Enter) {
+        tournament_state.in_progress = false;
+        next_state.set(GameState::MainMenu);
     }
 }
 
-/// Start next match
-pub fn start_next_match(
-    mut commands: Commands,
-    tournament_state: Res<TournamentState>,
+/// Choose the format (F) and field size (Left/Right) until the player's first game
+///
+/// Any change starts the tournament over with the new settings.
+pub fn handle_format_keys(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut config: ResMut<TournamentConfig>,
+    mut tournament_state: ResMut<TournamentState>,
     mut current_match: ResMut<CurrentMatch>,
+    mut simulations: ResMut<SimulatedMatches>,
+    mut snapshot: ResMut<MatchGameSnapshot>,
+    rating: Res<PlayerRating>,
 ) {
-    // Get next opponent from bracket
-    let opponent_index = tournament_state.current_match_index + 1;
+    if tournament_state.human_has_played() || !current_match.games.is_empty() {
+        return;
+    }
 
-    if let Some(opponent) = tournament_state.players.get(opponent_index) {
-        *current_match = CurrentMatch::new(
-            tournament_state.players[0].clone(), // Player is always index 0
-            opponent.clone(),
-        );
+    let mut new_config = *config;
+    if keyboard.just_pressed(KeyCode::KeyF) {
+        new_config.format = config.format.cycle(config.field_size);
+    }
+    if keyboard.just_pressed(KeyCode::ArrowRight) {
+        new_config.field_size = (config.field_size + 1).min(MAX_FIELD_SIZE);
+    }
+    if keyboard.just_pressed(KeyCode::ArrowLeft) {
+        new_config.field_size = config.field_size.saturating_sub(1).max(MIN_FIELD_SIZE);
+    }
+    if new_config == *config {
+        return;
+    }
+    if let TournamentFormat::Swiss { .. } = new_config.format {
+        new_config.format = TournamentFormat::Swiss { rounds: swiss_rounds(new_config.field_size) };
     }
-}
 
-fn advance_to_next_round(tournament_state: &mut TournamentState) {
-    use TournamentRound::*;
+    info!("Tournament: {}, {} players", new_config.format.name(), new_config.field_size);
+    *config = new_config;
+    *tournament_state = TournamentState::with_config(&config, rating.display());
+    tournament_state.in_progress = true;
+    *current_match = CurrentMatch::default();
+    // Dropping the tasks cancels the old tournament's matches
+    *simulations = SimulatedMatches::default();
+    snapshot.0 = None;
+}
 
-    match tournament_state.current_round {
-        Quarterfinals => tournament_state.start_round(Semifinals),
-        Semifinals => tournament_state.start_round(Finals),
-        Finals => {
-            tournament_state.is_complete = true;
-            // Set champion
-        }
+/// Start next match: sets up the player's match in the current round
+pub fn start_next_match(
+    tournament_state: Res<TournamentState>,
+    mut current_match: ResMut<CurrentMatch>,
+) {
+    let Some(index) = tournament_state.human_match() else {
+        return;
+    };
+    if current_match.bracket_index == Some(index) {
+        return;
     }
+
+    let bracket_match = &tournament_state.bracket[index];
+    let (player, opponent) = if bracket_match.player1.is_human {
+        (&bracket_match.player1, &bracket_match.player2)
+    } else {
+        (&bracket_match.player2, &bracket_match.player1)
+    };
+    info!("Next match: {} vs {} ({})", player.name, opponent.name, tournament_state.round_name(bracket_match.round));
+
+    *current_match = CurrentMatch::new(player.clone(), opponent.clone());
+    current_match.bracket_index = Some(index);
+    current_match.best_of = bracket_match.best_of;
 }
@@ -1,18 +1,134 @@
-/// Tournament bracket management
+/// Tournament management
+///
+/// A tournament is played in rounds. Each round's pairings come from the
+/// format: elimination brackets seed the field so the top seeds can only
+/// meet late (1 v 8, 4 v 5, 2 v 7, 3 v 6), double elimination knocks a
+/// player out on their second loss, and Swiss and round robin run a set
+/// number of rounds with players ranked by wins, then spread.
 
+use std::cmp::Reverse;
 use bevy::prelude::*;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
 use super::components::*;
 use super::ai_personality::*;
+use super::pairing::{self, PairingHistory, RoundPairings};
+use crate::lexicon::Lexicon;
+use crate::plugins::rating::INITIAL_RATING;
+use crate::scoring::ScoreCalculator;
+use crate::stage3::autoplay::{play_game, AutoPlayer};
+use crate::stage3::leave::LeaveTable;
+
+/// Games in a match unless configured otherwise
+pub const DEFAULT_BEST_OF: u32 = 3;
+
+/// Smallest and largest fields a tournament can have
+pub const MIN_FIELD_SIZE: usize = 4;
+pub const MAX_FIELD_SIZE: usize = AI_ROSTER.len() + 1;
+
+/// Spread credited for a bye in Swiss and round robin, which also counts as a win
+pub const BYE_SPREAD: i32 = 50;
+
+/// AI entrants: name, level, style and published rating
+const AI_ROSTER: [(&str, u8, AIPersonality, u32); 15] = [
+    ("Rookie Rita", 2, AIPersonality::Defensive, 1150),
+    ("Balanced Bob", 3, AIPersonality::Balanced, 1320),
+    ("Aggro Alex", 3, AIPersonality::Aggressive, 1380),
+    ("Strategic Sam", 4, AIPersonality::Balanced, 1520),
+    ("Vocab Victor", 4, AIPersonality::Aggressive, 1580),
+    ("Master Maya", 5, AIPersonality::Balanced, 1740),
+    ("Champion Chen", 5, AIPersonality::Aggressive, 1820),
+    ("Steady Sara", 2, AIPersonality::Balanced, 1100),
+    ("Cautious Carl", 2, AIPersonality::Defensive, 1060),
+    ("Bingo Ben", 3, AIPersonality::Aggressive, 1350),
+    ("Quiet Quinn", 3, AIPersonality::Defensive, 1280),
+    ("Rack Rosa", 4, AIPersonality::Defensive, 1490),
+    ("Tactical Tom", 4, AIPersonality::Balanced, 1550),
+    ("Bold Bella", 5, AIPersonality::Aggressive, 1700),
+    ("Grandmaster Grace", 5, AIPersonality::Defensive, 1780),
+];
+
+/// How the tournament is run
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TournamentFormat {
+    SingleElimination,
+    DoubleElimination,
+    /// A set number of rounds, the last one King of the Hill
+    Swiss { rounds: u32 },
+    RoundRobin,
+}
+
+impl TournamentFormat {
+    pub fn name(self) -> String {
+        match self {
+            TournamentFormat::SingleElimination => "Single elimination".to_string(),
+            TournamentFormat::DoubleElimination => "Double elimination".to_string(),
+            TournamentFormat::Swiss { rounds } => format!("Swiss, {} rounds", rounds),
+            TournamentFormat::RoundRobin => "Round robin".to_string(),
+        }
+    }
+
+    /// The format after this one, for choosing one from a list
+    pub fn cycle(self, field_size: usize) -> Self {
+        match self {
+            TournamentFormat::SingleElimination => TournamentFormat::DoubleElimination,
+            TournamentFormat::DoubleElimination => TournamentFormat::Swiss { rounds: swiss_rounds(field_size) },
+            TournamentFormat::Swiss { .. } => TournamentFormat::RoundRobin,
+            TournamentFormat::RoundRobin => TournamentFormat::SingleElimination,
+        }
+    }
+
+    /// Ranked by standings rather than knocked out
+    pub fn uses_standings(self) -> bool {
+        matches!(self, TournamentFormat::Swiss { .. } | TournamentFormat::RoundRobin)
+    }
+}
+
+/// Usual number of Swiss rounds for a field: enough to separate the top, plus one
+pub fn swiss_rounds(field_size: usize) -> u32 {
+    let rounds = field_size.next_power_of_two().trailing_zeros() + 1;
+    rounds.min(field_size.saturating_sub(1) as u32).max(1)
+}
+
+/// Settings for the next tournament
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct TournamentConfig {
+    pub format: TournamentFormat,
+    /// Entrants, including the player
+    pub field_size: usize,
+    pub best_of: u32,
+}
+
+impl Default for TournamentConfig {
+    fn default() -> Self {
+        Self {
+            format: TournamentFormat::SingleElimination,
+            field_size: 8,
+            best_of: DEFAULT_BEST_OF,
+        }
+    }
+}
 
 /// Tournament state resource
-#[derive(Resource)]
+#[derive(Resource, Clone, Serialize, Deserialize)]
 pub struct TournamentState {
+    pub format: TournamentFormat,
     pub current_round: TournamentRound,
+    /// Matches decided so far in the current round
     pub current_match_index: usize,
     pub players: Vec<TournamentPlayer>,
+    /// Every match scheduled so far, round by round
     pub bracket: Vec<BracketMatch>,
+    /// Players who sat out a round, by name
+    pub byes: Vec<(TournamentRound, String)>,
+    /// Single elimination: who holds each position still in play (None = empty)
+    pub slots: Vec<Option<String>>,
+    /// Games in each match
+    pub best_of: u32,
     pub is_complete: bool,
     pub champion: Option<TournamentPlayer>,
+    /// Whether a tournament has been started and not yet left
+    pub in_progress: bool,
 }
 
 impl Default for TournamentState {
@@ -22,7 +138,14 @@ impl Default for TournamentState {
 }
 
 impl TournamentState {
+    /// An 8-player single elimination tournament for a new player
     pub fn new() -> Self {
+        Self::with_config(&TournamentConfig::default(), INITIAL_RATING as u32)
+    }
+
+    /// A tournament for a player rated `human_rating`, against the personas closest to it
+    pub fn with_config(config: &TournamentConfig, human_rating: u32) -> Self {
+        let field_size = config.field_size.clamp(MIN_FIELD_SIZE, MAX_FIELD_SIZE);
         let mut players = vec![
             // Player (human)
             TournamentPlayer {
@@ -30,41 +153,81 @@ impl TournamentState {
                 is_human: true,
                 difficulty: 0,
                 personality: None,
+                rating: human_rating,
                 wins: 0,
                 losses: 0,
+                seed: 0,
+                spread: 0,
             },
         ];
 
-        // Add 7 AI opponents with varying difficulties and personalities
-        let ai_opponents = [
-            ("Rookie Rita", 2, AIPersonality::Defensive),
-            ("Balanced Bob", 3, AIPersonality::Balanced),
-            ("Aggro Alex", 3, AIPersonality::Aggressive),
-            ("Strategic Sam", 4, AIPersonality::Balanced),
-            ("Vocab Victor", 4, AIPersonality::Aggressive),
-            ("Master Maya", 5, AIPersonality::Balanced),
-            ("Champion Chen", 5, AIPersonality::Aggressive),
-        ];
+        // Matchmaking: the AI opponents nearest the player's rating
+        let mut entrants: Vec<usize> = (0..AI_ROSTER.len()).collect();
+        entrants.sort_by_key(|&index| AI_ROSTER[index].3.abs_diff(human_rating));
+        entrants.truncate(field_size - 1);
+        entrants.sort_unstable();
 
-        for (name, difficulty, personality) in &ai_opponents {
+        for (name, difficulty, personality, rating) in entrants.into_iter().map(|index| AI_ROSTER[index]) {
             players.push(TournamentPlayer {
                 name: name.to_string(),
                 is_human: false,
-                difficulty: *difficulty,
-                personality: Some(*personality),
+                difficulty,
+                personality: Some(personality),
+                rating,
                 wins: 0,
                 losses: 0,
+                seed: 0,
+                spread: 0,
             });
         }
 
-        Self {
-            current_round: TournamentRound::Quarterfinals,
+        // Swiss can't go on longer than it takes everyone to meet
+        let format = match config.format {
+            TournamentFormat::Swiss { rounds } => TournamentFormat::Swiss {
+                rounds: rounds.clamp(1, field_size as u32 - 1),
+            },
+            format => format,
+        };
+
+        let mut state = Self {
+            format,
+            current_round: TournamentRound::default(),
             current_match_index: 0,
             players,
             bracket: Vec::new(),
+            byes: Vec::new(),
+            slots: Vec::new(),
+            best_of: config.best_of.max(1),
             is_complete: false,
             champion: None,
+            in_progress: false,
+        };
+        state.seed_bracket();
+        state
+    }
+
+    /// Seeds the players by rating and pairs the first round
+    ///
+    /// The human goes below an AI on the same rating.
+    pub fn seed_bracket(&mut self) {
+        let mut order: Vec<usize> = (0..self.players.len()).collect();
+        order.sort_by_key(|&index| (Reverse(self.players[index].rating), self.players[index].is_human));
+        for (rank, &index) in order.iter().enumerate() {
+            self.players[index].seed = rank as u32 + 1;
         }
+
+        // Top seeds get the byes when the field doesn't fill the bracket
+        let size = self.players.len();
+        self.slots = bracket_order(size.next_power_of_two())
+            .into_iter()
+            .map(|seed| (seed <= size).then(|| self.players[order[seed - 1]].name.clone()))
+            .collect();
+        self.bracket.clear();
+        self.byes.clear();
+        self.current_round = TournamentRound::default();
+        self.is_complete = false;
+        self.champion = None;
+        self.next_round();
     }
 
     pub fn start_round(&mut self, round: TournamentRound) {
@@ -77,26 +240,395 @@ impl TournamentState {
     }
 
     pub fn is_round_complete(&self) -> bool {
-        match self.current_round {
-            TournamentRound::Quarterfinals => self.current_match_index >= 4,
-            TournamentRound::Semifinals => self.current_match_index >= 2,
-            TournamentRound::Finals => self.current_match_index >= 1,
+        self.current_match_index >= self.round_matches().len()
+    }
+
+    /// Indices into `bracket` of the current round's matches, in pairing order
+    pub fn round_matches(&self) -> Vec<usize> {
+        (0..self.bracket.len())
+            .filter(|&index| self.bracket[index].round == self.current_round)
+            .collect()
+    }
+
+    /// The human's undecided match in the current round, if they're still in
+    pub fn human_match(&self) -> Option<usize> {
+        self.round_matches().into_iter().find(|&index| {
+            let bracket_match = &self.bracket[index];
+            bracket_match.involves_human() && bracket_match.winner.is_none()
+        })
+    }
+
+    /// Whether the human has finished a match or has one under way
+    pub fn human_has_played(&self) -> bool {
+        self.bracket
+            .iter()
+            .any(|bracket_match| bracket_match.involves_human() && (bracket_match.winner.is_some() || !bracket_match.games.is_empty()))
+    }
+
+    /// Records a finished match, pairing the next round once this one is done
+    ///
+    /// `games` holds each game's final scores as (player 1, player 2).
+    pub fn record_result(&mut self, index: usize, games: Vec<(u32, u32)>) {
+        let Some(bracket_match) = self.bracket.get_mut(index) else {
+            return;
+        };
+        if bracket_match.winner.is_some() {
+            return;
+        }
+        bracket_match.games = games;
+        let winner = bracket_match.decide_winner();
+        let loser = if winner.name == bracket_match.player1.name {
+            bracket_match.player2.name.clone()
+        } else {
+            bracket_match.player1.name.clone()
+        };
+        let first_spread = bracket_match
+            .games
+            .iter()
+            .map(|&(first, second)| first as i32 - second as i32)
+            .sum::<i32>();
+        let (first, second) = (bracket_match.player1.name.clone(), bracket_match.player2.name.clone());
+        bracket_match.winner = Some(winner.clone());
+        info!("🏆 {} beats {} (round {})", winner.name, loser, bracket_match.round.0);
+
+        for player in self.players.iter_mut() {
+            if player.name == winner.name {
+                player.wins += 1;
+            } else if player.name == loser {
+                player.losses += 1;
+            }
+            if player.name == first {
+                player.spread += first_spread;
+            } else if player.name == second {
+                player.spread -= first_spread;
+            }
+        }
+
+        self.advance_match();
+        if self.is_round_complete() {
+            self.next_round();
+        }
+    }
+
+    /// Pairs the next round, or crowns the champion when the format is done
+    fn next_round(&mut self) {
+        let round = self.current_round.next();
+        let pairings = match self.format {
+            TournamentFormat::SingleElimination => {
+                if round.0 > 1 {
+                    self.slots = self.advance_slots();
+                }
+                let slots: Vec<Option<usize>> = self
+                    .slots
+                    .iter()
+                    .map(|slot| slot.as_ref().and_then(|name| self.player_index(name)))
+                    .collect();
+                pairing::elimination_pairings(&slots)
+            }
+            TournamentFormat::DoubleElimination => {
+                let ranked = self.ranked();
+                let unbeaten: Vec<usize> = ranked.iter().copied().filter(|&i| self.players[i].losses == 0).collect();
+                let once_beaten: Vec<usize> = ranked.iter().copied().filter(|&i| self.players[i].losses == 1).collect();
+                pairing::double_elimination_pairings(&unbeaten, &once_beaten, &self.pairing_history())
+            }
+            TournamentFormat::Swiss { rounds } if round.0 <= rounds => {
+                pairing::swiss_pairings(&self.ranked(), &self.pairing_history(), round.0 == rounds)
+            }
+            TournamentFormat::RoundRobin if (round.0 as usize) <= pairing::round_robin_rounds(self.players.len()) => {
+                pairing::round_robin_pairings(&self.seeded(), round.0 as usize - 1)
+            }
+            TournamentFormat::Swiss { .. } | TournamentFormat::RoundRobin => RoundPairings::default(),
+        };
+
+        if pairings.matches.is_empty() {
+            self.crown_champion();
+        } else {
+            self.schedule_round(round, pairings);
+        }
+    }
+
+    /// Adds a round's matches and byes to the bracket
+    fn schedule_round(&mut self, round: TournamentRound, pairings: RoundPairings) {
+        for (first, second) in pairings.matches {
+            let mut bracket_match = BracketMatch::new(round, self.players[first].clone(), self.players[second].clone());
+            bracket_match.best_of = self.best_of;
+            self.bracket.push(bracket_match);
+        }
+
+        let bye_counts_as_win = self.format.uses_standings();
+        for player in pairings.byes {
+            let player = &mut self.players[player];
+            if bye_counts_as_win {
+                player.wins += 1;
+                player.spread += BYE_SPREAD;
+            }
+            self.byes.push((round, player.name.clone()));
+        }
+        self.start_round(round);
+    }
+
+    fn crown_champion(&mut self) {
+        let champion = match self.format {
+            TournamentFormat::SingleElimination => self.slots.iter().flatten().next().and_then(|name| self.player(name)),
+            TournamentFormat::DoubleElimination => self.ranked().into_iter().map(|i| &self.players[i]).find(|p| p.losses < 2),
+            TournamentFormat::Swiss { .. } | TournamentFormat::RoundRobin => self.standings().into_iter().next(),
+        }
+        .cloned();
+
+        if let Some(champion) = &champion {
+            info!("👑 {} wins the tournament", champion.name);
         }
+        self.champion = champion;
+        self.is_complete = true;
     }
+
+    /// Bracket positions after the current round: each pair's winner moves on
+    fn advance_slots(&self) -> Vec<Option<String>> {
+        self.slots
+            .chunks(2)
+            .map(|pair| match pair {
+                [Some(first), Some(_)] => self
+                    .round_matches()
+                    .into_iter()
+                    .map(|index| &self.bracket[index])
+                    .find(|m| m.player1.name == *first || m.player2.name == *first)
+                    .and_then(|m| m.winner.as_ref().map(|winner| winner.name.clone())),
+                [Some(player), None] | [None, Some(player)] | [Some(player)] => Some(player.clone()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Meetings and byes so far, by player index
+    fn pairing_history(&self) -> PairingHistory {
+        let mut history = PairingHistory::default();
+        for bracket_match in &self.bracket {
+            if let (Some(first), Some(second)) = (
+                self.player_index(&bracket_match.player1.name),
+                self.player_index(&bracket_match.player2.name),
+            ) {
+                history.add_match(first, second);
+            }
+        }
+        for (_, name) in &self.byes {
+            if let Some(player) = self.player_index(name) {
+                history.add_bye(player);
+            }
+        }
+        history
+    }
+
+    /// Player indices by seed
+    fn seeded(&self) -> Vec<usize> {
+        let mut order: Vec<usize> = (0..self.players.len()).collect();
+        order.sort_by_key(|&index| self.players[index].seed);
+        order
+    }
+
+    /// Player indices by wins, then spread, then seed
+    fn ranked(&self) -> Vec<usize> {
+        let mut order = self.seeded();
+        order.sort_by_key(|&index| {
+            let player = &self.players[index];
+            (Reverse(player.wins), Reverse(player.spread))
+        });
+        order
+    }
+
+    /// Players by wins, then spread, then seed
+    pub fn standings(&self) -> Vec<&TournamentPlayer> {
+        self.ranked().into_iter().map(|index| &self.players[index]).collect()
+    }
+
+    /// Whether a player has been knocked out (never, in Swiss and round robin)
+    pub fn is_eliminated(&self, name: &str) -> bool {
+        let Some(player) = self.player(name) else {
+            return false;
+        };
+        match self.format {
+            TournamentFormat::SingleElimination => player.losses > 0,
+            TournamentFormat::DoubleElimination => player.losses > 1,
+            TournamentFormat::Swiss { .. } | TournamentFormat::RoundRobin => false,
+        }
+    }
+
+    /// Rounds the tournament will run, where the format fixes it
+    pub fn total_rounds(&self) -> Option<u32> {
+        match self.format {
+            TournamentFormat::SingleElimination => Some(self.players.len().next_power_of_two().trailing_zeros()),
+            TournamentFormat::DoubleElimination => None,
+            TournamentFormat::Swiss { rounds } => Some(rounds),
+            TournamentFormat::RoundRobin => Some(pairing::round_robin_rounds(self.players.len()) as u32),
+        }
+    }
+
+    /// Whether `round` decides the tournament
+    ///
+    /// In double elimination that's any round with a single match and nobody sitting out.
+    pub fn is_final_round(&self, round: TournamentRound) -> bool {
+        match self.total_rounds() {
+            Some(total) => round.0 == total,
+            None => {
+                let matches = self.bracket.iter().filter(|m| m.round == round).count();
+                let byes = self.byes.iter().filter(|(r, _)| *r == round).count();
+                matches == 1 && byes == 0
+            }
+        }
+    }
+
+    pub fn round_name(&self, round: TournamentRound) -> String {
+        match self.format {
+            TournamentFormat::SingleElimination => {
+                let players_left = self.players.len().next_power_of_two() >> round.0.saturating_sub(1);
+                match players_left {
+                    2 => "Final".to_string(),
+                    4 => "Semifinals".to_string(),
+                    8 => "Quarterfinals".to_string(),
+                    n => format!("Round of {}", n),
+                }
+            }
+            TournamentFormat::DoubleElimination if self.is_final_round(round) => "Final".to_string(),
+            TournamentFormat::DoubleElimination => format!("Round {}", round.0),
+            TournamentFormat::Swiss { rounds } if round.0 == rounds => {
+                format!("Round {} of {} (King of the Hill)", round.0, rounds)
+            }
+            TournamentFormat::Swiss { .. } | TournamentFormat::RoundRobin => {
+                format!("Round {} of {}", round.0, self.total_rounds().unwrap_or(round.0))
+            }
+        }
+    }
+
+    /// Current entry for a player, by name (bracket entries are snapshots)
+    pub fn player(&self, name: &str) -> Option<&TournamentPlayer> {
+        self.players.iter().find(|player| player.name == name)
+    }
+
+    fn player_index(&self, name: &str) -> Option<usize> {
+        self.players.iter().position(|player| player.name == name)
+    }
+}
+
+/// Seed numbers in bracket order for a bracket of `size` (a power of two)
+///
+/// Neighbouring pairs meet in the first round: 1 v 8, 4 v 5, 2 v 7, 3 v 6.
+pub fn bracket_order(size: usize) -> Vec<usize> {
+    let mut order = vec![1];
+    while order.len() < size {
+        let next_size = order.len() * 2;
+        order = order.iter().flat_map(|&seed| [seed, next_size + 1 - seed]).collect();
+    }
+    order
 }
 
 /// Individual bracket match
-#[derive(Clone)]
+#[derive(Clone, Serialize, Deserialize)]
 pub struct BracketMatch {
     pub round: TournamentRound,
     pub player1: TournamentPlayer,
     pub player2: TournamentPlayer,
     pub winner: Option<TournamentPlayer>,
     pub best_of: u32, // Best-of-3
+    /// Final scores of each game played, as (player 1, player 2)
+    pub games: Vec<(u32, u32)>,
+}
+
+impl BracketMatch {
+    pub fn new(round: TournamentRound, player1: TournamentPlayer, player2: TournamentPlayer) -> Self {
+        Self {
+            round,
+            player1,
+            player2,
+            winner: None,
+            best_of: DEFAULT_BEST_OF,
+            games: Vec::new(),
+        }
+    }
+
+    /// Games won by each player (tied games count for neither)
+    pub fn games_won(&self) -> (u32, u32) {
+        games_won(&self.games)
+    }
+
+    pub fn involves_human(&self) -> bool {
+        self.player1.is_human || self.player2.is_human
+    }
+
+    /// Whoever won more games; if level, more total points, then the higher seed
+    fn decide_winner(&self) -> TournamentPlayer {
+        let (first_wins, second_wins) = self.games_won();
+        let (first_points, second_points) = self
+            .games
+            .iter()
+            .fold((0, 0), |(a, b), &(first, second)| (a + first, b + second));
+
+        let first_wins_match = (first_wins, first_points, Reverse(self.player1.seed))
+            >= (second_wins, second_points, Reverse(self.player2.seed));
+        if first_wins_match {
+            self.player1.clone()
+        } else {
+            self.player2.clone()
+        }
+    }
+}
+
+/// Games won by each side of `games` (tied games count for neither)
+pub fn games_won(games: &[(u32, u32)]) -> (u32, u32) {
+    games.iter().fold((0, 0), |(first, second), &(a, b)| match a.cmp(&b) {
+        std::cmp::Ordering::Greater => (first + 1, second),
+        std::cmp::Ordering::Less => (first, second + 1),
+        std::cmp::Ordering::Equal => (first, second),
+    })
+}
+
+/// Games needed to take a best-of-`best_of` match
+pub fn wins_needed(best_of: u32) -> u32 {
+    best_of / 2 + 1
+}
+
+/// Someone has the games they need, or the tie replays (up to twice the scheduled games) have run out
+pub fn is_match_decided(games: &[(u32, u32)], best_of: u32) -> bool {
+    let (first_wins, second_wins) = games_won(games);
+    first_wins >= wins_needed(best_of)
+        || second_wins >= wins_needed(best_of)
+        || games.len() >= (best_of * 2) as usize
+}
+
+/// Stage 3 engine settings for an AI tournament player
+pub fn auto_player(player: &TournamentPlayer) -> AutoPlayer {
+    AutoPlayer {
+        level: player.difficulty.clamp(1, 5),
+        personality: player.personality,
+    }
+}
+
+/// Plays out a match between two AIs, alternating who moves first
+///
+/// Tied games are replayed, up to twice the scheduled number of games.
+pub fn simulate_match(
+    players: [AutoPlayer; 2],
+    best_of: u32,
+    lexicon: &Lexicon,
+    scorer: &ScoreCalculator,
+    leaves: &LeaveTable,
+    rng: &mut impl Rng,
+) -> Vec<(u32, u32)> {
+    let mut games = Vec::new();
+    while !is_match_decided(&games, best_of) {
+        let game = if games.len() % 2 == 0 {
+            let [first, second] = play_game(players, lexicon, scorer, leaves, rng);
+            (first, second)
+        } else {
+            let [second, first] = play_game([players[1], players[0]], lexicon, scorer, leaves, rng);
+            (first, second)
+        };
+        games.push(game);
+    }
+
+    games
 }
 
 /// Current match state
-#[derive(Resource, Default)]
+#[derive(Resource, Default, Clone, Serialize, Deserialize)]
 pub struct CurrentMatch {
     pub player: Option<TournamentPlayer>,
     pub opponent: Option<TournamentPlayer>,
@@ -105,6 +637,14 @@ pub struct CurrentMatch {
     pub current_game_score_player: u32,
     pub current_game_score_opponent: u32,
     pub is_active: bool,
+    /// The bracket match being played
+    pub bracket_index: Option<usize>,
+    /// Final scores of each game played, as (player, opponent)
+    pub games: Vec<(u32, u32)>,
+    /// Games scheduled (the bracket match's `best_of`)
+    pub best_of: u32,
+    /// A game of this match is being played on the Stage 3 board
+    pub game_in_progress: bool,
 }
 
 impl CurrentMatch {
@@ -117,14 +657,212 @@ impl CurrentMatch {
             current_game_score_player: 0,
             current_game_score_opponent: 0,
             is_active: true,
+            bracket_index: None,
+            games: Vec::new(),
+            best_of: DEFAULT_BEST_OF,
+            game_in_progress: false,
         }
     }
 
+    /// Adds a finished game's final scores
+    pub fn record_game(&mut self, player_score: u32, opponent_score: u32) {
+        self.current_game_score_player = player_score;
+        self.current_game_score_opponent = opponent_score;
+        self.games.push((player_score, opponent_score));
+        (self.player_games_won, self.opponent_games_won) = games_won(&self.games);
+    }
+
+    /// Someone has the games they need, or the tie replays have run out
     pub fn is_match_over(&self) -> bool {
-        self.player_games_won >= 2 || self.opponent_games_won >= 2
+        is_match_decided(&self.games, self.best_of)
     }
 
+    /// Decided like a bracket match: games, then points, then the higher seed
     pub fn player_won_match(&self) -> bool {
-        self.player_games_won >= 2
+        let (player_points, opponent_points) = self
+            .games
+            .iter()
+            .fold((0, 0), |(a, b), &(player, opponent)| (a + player, b + opponent));
+        let seed = |player: &Option<TournamentPlayer>| Reverse(player.as_ref().map_or(u32::MAX, |p| p.seed));
+
+        (self.player_games_won, player_points, seed(&self.player))
+            > (self.opponent_games_won, opponent_points, seed(&self.opponent))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bracket_seeding() {
+        assert_eq!(bracket_order(8), vec![1, 8, 4, 5, 2, 7, 3, 6]);
+
+        let state = TournamentState::new();
+        assert_eq!(state.bracket.len(), 4);
+        let top = &state.bracket[0];
+        assert_eq!((top.player1.seed, top.player2.seed), (1, 8));
+        assert!(top.player2.is_human);
+        assert_eq!(state.human_match(), Some(0));
+    }
+
+    #[test]
+    fn test_rounds_advance_to_a_champion() {
+        let mut state = TournamentState::new();
+
+        // Player 1 (the higher seed) takes every match
+        while !state.is_complete {
+            let index = state.round_matches()[state.current_match_index];
+            state.record_result(index, vec![(400, 300), (350, 360), (410, 200)]);
+        }
+
+        assert_eq!(state.bracket.len(), 7);
+        assert_eq!(state.bracket[6].round, TournamentRound(3));
+        assert_eq!(state.round_name(TournamentRound(3)), "Final");
+        let champion = state.champion.as_ref().unwrap();
+        assert_eq!(champion.seed, 1);
+        assert_eq!(state.player(&champion.name).unwrap().wins, 3);
+        assert_eq!(state.player("You").unwrap().losses, 1);
+        assert_eq!(state.human_match(), None);
+    }
+
+    /// Plays every match out with the higher seed winning, returning the rounds played
+    fn play_out(state: &mut TournamentState) -> u32 {
+        while !state.is_complete {
+            let index = state.round_matches()[state.current_match_index];
+            let bracket_match = &state.bracket[index];
+            let games = if bracket_match.player1.seed < bracket_match.player2.seed {
+                vec![(400, 300), (400, 300)]
+            } else {
+                vec![(300, 400), (300, 400)]
+            };
+            state.record_result(index, games);
+        }
+        state.current_round.0
+    }
+
+    #[test]
+    fn test_single_elimination_with_byes() {
+        let config = TournamentConfig { field_size: 6, ..default() };
+        let mut state = TournamentState::with_config(&config, INITIAL_RATING as u32);
+
+        // Seeds 1 and 2 sit out the first round
+        assert_eq!(state.bracket.len(), 2);
+        assert_eq!(state.byes.len(), 2);
+        assert_eq!(state.round_name(TournamentRound(1)), "Quarterfinals");
+
+        assert_eq!(play_out(&mut state), 3);
+        assert_eq!(state.champion.as_ref().unwrap().seed, 1);
+    }
+
+    #[test]
+    fn test_double_elimination_needs_two_losses() {
+        let config = TournamentConfig {
+            format: TournamentFormat::DoubleElimination,
+            ..default()
+        };
+        let mut state = TournamentState::with_config(&config, INITIAL_RATING as u32);
+        play_out(&mut state);
+
+        assert_eq!(state.champion.as_ref().unwrap().seed, 1);
+        assert!(state.players.iter().all(|p| p.losses == 2 || p.seed == 1));
+        assert!(state.is_eliminated("You"));
+    }
+
+    #[test]
+    fn test_swiss_and_round_robin() {
+        let config = TournamentConfig {
+            format: TournamentFormat::Swiss { rounds: 4 },
+            field_size: 7,
+            ..default()
+        };
+        let mut state = TournamentState::with_config(&config, INITIAL_RATING as u32);
+        assert_eq!(play_out(&mut state), 4);
+
+        // Three matches and a bye each round; one bye per player at most
+        assert_eq!(state.bracket.len(), 12);
+        let mut bye_takers: Vec<&String> = state.byes.iter().map(|(_, name)| name).collect();
+        bye_takers.sort();
+        bye_takers.dedup();
+        assert_eq!(bye_takers.len(), 4);
+        let standings = state.standings();
+        assert_eq!(standings[0].seed, 1);
+        assert_eq!(state.champion.as_ref().unwrap().seed, 1);
+        assert!(!state.is_eliminated("You"));
+
+        // No rematches before the King of the Hill round
+        let early: Vec<&BracketMatch> = state.bracket.iter().filter(|m| m.round.0 < 4).collect();
+        for (i, first) in early.iter().enumerate() {
+            for second in &early[i + 1..] {
+                let same = (first.player1.name == second.player1.name && first.player2.name == second.player2.name)
+                    || (first.player1.name == second.player2.name && first.player2.name == second.player1.name);
+                assert!(!same);
+            }
+        }
+
+        let config = TournamentConfig {
+            format: TournamentFormat::RoundRobin,
+            field_size: 5,
+            ..default()
+        };
+        let mut state = TournamentState::with_config(&config, INITIAL_RATING as u32);
+        assert_eq!(play_out(&mut state), 5);
+        assert_eq!(state.bracket.len(), 10);
+        assert!(state.players.iter().all(|p| p.wins + p.losses == 5));
+    }
+
+    #[test]
+    fn test_rating_matchmaking_and_seeding() {
+        // A new player meets the weakest personas and is seeded last
+        let state = TournamentState::new();
+        assert!(state.players.iter().all(|p| p.rating <= 1380));
+        assert_eq!(state.player("You").unwrap().seed, 8);
+
+        // A strong player meets the strongest and can be top seed
+        let config = TournamentConfig { field_size: 4, ..default() };
+        let state = TournamentState::with_config(&config, 1900);
+        let names: Vec<&str> = state.players.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(names, vec!["You", "Master Maya", "Champion Chen", "Grandmaster Grace"]);
+        assert_eq!(state.player("You").unwrap().seed, 1);
+        assert_eq!(state.player("Master Maya").unwrap().seed, 4);
+    }
+
+    #[test]
+    fn test_match_winner() {
+        let state = TournamentState::new();
+        let mut bracket_match = state.bracket[0].clone();
+
+        bracket_match.games = vec![(300, 400), (380, 350), (300, 301)];
+        assert_eq!(bracket_match.games_won(), (1, 2));
+        assert!(bracket_match.decide_winner().is_human);
+
+        // Level on games and points: the higher seed goes through
+        bracket_match.games = vec![(300, 300)];
+        assert_eq!(bracket_match.decide_winner().seed, 1);
+    }
+
+    #[test]
+    fn test_current_match_best_of() {
+        let state = TournamentState::new();
+        let bracket_match = &state.bracket[0];
+        let mut current = CurrentMatch::new(bracket_match.player2.clone(), bracket_match.player1.clone());
+
+        current.record_game(350, 300);
+        current.record_game(280, 280);
+        assert!(!current.is_match_over());
+        current.record_game(400, 310);
+        assert!(current.is_match_over());
+        assert!(current.player_won_match());
+
+        // Best of 5 needs a third win
+        let mut current = CurrentMatch::new(bracket_match.player2.clone(), bracket_match.player1.clone());
+        current.best_of = 5;
+        current.record_game(350, 300);
+        current.record_game(360, 300);
+        assert!(!current.is_match_over());
+        current.record_game(200, 300);
+        current.record_game(370, 300);
+        assert!(current.is_match_over());
+        assert_eq!((current.player_games_won, current.opponent_games_won), (3, 1));
     }
 }
@@ -3,6 +3,7 @@
 use bevy::prelude::*;
 use super::{TournamentState, CurrentMatch};
 use super::components::*;
+use super::tournament::BracketMatch;
 
 #[derive(Component)]
 pub struct TournamentBracket;
@@ -13,48 +14,364 @@ pub struct MatchDisplay;
 #[derive(Component)]
 pub struct OpponentInfo;
 
+/// Opponent and games won in the player's match
+#[derive(Component)]
+pub struct MatchScore;
+
+/// Row holding a column per round (and the standings), rebuilt as results come in
+#[derive(Component)]
+pub struct RoundColumns;
+
+/// Format, field size and how to change them
+#[derive(Component)]
+pub struct FormatLine;
+
+/// Line under the bracket: the champion, or what's happening
+#[derive(Component)]
+pub struct BracketStatus;
+
+/// Rounds shown alongside the standings in Swiss, round robin and double elimination
+const RECENT_ROUNDS: usize = 2;
+
 /// Spawn tournament bracket UI
 pub fn spawn_tournament_bracket(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
     tournament_state: Res<TournamentState>,
+    current_match: Res<CurrentMatch>,
 ) {
-    // Spawn 8-player bracket visualization
-    commands.spawn((
-        NodeBundle {
-            node: Node {
-                width: Val::Px(800.0),
-                height: Val::Px(600.0),
-                position_type: PositionType::Absolute,
-                right: Val::Px(20.0),
-                top: Val::Px(100.0),
-                flex_direction: FlexDirection::Column,
+    let font_bold: Handle<Font> = asset_server.load("fonts/FiraSans-Bold.ttf");
+    let font_medium: Handle<Font> = asset_server.load("fonts/FiraSans-Medium.ttf");
+
+    // Spawn bracket visualization
+    commands
+        .spawn((
+            NodeBundle {
+                node: Node {
+                    width: Val::Px(800.0),
+                    height: Val::Px(600.0),
+                    position_type: PositionType::Absolute,
+                    right: Val::Px(20.0),
+                    top: Val::Px(100.0),
+                    flex_direction: FlexDirection::Column,
+                    padding: UiRect::all(Val::Px(20.0)),
+                    row_gap: Val::Px(16.0),
+                    ..default()
+                },
+                background_color: Color::srgba(0.1, 0.1, 0.15, 0.9).into(),
+                ..default()
+            },
+            TournamentBracket,
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new("Tournament"),
+                TextFont {
+                    font: font_bold.clone(),
+                    font_size: 32.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(1.0, 0.85, 0.4)),
+            ));
+
+            parent.spawn((
+                Text::new(format_text(&tournament_state, &current_match)),
+                TextFont {
+                    font: font_medium.clone(),
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.7, 0.7, 0.8)),
+                FormatLine,
+            ));
+
+            parent
+                .spawn((
+                    NodeBundle {
+                        node: Node {
+                            flex_direction: FlexDirection::Row,
+                            column_gap: Val::Px(30.0),
+                            ..default()
+                        },
+                        ..default()
+                    },
+                    RoundColumns,
+                ))
+                .with_children(|columns| spawn_round_columns(columns, &tournament_state, &font_medium));
+
+            parent.spawn((
+                Text::new(status_text(&tournament_state)),
+                TextFont {
+                    font: font_bold.clone(),
+                    font_size: 22.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.5, 0.9, 0.5)),
+                BracketStatus,
+            ));
+        });
+
+    // Current match and opponent, to the left of the bracket
+    commands
+        .spawn((
+            NodeBundle {
+                node: Node {
+                    width: Val::Px(340.0),
+                    position_type: PositionType::Absolute,
+                    left: Val::Px(20.0),
+                    top: Val::Px(100.0),
+                    flex_direction: FlexDirection::Column,
+                    padding: UiRect::all(Val::Px(20.0)),
+                    row_gap: Val::Px(12.0),
+                    ..default()
+                },
+                background_color: Color::srgba(0.1, 0.1, 0.15, 0.9).into(),
+                ..default()
+            },
+            MatchDisplay,
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new("Your Match"),
+                TextFont {
+                    font: font_bold,
+                    font_size: 26.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.5, 0.7, 1.0)),
+            ));
+
+            parent.spawn((
+                Text::new(""),
+                TextFont {
+                    font: font_medium.clone(),
+                    font_size: 20.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.95, 0.95, 0.95)),
+                MatchScore,
+            ));
+
+            parent.spawn((
+                Text::new(""),
+                TextFont {
+                    font: font_medium,
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.75, 0.75, 0.8)),
+                OpponentInfo,
+            ));
+        });
+}
+
+/// One text column per round worth showing, plus the standings where the format ranks players
+fn spawn_round_columns(parent: &mut ChildBuilder, tournament_state: &TournamentState, font: &Handle<Font>) {
+    let mut columns = Vec::new();
+    match tournament_state.total_rounds().filter(|_| !tournament_state.format.uses_standings()) {
+        // The whole bracket, rounds to come included
+        Some(total) => {
+            for round in 1..=total {
+                columns.push(round_text(tournament_state, TournamentRound(round)));
+            }
+        }
+        None => {
+            let current = tournament_state.current_round.0;
+            for round in current.saturating_sub(RECENT_ROUNDS as u32 - 1).max(1)..=current {
+                columns.push(round_text(tournament_state, TournamentRound(round)));
+            }
+            columns.push(standings_text(tournament_state));
+        }
+    }
+
+    for text in columns {
+        parent.spawn((
+            Text::new(text),
+            TextFont {
+                font: font.clone(),
+                font_size: 15.0,
                 ..default()
             },
-            background_color: Color::srgba(0.1, 0.1, 0.15, 0.9).into(),
-            ..default()
-        },
-        TournamentBracket,
-    ));
+            TextColor(Color::srgb(0.85, 0.85, 0.9)),
+        ));
+    }
+}
+
+/// Matches of one round, winners marked
+fn round_text(tournament_state: &TournamentState, round: TournamentRound) -> String {
+    let matches: Vec<&BracketMatch> = tournament_state.bracket.iter().filter(|m| m.round == round).collect();
+    let mut text = format!("{}\n\n", tournament_state.round_name(round));
+    if matches.is_empty() {
+        text.push_str("To be decided");
+        return text;
+    }
+
+    for bracket_match in matches {
+        let (first_wins, second_wins) = bracket_match.games_won();
+        for (player, wins) in [(&bracket_match.player1, first_wins), (&bracket_match.player2, second_wins)] {
+            let is_winner = bracket_match.winner.as_ref().is_some_and(|w| w.name == player.name);
+            let marker = if is_winner { "> " } else { "  " };
+            let score = if bracket_match.games.is_empty() { String::new() } else { format!("  {}", wins) };
+            text.push_str(&format!("{}({}) {}{}\n", marker, player.seed, player.name, score));
+        }
+        text.push('\n');
+    }
+    for (_, name) in tournament_state.byes.iter().filter(|(r, _)| *r == round) {
+        let seed = tournament_state.player(name).map_or(0, |p| p.seed);
+        text.push_str(&format!("  ({}) {}  bye\n", seed, name));
+    }
+    text
+}
+
+/// Players by wins, then spread
+fn standings_text(tournament_state: &TournamentState) -> String {
+    let mut text = "Standings\n\n".to_string();
+    for (rank, player) in tournament_state.standings().into_iter().enumerate() {
+        let out = if tournament_state.is_eliminated(&player.name) { "  (out)" } else { "" };
+        text.push_str(&format!(
+            "{}. {}  {}-{}  {:+}{}\n",
+            rank + 1,
+            player.name,
+            player.wins,
+            player.losses,
+            player.spread,
+            out
+        ));
+    }
+    text
+}
+
+fn format_text(tournament_state: &TournamentState, current_match: &CurrentMatch) -> String {
+    let mut text = format!(
+        "{}  -  {} players  -  best of {}",
+        tournament_state.format.name(),
+        tournament_state.players.len(),
+        tournament_state.best_of
+    );
+    if !tournament_state.human_has_played() && current_match.games.is_empty() {
+        text.push_str("\nF: change format   Left/Right: fewer/more players (starts over)");
+    }
+    text
+}
+
+fn status_text(tournament_state: &TournamentState) -> String {
+    let round_name = tournament_state.round_name(tournament_state.current_round);
+    if let Some(champion) = &tournament_state.champion {
+        let record = tournament_state
+            .player(&champion.name)
+            .map(|p| format!(" ({}-{})", p.wins, p.losses))
+            .unwrap_or_default();
+        return format!("Champion: {}{}  -  Enter: main menu", champion.name, record);
+    }
+    if tournament_state.human_match().is_some() {
+        format!("{}: your match is up", round_name)
+    } else {
+        format!("{}: matches in progress...", round_name)
+    }
 }
 
 /// Update bracket display
 pub fn update_tournament_bracket(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
     tournament_state: Res<TournamentState>,
+    current_match: Res<CurrentMatch>,
+    columns: Query<Entity, With<RoundColumns>>,
+    mut format_line: Query<&mut Text, (With<FormatLine>, Without<BracketStatus>)>,
+    mut status: Query<&mut Text, With<BracketStatus>>,
 ) {
+    if current_match.is_changed() {
+        for mut text in format_line.iter_mut() {
+            **text = format_text(&tournament_state, &current_match);
+        }
+    }
+    if !tournament_state.is_changed() {
+        return;
+    }
+
     // Update visual representation of bracket as matches complete
+    let font: Handle<Font> = asset_server.load("fonts/FiraSans-Medium.ttf");
+    for entity in columns.iter() {
+        commands
+            .entity(entity)
+            .despawn_descendants()
+            .with_children(|parent| spawn_round_columns(parent, &tournament_state, &font));
+    }
+    for mut text in format_line.iter_mut() {
+        **text = format_text(&tournament_state, &current_match);
+    }
+    for mut text in status.iter_mut() {
+        **text = status_text(&tournament_state);
+    }
 }
 
 /// Update match display
 pub fn update_match_display(
     current_match: Res<CurrentMatch>,
+    tournament_state: Res<TournamentState>,
+    mut score_query: Query<&mut Text, With<MatchScore>>,
 ) {
-    // Show current match score (best-of-3)
+    if !current_match.is_changed() && !tournament_state.is_changed() {
+        return;
+    }
+    let Ok(mut text) = score_query.get_single_mut() else {
+        return;
+    };
+
+    // Show current match score (best-of-N)
+    **text = match current_match.opponent.as_ref().filter(|_| current_match.is_active) {
+        Some(opponent) => {
+            let next_game = if current_match.is_match_over() {
+                String::new()
+            } else {
+                let action = if current_match.game_in_progress { "resume" } else { "play" };
+                format!("\n\nEnter: {} game {}", action, current_match.games.len() + 1)
+            };
+            let last_game = current_match
+                .games
+                .last()
+                .map(|(player, opponent)| format!("\nLast game: {} - {}", player, opponent))
+                .unwrap_or_default();
+            format!(
+                "vs ({}) {}\nBest of {}  -  games {} - {}{}{}",
+                opponent.seed,
+                opponent.name,
+                current_match.best_of,
+                current_match.player_games_won,
+                current_match.opponent_games_won,
+                last_game,
+                next_game,
+            )
+        }
+        None if tournament_state.is_eliminated("You") => {
+            "You're out of the tournament.\nThe remaining matches play out in the bracket.".to_string()
+        }
+        None if tournament_state.is_complete => String::new(),
+        None => "Waiting for the other matches to finish.".to_string(),
+    };
 }
 
 /// Update opponent info panel
 pub fn update_opponent_info(
     current_match: Res<CurrentMatch>,
+    mut info_query: Query<&mut Text, With<OpponentInfo>>,
 ) {
-    // Show opponent name, difficulty, personality
+    if !current_match.is_changed() {
+        return;
+    }
+    let Ok(mut text) = info_query.get_single_mut() else {
+        return;
+    };
+
+    // Show opponent rating, difficulty and personality
+    **text = match current_match.opponent.as_ref().filter(|_| current_match.is_active) {
+        Some(opponent) => {
+            let style = opponent
+                .personality
+                .map(|p| format!("\n{:?}: {}", p, p.description()))
+                .unwrap_or_default();
+            format!("Rated {}  -  level {}{}", opponent.rating, opponent.difficulty, style)
+        }
+        None => String::new(),
+    };
 }
@@ -6,6 +6,7 @@ use super::components::{
     Stack, StackDirection, Spacing, Alignment, Spacer,
 };
 use super::keyboard_nav::{KeyboardFocus, KeyboardNavigable};
+use crate::stage5::save::{has_saved_tournament, ResumeRequest};
 
 #[derive(Component)]
 pub struct MainMenuScreen;
@@ -13,28 +14,69 @@ pub struct MainMenuScreen;
 #[derive(Component)]
 pub struct PlayButton;
 
+#[derive(Component)]
+pub struct ResumeButton;
+
+#[derive(Component)]
+pub struct ReviewButton;
+
 #[derive(Component)]
 pub struct SettingsButton;
 
+/// Menu buttons in keyboard order
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum MenuButton {
+    Play,
+    Resume,
+    Review,
+    Settings,
+}
+
+/// Whether there was a saved tournament to resume on entering the menu
+#[derive(Resource, Default)]
+pub struct CanResume(pub bool);
+
+/// Look for a saved tournament once per visit to the menu
+pub fn check_saved_tournament(mut can_resume: ResMut<CanResume>) {
+    can_resume.0 = has_saved_tournament();
+}
+
+/// Resume only shows up when there's a saved tournament
+fn menu_buttons(can_resume: bool) -> Vec<MenuButton> {
+    let mut buttons = vec![MenuButton::Play];
+    if can_resume {
+        buttons.push(MenuButton::Resume);
+    }
+    buttons.extend([MenuButton::Review, MenuButton::Settings]);
+    buttons
+}
+
+#[allow(clippy::too_many_arguments)]
 pub fn update_main_menu(
     mut commands: Commands,
     state: Res<State<GameState>>,
     query: Query<Entity, With<MainMenuScreen>>,
     mut next_state: ResMut<NextState<GameState>>,
     interaction_query: Query<(&Interaction, &PlayButton), Changed<Interaction>>,
+    resume_query: Query<(&Interaction, &ResumeButton), Changed<Interaction>>,
+    review_query: Query<(&Interaction, &ReviewButton), Changed<Interaction>>,
     settings_query: Query<(&Interaction, &SettingsButton), Changed<Interaction>>,
     keyboard: Res<ButtonInput<KeyCode>>,
     focus: Option<ResMut<KeyboardFocus>>,
+    mut resume_request: ResMut<ResumeRequest>,
+    can_resume: Res<CanResume>,
 ) {
     if *state.get() == GameState::MainMenu {
+        let can_resume = can_resume.0;
+
         // Spawn UI if it doesn't exist
         if query.is_empty() {
-            spawn_main_menu_ui(&mut commands);
+            spawn_main_menu_ui(&mut commands, can_resume);
         }
 
         // Always ensure KeyboardFocus resource exists (it gets removed when leaving this state)
         if focus.is_none() {
-            commands.insert_resource(KeyboardFocus::new(2));
+            commands.insert_resource(KeyboardFocus::new(menu_buttons(can_resume).len()));
             return; // Skip navigation this frame - resource won't be available until next frame
         }
 
@@ -65,12 +107,21 @@ pub fn update_main_menu(
             if keyboard.just_pressed(KeyCode::Enter) || keyboard.just_pressed(KeyCode::Space) {
                 info!("⏎ Enter/Space pressed with focus: {:?}", focus.focused_index);
                 if let Some(focused_idx) = focus.focused_index {
-                    match focused_idx {
-                        0 => {
+                    match menu_buttons(can_resume).get(focused_idx) {
+                        Some(MenuButton::Play) => {
                             info!("   Navigating to Stage Select (Play button)");
                             next_state.set(GameState::StageSelect);
                         }
-                        1 => {
+                        Some(MenuButton::Resume) => {
+                            info!("   Resuming saved tournament");
+                            resume_request.0 = true;
+                            next_state.set(GameState::Stage5Playing);
+                        }
+                        Some(MenuButton::Review) => {
+                            info!("   Navigating to Game Review");
+                            next_state.set(GameState::Stage3Review);
+                        }
+                        Some(MenuButton::Settings) => {
                             info!("   Navigating to Settings");
                             next_state.set(GameState::Settings);
                         }
@@ -94,6 +145,21 @@ pub fn update_main_menu(
             }
         }
 
+        // Handle Resume button mouse click
+        for (interaction, _) in resume_query.iter() {
+            if *interaction == Interaction::Pressed {
+                resume_request.0 = true;
+                next_state.set(GameState::Stage5Playing);
+            }
+        }
+
+        // Handle Review button mouse click
+        for (interaction, _) in review_query.iter() {
+            if *interaction == Interaction::Pressed {
+                next_state.set(GameState::Stage3Review);
+            }
+        }
+
         // Handle Settings button mouse click
         for (interaction, _) in settings_query.iter() {
             if *interaction == Interaction::Pressed {
@@ -109,7 +175,7 @@ pub fn update_main_menu(
     }
 }
 
-fn spawn_main_menu_ui(commands: &mut Commands) {
+fn spawn_main_menu_ui(commands: &mut Commands, can_resume: bool) {
     // Create main screen container with centered vertical stack
     let screen_id = commands.spawn((
         Node {
@@ -163,6 +229,35 @@ fn spawn_main_menu_ui(commands: &mut Commands) {
     commands.entity(play_button).insert(KeyboardNavigable { index: 0 });
     commands.entity(stack_id).add_child(play_button);
 
+    // Keyboard order of the remaining buttons depends on whether Resume is shown
+    let buttons = menu_buttons(can_resume);
+    let index_of = |button| buttons.iter().position(|&b| b == button).unwrap_or_default();
+
+    // Resume button, for a saved tournament
+    if can_resume {
+        let resume_button = ButtonComponent::spawn(
+            commands,
+            "🏆 Resume Tournament",
+            ButtonSize::Large,
+            ButtonVariant::Secondary,
+            ResumeButton,
+        );
+        commands.entity(resume_button).insert(KeyboardNavigable { index: index_of(MenuButton::Resume) });
+        commands.entity(stack_id).add_child(resume_button);
+    }
+
+    // Review button (using ButtonComponent)
+    let review_button = ButtonComponent::spawn(
+        commands,
+        "📖 Review Games",
+        ButtonSize::Large,
+        ButtonVariant::Secondary,
+        ReviewButton,
+    );
+    // Make it keyboard navigable
+    commands.entity(review_button).insert(KeyboardNavigable { index: index_of(MenuButton::Review) });
+    commands.entity(stack_id).add_child(review_button);
+
     // Settings button (using ButtonComponent)
     let settings_button = ButtonComponent::spawn(
         commands,
@@ -171,8 +266,8 @@ fn spawn_main_menu_ui(commands: &mut Commands) {
         ButtonVariant::Secondary,
         SettingsButton,
     );
-    // Make it keyboard navigable (index 1)
-    commands.entity(settings_button).insert(KeyboardNavigable { index: 1 });
+    // Make it keyboard navigable
+    commands.entity(settings_button).insert(KeyboardNavigable { index: index_of(MenuButton::Settings) });
     commands.entity(stack_id).add_child(settings_button);
 
     // Spacer between buttons and instructions
@@ -8,6 +8,7 @@ pub mod settings;
 pub mod keyboard_nav;
 
 use bevy::prelude::*;
+use crate::plugins::state::GameState;
 use components::button;
 use keyboard_nav::{KeyboardFocus, apply_focus_visual};
 
@@ -17,6 +18,8 @@ pub struct UiPlugin;
 impl Plugin for UiPlugin {
     fn build(&self, app: &mut App) {
         app
+            .init_resource::<main_menu::CanResume>()
+            .add_systems(OnEnter(GameState::MainMenu), main_menu::check_saved_tournament)
             .add_systems(Update, (
                 button::update_button_interaction,
                 splash::update_splash,
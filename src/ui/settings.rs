@@ -17,6 +17,7 @@ pub enum SettingButton {
     DictionaryCycle,
     TimerCycle,
     DifficultyCycle,
+    ChallengeCycle,
     SaveSettings,
     BackToMenu,
 }
@@ -35,6 +36,7 @@ pub enum SettingType {
     Dictionary,
     Timer,
     Difficulty,
+    ChallengeRule,
 }
 
 pub fn update_settings(
@@ -56,7 +58,7 @@ pub fn update_settings(
 
         // Always ensure KeyboardFocus resource exists (it gets removed when leaving this state)
         if focus.is_none() {
-            commands.insert_resource(KeyboardFocus::new(9));
+            commands.insert_resource(KeyboardFocus::new(10));
             return; // Skip navigation this frame - resource won't be available until next frame
         }
 
@@ -184,6 +186,10 @@ pub fn handle_setting_buttons(
                     };
                     update_labels(&settings, &mut label_query);
                 }
+                SettingButton::ChallengeCycle => {
+                    settings.gameplay.challenge_rule = settings.gameplay.challenge_rule.next();
+                    update_labels(&settings, &mut label_query);
+                }
                 SettingButton::SaveSettings => {
                     if let Err(e) = settings.save() {
                         error!("Failed to save settings: {}", e);
@@ -255,7 +261,12 @@ fn handle_left_arrow(focused_idx: usize, settings: &mut GameSettings) -> bool {
             };
             true
         }
-        _ => false, // Indices 7-8 (buttons) don't respond to left/right
+        7 => {
+            // Challenge Rule - Cycle Backward
+            settings.gameplay.challenge_rule = settings.gameplay.challenge_rule.previous();
+            true
+        }
+        _ => false, // Indices 8-9 (buttons) don't respond to left/right
     }
 }
 
@@ -314,7 +325,12 @@ fn handle_right_arrow(focused_idx: usize, settings: &mut GameSettings) -> bool {
             };
             true
         }
-        _ => false, // Indices 7-8 (buttons) don't respond to left/right
+        7 => {
+            // Challenge Rule - Cycle Forward
+            settings.gameplay.challenge_rule = settings.gameplay.challenge_rule.next();
+            true
+        }
+        _ => false, // Indices 8-9 (buttons) don't respond to left/right
     }
 }
 
@@ -373,10 +389,15 @@ fn handle_enter_activation(focused_idx: usize, settings: &mut GameSettings, next
             true
         }
         7 => {
+            // Challenge Rule - Cycle Forward
+            settings.gameplay.challenge_rule = settings.gameplay.challenge_rule.next();
+            true
+        }
+        8 => {
             // Save Settings button (auto-saved, so just acknowledge)
             false
         }
-        8 => {
+        9 => {
             // Back to Menu button
             next_state.set(GameState::MainMenu);
             false
@@ -423,6 +444,9 @@ fn update_labels(settings: &GameSettings, label_query: &mut Query<(&SettingLabel
                 };
                 format!("🎮 Difficulty: {}", diff_name)
             }
+            SettingType::ChallengeRule => {
+                format!("⚖ Challenge Rule: {}", settings.gameplay.challenge_rule.name())
+            }
         };
     }
 }
@@ -540,6 +564,16 @@ fn spawn_settings_ui(commands: &mut Commands, settings: &GameSettings, asset_ser
                         settings,
                         6,
                     );
+
+                    // Challenge Rule Cycle (index 7)
+                    spawn_cycle_row(
+                        container,
+                        &font_medium,
+                        SettingType::ChallengeRule,
+                        SettingButton::ChallengeCycle,
+                        settings,
+                        7,
+                    );
                 });
 
             // Action buttons
@@ -552,24 +586,24 @@ fn spawn_settings_ui(commands: &mut Commands, settings: &GameSettings, asset_ser
                     ..default()
                 })
                 .with_children(|buttons| {
-                    // Save button (index 7)
+                    // Save button (index 8)
                     spawn_action_button(
                         buttons,
                         &font_bold,
                         "💾 Save",
                         SettingButton::SaveSettings,
                         Color::srgb(0.2, 0.6, 0.3),
-                        7,
+                        8,
                     );
 
-                    // Back button (index 8)
+                    // Back button (index 9)
                     spawn_action_button(
                         buttons,
                         &font_bold,
                         "← Back",
                         SettingButton::BackToMenu,
                         Color::srgb(0.3, 0.3, 0.4),
-                        8,
+                        9,
                     );
                 });
 
@@ -821,6 +855,9 @@ fn spawn_cycle_row(
                     };
                     format!("🎮 Difficulty: {}", diff_name)
                 }
+                SettingType::ChallengeRule => {
+                    format!("⚖ Challenge Rule: {}", settings.gameplay.challenge_rule.name())
+                }
                 _ => String::new(),
             };
 